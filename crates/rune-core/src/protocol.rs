@@ -153,6 +153,19 @@ impl Protocol {
             doc: ["Allows an equality operation to work."],
         };
 
+        /// Hash a value, so that it can be used as a key in a map or a set.
+        ///
+        /// Keys of the same type which produce the same hash are considered to
+        /// be the same key, so the hash should uniquely identify the value.
+        ///
+        /// Signature: `fn(&self) -> i64`.
+        pub const HASH: Protocol = Protocol {
+            name: "hash",
+            hash: 0x94e7cded6aff578b,
+            repr: None,
+            doc: ["Allows the value to be used as a key in maps and sets."],
+        };
+
         /// The function to implement for the addition operation.
         pub const ADD: Protocol = Protocol {
             name: "add",
@@ -369,6 +382,16 @@ impl Protocol {
             doc: ["Allows the value to be debug printed."],
         };
 
+        /// Protocol function used when deep cloning a value.
+        ///
+        /// Signature: `fn(&self) -> Self`.
+        pub const CLONE: Protocol = Protocol {
+            name: "clone",
+            hash: 0xb5e80444e6143685,
+            repr: Some("let output = std::deep_clone($value)"),
+            doc: ["Allows the value to be deep cloned."],
+        };
+
         /// Function used to convert an argument into an iterator.
         pub const INTO_ITER: Protocol = Protocol {
             name: "into_iter",
@@ -24,13 +24,16 @@
 //! use process::Command;
 //!
 //! fn main() {
-//!     let command = Command::new("ls");
-//!     command.run().await;
+//!     let command = Command::new("ls").arg("-l").spawn()?;
+//!     command.wait_with_output().await;
 //! }
 //! ```
 
-use rune::{Any, Module, ContextError};
-use rune::runtime::{Bytes, Shared, Value, Protocol, VmResult};
+use rune::{Any, Hash, Module, ContextError};
+use rune::runtime::{
+    Bytes, FromValue, FullTypeOf, MaybeTypeOf, Mut, Shared, ToValue, TypeInfo, TypeOf, Value,
+    Protocol, VmResult,
+};
 use std::fmt;
 use std::io;
 use tokio::process;
@@ -47,6 +50,8 @@ pub fn module(_stdio: bool) -> Result<Module, ContextError> {
     module.associated_function("spawn", Command::spawn)?;
     module.associated_function("arg", Command::arg)?;
     module.associated_function("args", Command::args)?;
+    module.associated_function("env", Command::env)?;
+    module.associated_function("current_dir", Command::current_dir)?;
     module.associated_function("wait_with_output", Child::wait_with_output)?;
     module.associated_function(Protocol::STRING_DISPLAY, ExitStatus::display)?;
     module.associated_function("code", ExitStatus::code)?;
@@ -68,14 +73,16 @@ impl Command {
     }
 
     /// Add arguments.
-    fn args(&mut self, args: &[Value]) -> VmResult<()> {
+    fn args(this: CommandRef, args: &[Value]) -> VmResult<CommandRef> {
+        let mut command = rune::vm_try!(this.borrow_mut());
+
         for arg in args {
             match arg {
                 Value::String(s) => {
-                    self.inner.arg(&*rune::vm_try!(s.borrow_ref()));
+                    command.inner.arg(&*rune::vm_try!(s.borrow_ref()));
                 }
                 Value::StaticString(s) => {
-                    self.inner.arg(&***s);
+                    command.inner.arg(&***s);
                 }
                 actual => {
                     return VmResult::expected::<String>(rune::vm_try!(actual.type_info()));
@@ -83,12 +90,26 @@ impl Command {
             }
         }
 
-        VmResult::Ok(())
+        drop(command);
+        VmResult::Ok(this)
     }
 
     /// Add an argument.
-    fn arg(&mut self, arg: &str) {
-        self.inner.arg(arg);
+    fn arg(this: CommandRef, arg: &str) -> VmResult<CommandRef> {
+        rune::vm_try!(this.borrow_mut()).inner.arg(arg);
+        VmResult::Ok(this)
+    }
+
+    /// Set an environment variable.
+    fn env(this: CommandRef, key: &str, value: &str) -> VmResult<CommandRef> {
+        rune::vm_try!(this.borrow_mut()).inner.env(key, value);
+        VmResult::Ok(this)
+    }
+
+    /// Set the working directory.
+    fn current_dir(this: CommandRef, dir: &str) -> VmResult<CommandRef> {
+        rune::vm_try!(this.borrow_mut()).inner.current_dir(dir);
+        VmResult::Ok(this)
     }
 
     /// Spawn the command.
@@ -99,6 +120,52 @@ impl Command {
     }
 }
 
+/// A handle to a shared [Command].
+///
+/// This is what the builder methods of a command receive and return, so that
+/// calls can be chained from Rune while mutating the same command in place.
+struct CommandRef(Value);
+
+impl CommandRef {
+    fn borrow_mut(&self) -> VmResult<Mut<Command>> {
+        Mut::from_value(self.0.clone())
+    }
+}
+
+impl FromValue for CommandRef {
+    fn from_value(value: Value) -> VmResult<Self> {
+        // Check that the value is a command up front.
+        drop(rune::vm_try!(Mut::<Command>::from_value(value.clone())));
+        VmResult::Ok(Self(value))
+    }
+}
+
+impl ToValue for CommandRef {
+    fn to_value(self) -> VmResult<Value> {
+        VmResult::Ok(self.0)
+    }
+}
+
+impl TypeOf for CommandRef {
+    fn type_hash() -> Hash {
+        <Command as TypeOf>::type_hash()
+    }
+
+    fn type_of() -> FullTypeOf {
+        Command::type_of()
+    }
+
+    fn type_info() -> TypeInfo {
+        Command::type_info()
+    }
+}
+
+impl MaybeTypeOf for CommandRef {
+    fn maybe_type_of() -> Option<FullTypeOf> {
+        Command::maybe_type_of()
+    }
+}
+
 #[derive(Any)]
 #[rune(item = ::process)]
 struct Child {
@@ -160,3 +227,42 @@ impl ExitStatus {
         self.status.code()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::ffi::OsStr;
+    use std::sync::Arc;
+
+    use rune::{Context, Source, Sources, Vm};
+
+    use super::Command;
+
+    #[test]
+    fn test_chained_args() -> rune::Result<()> {
+        let mut context = Context::with_default_modules()?;
+        context.install(super::module(true)?)?;
+        let runtime = Arc::new(context.runtime());
+
+        let mut sources = Sources::new();
+        sources.insert(Source::new(
+            "main",
+            r#"
+            use process::Command;
+
+            pub fn main() {
+                let command = Command::new("ls");
+                command.arg("-l").arg("-a");
+                command.args(["-h"]).env("LANG", "C").current_dir(".")
+            }
+            "#,
+        ));
+
+        let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+        let mut vm = Vm::new(runtime, Arc::new(unit));
+        let command: Command = rune::from_value(vm.call(["main"], ())?)?;
+
+        let args = command.inner.as_std().get_args().collect::<Vec<_>>();
+        assert_eq!(args, [OsStr::new("-l"), OsStr::new("-a"), OsStr::new("-h")]);
+        Ok(())
+    }
+}
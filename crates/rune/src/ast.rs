@@ -128,6 +128,7 @@ mod expr_yield;
 mod fields;
 mod file;
 mod fn_arg;
+mod generic_param;
 mod grouped;
 mod ident;
 mod item;
@@ -193,18 +194,19 @@ pub use self::expr_yield::ExprYield;
 pub use self::fields::Fields;
 pub use self::file::{File, Shebang};
 pub use self::fn_arg::FnArg;
+pub use self::generic_param::GenericParam;
 pub use self::grouped::{AngleBracketed, Braced, Bracketed, Parenthesized};
 pub use self::ident::Ident;
 pub use self::item::Item;
 pub use self::item_const::ItemConst;
 pub use self::item_enum::{ItemEnum, ItemVariant};
 pub use self::item_fn::ItemFn;
-pub use self::item_impl::ItemImpl;
+pub use self::item_impl::{ImplItem, ItemImpl};
 pub use self::item_mod::{ItemInlineBody, ItemMod, ItemModBody};
 pub use self::item_struct::{Field, ItemStruct};
 pub use self::item_use::{ItemUse, ItemUsePath, ItemUseSegment};
 pub use self::label::Label;
-pub use self::lit::Lit;
+pub use self::lit::{Lit, LitKind};
 pub use self::lit_bool::LitBool;
 pub use self::lit_byte::LitByte;
 pub use self::lit_byte_str::LitByteStr;
@@ -214,7 +216,7 @@ pub use self::lit_str::LitStr;
 pub use self::local::Local;
 pub use self::macro_call::MacroCall;
 pub use self::pat::{
-    Pat, PatBinding, PatIgnore, PatLit, PatObject, PatPath, PatRest, PatTuple, PatVec,
+    Pat, PatBinding, PatIgnore, PatLit, PatObject, PatPath, PatRange, PatRest, PatTuple, PatVec,
 };
 pub use self::path::{Path, PathKind, PathSegment, PathSegmentExpr};
 pub use self::span::{ByteIndex, Span};
@@ -274,33 +274,6 @@ impl Expr {
         }
     }
 
-    /// Check if this expression is a literal expression.
-    ///
-    /// There are exactly two kinds of literal expressions:
-    /// * Ones that are ExprLit
-    /// * Unary expressions which are the negate operation.
-    pub(crate) fn is_lit(&self) -> bool {
-        match self {
-            Self::Lit(..) => return true,
-            Self::Unary(ast::ExprUnary {
-                op: ast::UnOp::Neg(..),
-                expr,
-                ..
-            }) => {
-                return matches!(
-                    &**expr,
-                    Self::Lit(ast::ExprLit {
-                        lit: ast::Lit::Number(..),
-                        ..
-                    })
-                );
-            }
-            _ => (),
-        }
-
-        false
-    }
-
     /// Internal function to construct a literal expression.
     pub(crate) fn from_lit(lit: ast::Lit) -> Self {
         Self::Lit(ast::ExprLit {
@@ -700,6 +673,13 @@ fn binary(
                     continue;
                 }
                 (lh, rh) if lh == rh => {
+                    if op.is_comparison() && next.is_comparison() {
+                        return Err(compile::Error::new(
+                            lhs.span().join(rhs.span()),
+                            ParseErrorKind::ChainedComparison { lhs: op, rhs: next },
+                        ));
+                    }
+
                     if !next.is_assoc() {
                         return Err(compile::Error::new(
                             lhs.span().join(rhs.span()),
@@ -834,6 +814,29 @@ mod tests {
         rt::<ast::Expr>("42 is int::int");
     }
 
+    #[test]
+    fn test_expr_paren_disambiguation() {
+        let expr = rt::<ast::Expr>("()");
+        assert!(matches!(expr, ast::Expr::Tuple(ref tuple) if tuple.items.is_empty()));
+
+        let expr = rt::<ast::Expr>("(a)");
+        assert!(matches!(expr, ast::Expr::Group(..)));
+
+        let expr = rt::<ast::Expr>("((a, b))");
+        assert!(
+            matches!(expr, ast::Expr::Group(ref group) if matches!(*group.expr, ast::Expr::Tuple(..)))
+        );
+
+        let expr = rt::<ast::Expr>("(a,)");
+        assert!(matches!(expr, ast::Expr::Tuple(ref tuple) if tuple.items.len() == 1));
+
+        let expr = rt::<ast::Expr>("(a, b)");
+        assert!(matches!(expr, ast::Expr::Tuple(ref tuple) if tuple.items.len() == 2));
+
+        let expr = rt::<ast::Expr>("(a, b).1");
+        assert!(matches!(expr, ast::Expr::FieldAccess(..)));
+    }
+
     #[test]
     fn test_macro_call_chain() {
         rt::<ast::Expr>("format!(\"{}\", a).bar()");
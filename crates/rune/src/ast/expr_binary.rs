@@ -10,6 +10,55 @@ fn ast_parse() {
     rt::<ast::ExprBinary>("b << 10");
 }
 
+#[test]
+fn ast_parse_compound_assign() {
+    use crate::testing::rt;
+
+    for (source, op) in [
+        ("x += 1", "+="),
+        ("x -= 1", "-="),
+        ("x *= 1", "*="),
+        ("x /= 1", "/="),
+    ] {
+        let expr = rt::<ast::ExprBinary>(source);
+        assert!(expr.op.is_assign());
+        assert_eq!(expr.op.to_string(), op);
+        assert_eq!(expr.span(), Span::new(0, source.len()));
+    }
+}
+
+#[test]
+fn ast_parse_logical_precedence() {
+    use crate::testing::rt;
+
+    let expr = rt::<ast::ExprBinary>("1 == 1 && 2 == 2");
+    assert!(matches!(expr.op, ast::BinOp::And(..)));
+    assert!(matches!(
+        &*expr.lhs,
+        ast::Expr::Binary(ast::ExprBinary {
+            op: ast::BinOp::Eq(..),
+            ..
+        })
+    ));
+    assert!(matches!(
+        &*expr.rhs,
+        ast::Expr::Binary(ast::ExprBinary {
+            op: ast::BinOp::Eq(..),
+            ..
+        })
+    ));
+
+    let expr = rt::<ast::ExprBinary>("a && b || c && d");
+    assert!(matches!(expr.op, ast::BinOp::Or(..)));
+    assert!(matches!(
+        &*expr.lhs,
+        ast::Expr::Binary(ast::ExprBinary {
+            op: ast::BinOp::And(..),
+            ..
+        })
+    ));
+}
+
 /// A binary expression.
 ///
 /// * `<expr> <op> <expr>`.
@@ -126,6 +175,19 @@ impl BinOp {
         }
     }
 
+    /// Test if operator is a comparison operator.
+    pub(super) fn is_comparison(&self) -> bool {
+        matches!(
+            self,
+            Self::Eq(..)
+                | Self::Neq(..)
+                | Self::Lt(..)
+                | Self::Gt(..)
+                | Self::Lte(..)
+                | Self::Gte(..)
+        )
+    }
+
     /// Get the precedence for the current operator.
     pub(super) fn precedence(&self) -> usize {
         // NB: Rules from: https://doc.rust-lang.org/reference/expressions.html#expression-precedence
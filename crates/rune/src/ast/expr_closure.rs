@@ -1,3 +1,5 @@
+use core::iter;
+
 use crate::ast::prelude::*;
 
 #[test]
@@ -46,11 +48,13 @@ pub struct ExprClosure {
 impl ExprClosure {
     /// Get the identifying span for this closure.
     pub fn item_span(&self) -> Span {
-        if let Some(async_) = &self.async_token {
-            async_.span().join(self.args.span())
-        } else {
-            self.args.span()
-        }
+        let spans = self
+            .async_token
+            .option_span()
+            .into_iter()
+            .chain(iter::once(self.args.span()));
+
+        Span::from_spans(spans).unwrap_or_else(|| self.args.span())
     }
 }
 
@@ -11,6 +11,40 @@ fn ast_parse() {
     rt::<ast::ExprFieldAccess>("(foo.0).1");
 }
 
+#[test]
+fn ast_parse_chained() {
+    use crate::testing::rt;
+
+    // Postfix operations associate to the left: `((((a.b)()).c)[0]).d)()`.
+    let expr = rt::<ast::Expr>("a.b().c[0].d()");
+
+    let ast::Expr::Call(call) = expr else {
+        panic!("expected call");
+    };
+
+    let ast::Expr::FieldAccess(d) = *call.expr else {
+        panic!("expected field access `.d`");
+    };
+
+    let ast::Expr::Index(index) = *d.expr else {
+        panic!("expected index `[0]`");
+    };
+
+    let ast::Expr::FieldAccess(c) = *index.target else {
+        panic!("expected field access `.c`");
+    };
+
+    let ast::Expr::Call(call) = *c.expr else {
+        panic!("expected call `b()`");
+    };
+
+    let ast::Expr::FieldAccess(b) = *call.expr else {
+        panic!("expected field access `.b`");
+    };
+
+    assert!(matches!(*b.expr, ast::Expr::Path(..)));
+}
+
 /// A field access.
 ///
 /// * `<expr>.<field>`.
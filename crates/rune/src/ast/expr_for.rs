@@ -8,6 +8,12 @@ fn ast_parse() {
     rt::<ast::ExprFor>("for (a, _) in x {}");
     rt::<ast::ExprFor>("'label: for i in x {}");
     rt::<ast::ExprFor>("#[attr] 'label: for i in x {}");
+
+    let expr = rt::<ast::ExprFor>("for i in x { i }");
+    assert_eq!(expr.span(), expr.for_token.span.join(expr.body.span()));
+
+    let block = rt::<ast::Block>("{ for i in x {} 42 }");
+    assert_eq!(block.statements.len(), 2);
 }
 
 /// A `for` loop over an iterator.
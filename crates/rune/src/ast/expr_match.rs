@@ -9,6 +9,30 @@ fn ast_parse() {
     assert_eq!(expr.attributes.len(), 1);
 
     rt::<ast::ExprMatchBranch>("1 => { foo }");
+
+    let expr = rt::<ast::ExprMatch>("match x { 1 => a, \"b\" => b, y => y, _ => c }");
+    assert_eq!(expr.branches.len(), 4);
+    assert!(expr.branches.last().unwrap().1.is_none());
+
+    let expr = rt::<ast::ExprMatch>("match x { 1 => a, _ => c, }");
+    assert_eq!(expr.branches.len(), 2);
+    assert!(expr.branches.last().unwrap().1.is_some());
+
+    let block = rt::<ast::Block>("{ match x { _ => {} } 42 }");
+    assert_eq!(block.statements.len(), 2);
+
+    // Grouping forces an object literal to be parsed as the scrutinee.
+    let expr = rt::<ast::ExprMatch>("match (#{}) { }");
+    assert!(expr.branches.is_empty());
+
+    let ast::Expr::Group(group) = &*expr.expr else {
+        panic!("expected group");
+    };
+
+    assert!(matches!(&*group.expr, ast::Expr::Object(..)));
+
+    let expr = rt::<ast::ExprMatch>("match (#{\"a\": 1}) { #{a} => a }");
+    assert_eq!(expr.branches.len(), 1);
 }
 
 /// A match expression.
@@ -9,6 +9,7 @@ fn ast_parse() {
     rt::<ast::ExprObject>("Foo {\"foo\": 42}");
     rt::<ast::ExprObject>("#{\"foo\": 42}");
     rt::<ast::ExprObject>("#{\"foo\": 42,}");
+    rt::<ast::ExprObject>("#{}");
 
     rt::<ast::FieldAssign>("\"foo\": 42");
     rt::<ast::FieldAssign>("\"foo\": 42");
@@ -49,3 +49,36 @@ impl Parse for ExprRangeLimits {
 }
 
 expr_parse!(Range, ExprRange, "range expression");
+
+#[test]
+fn ast_parse_bounds() {
+    use crate::testing::rt;
+
+    let range = rt::<ast::ExprRange>("a + 1 .. b * 2");
+    assert!(matches!(range.limits, ExprRangeLimits::HalfOpen(..)));
+    assert!(matches!(range.from.as_deref(), Some(ast::Expr::Binary(..))));
+    assert!(matches!(range.to.as_deref(), Some(ast::Expr::Binary(..))));
+
+    let range = rt::<ast::ExprRange>("..b");
+    assert!(range.from.is_none());
+    assert!(range.to.is_some());
+    assert_eq!(range.span(), Span::new(0, 3));
+
+    let range = rt::<ast::ExprRange>("a..");
+    assert!(range.from.is_some());
+    assert!(range.to.is_none());
+    assert_eq!(range.span(), Span::new(0, 3));
+
+    let range = rt::<ast::ExprRange>("..");
+    assert!(range.from.is_none() && range.to.is_none());
+    assert_eq!(range.span(), Span::new(0, 2));
+
+    let range = rt::<ast::ExprRange>("a..=b");
+    assert!(matches!(range.limits, ExprRangeLimits::Closed(..)));
+
+    // Ranges bind looser than comparisons.
+    let expr = rt::<ast::Expr>("a < b .. c");
+    assert!(
+        matches!(expr, ast::Expr::Range(range) if matches!(range.from.as_deref(), Some(ast::Expr::Binary(..))))
+    );
+}
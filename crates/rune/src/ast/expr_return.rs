@@ -9,6 +9,29 @@ fn ast_parse() {
     rt::<ast::ExprReturn>("#[attr] return 42");
 }
 
+#[test]
+fn ast_parse_terminated() {
+    use crate::testing::rt;
+
+    let block = rt::<ast::Block>("{ return; }");
+    let [ast::Stmt::Semi(stmt)] = &block.statements[..] else {
+        panic!("expected semi-terminated statement");
+    };
+    assert!(matches!(&stmt.expr, ast::Expr::Return(expr) if expr.expr.is_none()));
+
+    let block = rt::<ast::Block>("{ return }");
+    let [ast::Stmt::Expr(ast::Expr::Return(expr))] = &block.statements[..] else {
+        panic!("expected return expression");
+    };
+    assert!(expr.expr.is_none());
+
+    let block = rt::<ast::Block>("{ return 42 }");
+    let [ast::Stmt::Expr(ast::Expr::Return(expr))] = &block.statements[..] else {
+        panic!("expected return expression");
+    };
+    assert!(expr.expr.is_some());
+}
+
 /// A return expression.
 ///
 /// * `return [expr]`.
@@ -19,6 +19,30 @@ fn ast_parse() {
     rt::<ast::UnOp>("-");
     rt::<ast::UnOp>("&");
     rt::<ast::UnOp>("*");
+
+    let expr = rt::<ast::ExprUnary>("-42");
+    assert!(matches!(expr.op, ast::UnOp::Neg(..)));
+    assert!(matches!(&*expr.expr, ast::Expr::Lit(..)));
+
+    let expr = rt::<ast::ExprUnary>("--x");
+    assert!(matches!(expr.op, ast::UnOp::Neg(..)));
+    assert!(matches!(
+        &*expr.expr,
+        ast::Expr::Unary(ast::ExprUnary {
+            op: ast::UnOp::Neg(..),
+            ..
+        })
+    ));
+
+    let expr = rt::<ast::ExprUnary>("!!flag");
+    assert!(matches!(expr.op, ast::UnOp::Not(..)));
+    assert!(matches!(
+        &*expr.expr,
+        ast::Expr::Unary(ast::ExprUnary {
+            op: ast::UnOp::Not(..),
+            ..
+        })
+    ));
 }
 
 /// A unary expression.
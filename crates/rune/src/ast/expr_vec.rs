@@ -7,6 +7,7 @@ fn ast_parse() {
     rt::<ast::ExprVec>("[1, \"two\"]");
     rt::<ast::ExprVec>("[1, 2,]");
     rt::<ast::ExprVec>("[1, 2, foo()]");
+    rt::<ast::ExprVec>("[]");
 }
 
 /// A literal vector.
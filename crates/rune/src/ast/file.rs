@@ -69,15 +69,14 @@ pub struct File {
 
 impl OptionSpanned for File {
     fn option_span(&self) -> Option<Span> {
-        let start = self.attributes.option_span();
-        let end = self.attributes.option_span();
-
-        match (start, end) {
-            (Some(start), Some(end)) => Some(start.join(end)),
-            (Some(start), None) => Some(start),
-            (None, Some(end)) => Some(end),
-            _ => None,
-        }
+        let attributes = self.attributes.iter().map(Spanned::span);
+
+        let items = self.items.iter().map(|(item, semi)| match semi {
+            Some(semi) => item.span().join(semi.span()),
+            None => item.span(),
+        });
+
+        Span::from_spans(attributes.chain(items))
     }
 }
 
@@ -0,0 +1,78 @@
+use crate::ast::prelude::*;
+
+#[test]
+fn ast_parse() {
+    use crate::testing::rt;
+
+    let param = rt::<ast::GenericParam>("T");
+    assert!(param.colon.is_none());
+    assert!(param.bounds.is_empty());
+
+    let param = rt::<ast::GenericParam>("T: Display");
+    assert!(param.colon.is_some());
+    assert_eq!(param.bounds.len(), 1);
+
+    let param = rt::<ast::GenericParam>("T: std::fmt::Display + Clone");
+    assert_eq!(param.bounds.len(), 2);
+
+    rt::<ast::AngleBracketed<ast::GenericParam, T![,]>>("<T, U: Display,>");
+}
+
+/// A generic parameter declared on a function, with optional bounds.
+///
+/// Bounds are currently only recorded for documentation and introspection, they
+/// are not checked.
+///
+/// * `<ident>`.
+/// * `<ident>: <path> (+ <path>)*`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens)]
+#[non_exhaustive]
+pub struct GenericParam {
+    /// The name of the generic parameter.
+    pub name: ast::Ident,
+    /// The colon separating the name from its bounds.
+    #[rune(iter)]
+    pub colon: Option<T![:]>,
+    /// The bounds of the parameter, each followed by an optional `+`.
+    #[rune(iter)]
+    pub bounds: Vec<(ast::Path, Option<T![+]>)>,
+}
+
+impl Parse for GenericParam {
+    fn parse(p: &mut Parser<'_>) -> Result<Self> {
+        let name = p.parse()?;
+        let colon = p.parse::<Option<T![:]>>()?;
+        let mut bounds = Vec::new();
+
+        if colon.is_some() {
+            loop {
+                let path = p.parse()?;
+                let plus = p.parse::<Option<T![+]>>()?;
+                let is_end = plus.is_none();
+                bounds.push((path, plus));
+
+                if is_end {
+                    break;
+                }
+            }
+        }
+
+        Ok(Self {
+            name,
+            colon,
+            bounds,
+        })
+    }
+}
+
+impl Spanned for GenericParam {
+    fn span(&self) -> Span {
+        let span = self.name.span();
+
+        let Some((path, plus)) = self.bounds.last() else {
+            return span.join(self.colon.option_span().unwrap_or(span));
+        };
+
+        span.join(plus.option_span().unwrap_or_else(|| path.span()))
+    }
+}
@@ -36,6 +36,11 @@ fn ast_parse() {
     assert_eq!(item.attributes.len(), 1);
     assert!(item.async_token.is_none());
     assert!(item.const_token.is_some());
+
+    let item = rt::<ast::ItemFn>("fn hello<T, U: Display + Clone>(foo, bar) {}");
+    let generics = item.generics.as_ref().expect("expected generics");
+    assert_eq!(generics.len(), 2);
+    assert_eq!(item.args.len(), 2);
 }
 
 /// A function item.
@@ -62,6 +67,9 @@ pub struct ItemFn {
     pub fn_token: T![fn],
     /// The name of the function.
     pub name: ast::Ident,
+    /// Optional generic parameters of the function.
+    #[rune(iter)]
+    pub generics: Option<ast::AngleBracketed<ast::GenericParam, T![,]>>,
     /// The arguments of the function.
     pub args: ast::Parenthesized<ast::FnArg, T![,]>,
     /// The body of the function.
@@ -72,11 +80,13 @@ impl ItemFn {
     /// Get the descriptive span of this item, e.g. `pub fn foo()` instead of
     /// the span for the whole function declaration, body included.
     pub(crate) fn descriptive_span(&self) -> Span {
-        if let Some(async_token) = &self.async_token {
-            async_token.span().join(self.args.span())
-        } else {
-            self.fn_token.span().join(self.args.span())
-        }
+        let spans = self
+            .async_token
+            .option_span()
+            .into_iter()
+            .chain([self.fn_token.span(), self.args.span()]);
+
+        Span::from_spans(spans).unwrap_or_else(|| self.args.span())
     }
 
     /// Test if function is an instance fn.
@@ -10,6 +10,15 @@ fn ast_parse() {
         "#[variant(enum_= \"SuperHero\", x = \"1\")] impl Foo { fn test(self) { } }",
     );
     rt::<ast::ItemImpl>("#[xyz] impl Foo { #[jit] fn test(self) { } }");
+    rt::<ast::ItemImpl>("impl Foo { const MAX = 100; const fn max() { Self::MAX } }");
+    rt::<ast::ItemImpl>("impl Foo { fn a() { } const A = 1; fn b() { } }");
+
+    let item = rt::<ast::ItemImpl>("impl Foo { fn a() { } const A = 1; }");
+    assert!(matches!(
+        &item.items[..],
+        [ast::ImplItem::Fn(..), ast::ImplItem::Const(..)]
+    ));
+    assert_eq!(item.functions().count(), 1);
 }
 
 /// An impl item.
@@ -25,13 +34,22 @@ pub struct ItemImpl {
     pub path: ast::Path,
     /// The open brace.
     pub open: T!['{'],
-    /// The collection of functions.
-    pub functions: Vec<ast::ItemFn>,
+    /// The associated items, in the order they were declared.
+    #[rune(iter)]
+    pub items: Vec<ImplItem>,
     /// The close brace.
     pub close: T!['}'],
 }
 
 impl ItemImpl {
+    /// Iterate over the associated functions of the `impl` block.
+    pub fn functions(&self) -> impl Iterator<Item = &ast::ItemFn> {
+        self.items.iter().filter_map(|item| match item {
+            ImplItem::Fn(item_fn) => Some(item_fn),
+            ImplItem::Const(..) => None,
+        })
+    }
+
     /// Parse an `impl` item with the given attributes.
     pub(crate) fn parse_with_attributes(
         parser: &mut Parser<'_>,
@@ -41,10 +59,36 @@ impl ItemImpl {
         let path = parser.parse()?;
         let open = parser.parse()?;
 
-        let mut functions = vec![];
+        let mut items = vec![];
 
         while !parser.peek::<ast::CloseBrace>()? {
-            functions.push(ast::ItemFn::parse(parser)?);
+            let attributes = parser.parse()?;
+            let visibility = parser.parse()?;
+            let const_token = parser.parse::<Option<T![const]>>()?;
+
+            match (const_token, parser.nth(0)?) {
+                (Some(const_token), K![ident]) => {
+                    let item = ast::ItemConst::parse_with_meta(
+                        parser,
+                        attributes,
+                        visibility,
+                        const_token,
+                    )?;
+
+                    items.push(ImplItem::Const(item, parser.parse()?));
+                }
+                (const_token, _) => {
+                    let async_token = parser.parse()?;
+
+                    items.push(ImplItem::Fn(ast::ItemFn::parse_with_meta(
+                        parser,
+                        attributes,
+                        visibility,
+                        const_token,
+                        async_token,
+                    )?));
+                }
+            }
         }
 
         let close = parser.parse()?;
@@ -54,10 +98,20 @@ impl ItemImpl {
             impl_,
             path,
             open,
-            functions,
+            items,
             close,
         })
     }
 }
 
+/// An item declared inside of an `impl` block.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+#[non_exhaustive]
+pub enum ImplItem {
+    /// An associated constant.
+    Const(ast::ItemConst, T![;]),
+    /// An associated function.
+    Fn(ast::ItemFn),
+}
+
 item_parse!(Impl, ItemImpl, "impl item");
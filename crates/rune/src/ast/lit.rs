@@ -49,7 +49,37 @@ pub enum Lit {
     Number(ast::LitNumber),
 }
 
+/// The kind of a [Lit], without its value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LitKind {
+    /// A boolean literal
+    Bool,
+    /// A byte literal
+    Byte,
+    /// A string literal
+    Str,
+    /// A byte string literal
+    ByteStr,
+    /// A character literal
+    Char,
+    /// A number literal
+    Number,
+}
+
 impl Lit {
+    /// Get the kind of the literal.
+    pub fn kind(&self) -> LitKind {
+        match self {
+            Lit::Bool(..) => LitKind::Bool,
+            Lit::Byte(..) => LitKind::Byte,
+            Lit::Str(..) => LitKind::Str,
+            Lit::ByteStr(..) => LitKind::ByteStr,
+            Lit::Char(..) => LitKind::Char,
+            Lit::Number(..) => LitKind::Number,
+        }
+    }
+
     /// Test if this is an immediate literal in an expression.
     ///
     /// Here we only test for unambiguous literals which will not be caused by
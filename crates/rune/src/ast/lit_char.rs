@@ -52,7 +52,7 @@ impl<'a> Resolve<'a> for LitChar {
             .source(source_id, span.narrow(1u32))
             .ok_or_else(|| compile::Error::new(span, ResolveErrorKind::BadSlice))?;
 
-        let start = span.start.into_usize();
+        let start = span.narrow(1u32).start.into_usize();
 
         let mut it = string
             .char_indices()
@@ -1,3 +1,5 @@
+use crate::no_std::borrow::Cow;
+
 use crate::ast::prelude::*;
 
 use num::Num;
@@ -12,6 +14,27 @@ fn ast_parse() {
     rt::<ast::LitNumber>("0.42e10");
 }
 
+#[test]
+fn ast_parse_fractional() {
+    use crate::testing::rt;
+
+    for (source, expected) in [
+        ("42", false),
+        ("0x42", false),
+        ("3.14", true),
+        ("1e10", true),
+        ("1.5e10", true),
+    ] {
+        let lit = rt::<ast::LitNumber>(source);
+
+        let ast::NumberSource::Text(text) = lit.source else {
+            panic!("expected text number source for {source}");
+        };
+
+        assert_eq!(text.is_fractional, expected, "{source}");
+    }
+}
+
 /// A number literal.
 ///
 /// * `42`.
@@ -68,6 +91,7 @@ impl<'a> Resolve<'a> for LitNumber {
             .ok_or_else(|| compile::Error::new(span, ResolveErrorKind::BadSlice))?;
 
         if text.is_fractional {
+            let string = strip_underscores(string, 10).ok_or_else(bad_number(span))?;
             let number: f64 = string.parse().map_err(err_span(span))?;
             return Ok(ast::Number::Float(number));
         }
@@ -79,12 +103,45 @@ impl<'a> Resolve<'a> for LitNumber {
             ast::NumberBase::Decimal => (0, 10),
         };
 
-        let number = num::BigInt::from_str_radix(&string[s..], radix).map_err(err_span(span))?;
+        let string = strip_underscores(&string[s..], radix).ok_or_else(bad_number(span))?;
+        let number = num::BigInt::from_str_radix(&string, radix).map_err(err_span(span))?;
         return Ok(ast::Number::Integer(number));
 
         fn err_span<E>(span: Span) -> impl Fn(E) -> compile::Error {
             move |_| compile::Error::new(span, ResolveErrorKind::BadNumberLiteral)
         }
+
+        fn bad_number(span: Span) -> impl Fn() -> compile::Error {
+            move || compile::Error::new(span, ResolveErrorKind::BadNumberLiteral)
+        }
+
+        /// Strip digit separators from a number literal, making sure that each
+        /// underscore sits between two digits of the given radix.
+        fn strip_underscores(string: &str, radix: u32) -> Option<Cow<'_, str>> {
+            if !string.contains('_') {
+                return Some(Cow::Borrowed(string));
+            }
+
+            let mut out = String::with_capacity(string.len());
+            let mut prev = None;
+            let mut it = string.chars().peekable();
+
+            while let Some(c) = it.next() {
+                if c == '_' {
+                    let is_digit = |c: Option<char>| c.map_or(false, |c| c.is_digit(radix));
+
+                    if !is_digit(prev) || !is_digit(it.peek().copied()) {
+                        return None;
+                    }
+                } else {
+                    out.push(c);
+                }
+
+                prev = Some(c);
+            }
+
+            Some(Cow::Owned(out))
+        }
     }
 }
 
@@ -8,12 +8,15 @@ fn ast_parse() {
 
     rt::<ast::LitStr>("\"hello world\"");
     rt::<ast::LitStr>("\"hello\\nworld\"");
+    rt::<ast::LitStr>("r\"C:\\foo\"");
+    rt::<ast::LitStr>("r#\"has \"quotes\"\"#");
 }
 
 /// A string literal.
 ///
 /// * `"Hello World"`.
 /// * `"Hello\nWorld"`.
+/// * `r#"Hello "World""#`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Spanned)]
 #[non_exhaustive]
 pub struct LitStr {
@@ -58,10 +61,10 @@ impl LitStr {
             }
         };
 
-        let span = if text.wrapped {
-            span.narrow(1u32)
-        } else {
-            span
+        let span = match text.raw {
+            Some(hashes) => span.trim_start(2 + hashes).trim_end(1 + hashes),
+            None if text.wrapped => span.narrow(1u32),
+            None => span,
         };
 
         let string = ctx
@@ -16,6 +16,16 @@ fn ast_parse() {
     rt::<ast::Pat>("var");
     rt::<ast::Pat>("_");
     rt::<ast::Pat>("Foo(n)");
+    rt::<ast::Pat>("'a'..='z'");
+    rt::<ast::Pat>("0..10");
+    rt::<ast::Pat>("-10..=-1");
+    rt::<ast::Pat>("b'0'..=b'9'");
+
+    let pat = rt::<ast::Pat>("-1");
+    assert!(matches!(
+        pat,
+        ast::Pat::PatLit(ast::PatLit { ref expr, .. }) if matches!(&**expr, ast::Expr::Unary(..))
+    ));
 }
 
 /// A pattern match.
@@ -38,6 +48,8 @@ pub enum Pat {
     PatBinding(PatBinding),
     /// The rest pattern `..`.
     PatRest(PatRest),
+    /// A range pattern `'a'..='z'` or `0..10`.
+    PatRange(PatRange),
 }
 
 impl Parse for Pat {
@@ -45,17 +57,9 @@ impl Parse for Pat {
         let attributes = p.parse::<Vec<ast::Attribute>>()?;
 
         match p.nth(0)? {
-            K![byte] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: Box::new(ast::Expr::from_lit(ast::Lit::Byte(p.parse()?))),
-                }));
-            }
-            K![char] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: Box::new(ast::Expr::from_lit(ast::Lit::Char(p.parse()?))),
-                }));
+            K![byte] | K![char] | K![number] | K![-] => {
+                let expr = range_bound(p)?;
+                return Self::parse_lit_or_range(p, attributes, expr);
             }
             K![bytestr] => {
                 return Ok(Self::PatLit(PatLit {
@@ -83,12 +87,6 @@ impl Parse for Pat {
                     }),
                 });
             }
-            K![number] => {
-                return Ok(Self::PatLit(PatLit {
-                    attributes,
-                    expr: Box::new(ast::Expr::from_lit(ast::Lit::Number(p.parse()?))),
-                }));
-            }
             K![..] => {
                 return Ok(Self::PatRest(PatRest {
                     attributes,
@@ -119,16 +117,6 @@ impl Parse for Pat {
                     items: p.parse()?,
                 }))
             }
-            K![-] => {
-                let expr: ast::Expr = p.parse()?;
-
-                if expr.is_lit() {
-                    return Ok(Self::PatLit(PatLit {
-                        attributes,
-                        expr: Box::new(expr),
-                    }));
-                }
-            }
             K![_] => {
                 return Ok(Self::PatIgnore(PatIgnore {
                     attributes,
@@ -165,6 +153,60 @@ impl Parse for Pat {
     }
 }
 
+impl Pat {
+    /// Parse what follows a literal which might be the start of a range
+    /// pattern.
+    fn parse_lit_or_range(
+        p: &mut Parser<'_>,
+        attributes: Vec<ast::Attribute>,
+        expr: ast::Expr,
+    ) -> Result<Self> {
+        let limits = match p.nth(0)? {
+            K![..] => ast::ExprRangeLimits::HalfOpen(p.parse()?),
+            K![..=] => ast::ExprRangeLimits::Closed(p.parse()?),
+            _ => {
+                return Ok(Self::PatLit(PatLit {
+                    attributes,
+                    expr: Box::new(expr),
+                }));
+            }
+        };
+
+        Ok(Self::PatRange(PatRange {
+            attributes,
+            start: Box::new(expr),
+            limits,
+            end: Box::new(range_bound(p)?),
+        }))
+    }
+}
+
+/// Parse a literal which can be used as the bound of a range pattern.
+fn range_bound(p: &mut Parser<'_>) -> Result<ast::Expr> {
+    let lit = match p.nth(0)? {
+        K![byte] => ast::Lit::Byte(p.parse()?),
+        K![char] => ast::Lit::Char(p.parse()?),
+        K![number] => ast::Lit::Number(p.parse()?),
+        K![-] => {
+            let expr = ast::Expr::Unary(ast::ExprUnary {
+                attributes: Vec::new(),
+                op: ast::UnOp::Neg(p.parse()?),
+                expr: Box::new(ast::Expr::from_lit(ast::Lit::Number(p.parse()?))),
+            });
+
+            return Ok(expr);
+        }
+        _ => {
+            return Err(compile::Error::expected(
+                p.tok_at(0)?,
+                "range pattern bound",
+            ))
+        }
+    };
+
+    Ok(ast::Expr::from_lit(lit))
+}
+
 impl Peek for Pat {
     fn peek(p: &mut Peeker<'_>) -> bool {
         match p.nth(0) {
@@ -192,6 +234,23 @@ pub struct PatLit {
     pub expr: Box<ast::Expr>,
 }
 
+/// A range pattern.
+///
+/// * `'a'..='z'` or `0..10`.
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
+#[non_exhaustive]
+pub struct PatRange {
+    /// Attributes associated with the range pattern.
+    #[rune(iter)]
+    pub attributes: Vec<ast::Attribute>,
+    /// The start of the range.
+    pub start: Box<ast::Expr>,
+    /// The range limits.
+    pub limits: ast::ExprRangeLimits,
+    /// The end of the range.
+    pub end: Box<ast::Expr>,
+}
+
 /// The rest pattern `..` and associated attributes.
 #[derive(Debug, Clone, PartialEq, Eq, ToTokens, Spanned)]
 #[non_exhaustive]
@@ -13,10 +13,63 @@ fn ast_parse() {
     rt::<ast::Path>("super::bar");
     rt::<ast::Path>("HashMap::<Foo, Bar>");
     rt::<ast::Path>("super::HashMap::<Foo, Bar>");
+
+    assert_eq!(rt::<ast::Path>("foo").span(), Span::new(0, 3));
+    assert_eq!(rt::<ast::Path>("::foo::bar").span(), Span::new(0, 10));
+    assert_eq!(rt::<ast::Path>("Vec::<int>").span(), Span::new(0, 10));
+
+    let path = rt::<ast::Path>("Vec::<int>");
+    assert!(matches!(path.first, PathSegment::Ident(..)));
+    assert!(
+        matches!(&path.rest[..], [(_, PathSegment::Generics(generics))] if generics.len() == 1)
+    );
+
+    let call = rt::<ast::ExprCall>("map::<K, V>()");
+
+    let ast::Expr::Path(path) = &*call.expr else {
+        panic!("expected path, got {:?}", call.expr);
+    };
+
+    assert!(
+        matches!(&path.rest[..], [(Some(..), PathSegment::Generics(generics))] if generics.len() == 2)
+    );
+
+    let path = rt::<ast::Path>("Vec<int>");
+    assert!(matches!(path.first, PathSegment::Ident(..)));
+    assert!(
+        matches!(&path.rest[..], [(None, PathSegment::Generics(generics))] if generics.len() == 1)
+    );
+
+    let path = rt::<ast::Path>("HashMap<String, int>::new");
+    assert!(matches!(
+        &path.rest[..],
+        [
+            (None, PathSegment::Generics(..)),
+            (Some(..), PathSegment::Ident(..))
+        ]
+    ));
+
+    let call = rt::<ast::ExprCall>("map<K, V>()");
+
+    let ast::Expr::Path(path) = &*call.expr else {
+        panic!("expected path, got {:?}", call.expr);
+    };
+
+    assert!(
+        matches!(&path.rest[..], [(None, PathSegment::Generics(generics))] if generics.len() == 2)
+    );
+
+    // Comparisons are not mistaken for generic arguments.
+    let expr = rt::<ast::ExprTuple>("(a < b, b > a)");
+    assert_eq!(expr.items.len(), 2);
+    assert!(matches!(
+        expr.items.first(),
+        Some((ast::Expr::Binary(..), _))
+    ));
 }
 
 /// A path, where each element is separated by a `::`.
-#[derive(Debug, Clone, PartialEq, Eq, Parse, ToTokens, Spanned, Opaque)]
+#[derive(Debug, Clone, PartialEq, Eq, ToTokens, Opaque)]
 #[non_exhaustive]
 pub struct Path {
     /// Opaque id associated with path.
@@ -27,14 +80,30 @@ pub struct Path {
     pub global: Option<T![::]>,
     /// The first component in the path.
     pub first: PathSegment,
-    /// The rest of the components in the path.
+    /// The rest of the components in the path, each preceded by a `::`.
+    ///
+    /// The `::` is only optional for generic arguments, as in `Vec<int>`.
     #[rune(iter)]
-    pub rest: Vec<(T![::], PathSegment)>,
+    pub rest: Vec<(Option<T![::]>, PathSegment)>,
     /// Trailing scope.
     #[rune(iter)]
     pub trailing: Option<T![::]>,
 }
 
+impl Spanned for Path {
+    fn span(&self) -> Span {
+        let spans = self
+            .global
+            .option_span()
+            .into_iter()
+            .chain(iter::once(self.first.span()))
+            .chain(self.rest.iter().map(|(_, segment)| segment.span()))
+            .chain(self.trailing.option_span());
+
+        Span::from_spans(spans).unwrap_or_else(|| self.first.span())
+    }
+}
+
 impl Path {
     /// Identify the kind of the path.
     pub(crate) fn as_kind(&self) -> Option<PathKind<'_>> {
@@ -88,6 +157,88 @@ impl Path {
     }
 }
 
+impl Parse for Path {
+    fn parse(p: &mut Parser<'_>) -> Result<Self> {
+        let global = p.parse()?;
+        let first = p.parse::<PathSegment>()?;
+
+        let mut is_generics = matches!(first, PathSegment::Generics(..));
+        let mut rest = Vec::new();
+
+        loop {
+            let segment = if p.peek::<T![::]>()? {
+                (Some(p.parse()?), p.parse::<PathSegment>()?)
+            } else if !is_generics && peek_generics(p)? {
+                (None, PathSegment::Generics(p.parse()?))
+            } else {
+                break;
+            };
+
+            is_generics = matches!(segment.1, PathSegment::Generics(..));
+            rest.push(segment);
+        }
+
+        Ok(Self {
+            id: Default::default(),
+            global,
+            first,
+            rest,
+            trailing: p.parse()?,
+        })
+    }
+}
+
+/// Test if the upcoming tokens are generic arguments which are not preceded
+/// by a `::`, like `<int>` in `Vec<int>`.
+///
+/// Since `<` might also start a comparison, this only matches arguments which
+/// consist of paths and which are followed by a token that can't continue an
+/// expression. Nested generic arguments closed by `>>` need to be spaced out as
+/// `> >` or use the `::<` form.
+fn peek_generics(p: &mut Parser<'_>) -> Result<bool> {
+    if !matches!(p.nth(0)?, K![<]) {
+        return Ok(false);
+    }
+
+    let mut depth = 0usize;
+    let mut n = 0;
+
+    loop {
+        match p.nth(n)? {
+            K![<] => {
+                depth += 1;
+            }
+            K![>] => {
+                depth -= 1;
+
+                if depth == 0 {
+                    break;
+                }
+            }
+            K![ident] | K![::] | K![,] | K![Self] | K![self] | K![crate] | K![super] => {}
+            _ => return Ok(false),
+        }
+
+        n += 1;
+    }
+
+    Ok(matches!(
+        p.nth(n + 1)?,
+        K![::]
+            | K!['(']
+            | K![')']
+            | K![']']
+            | K!['}']
+            | K![,]
+            | K![;]
+            | K![=]
+            | K![=>]
+            | K![>]
+            | K![+]
+            | ast::Kind::Eof
+    ))
+}
+
 impl Peek for Path {
     fn peek(p: &mut Peeker<'_>) -> bool {
         matches!(p.nth(0), K![::]) || PathSegment::peek(p)
@@ -132,8 +283,10 @@ impl<'a> Resolve<'a> for Path {
             }
         }
 
-        for (_, segment) in &self.rest {
-            buf.push_str("::");
+        for (cc, segment) in &self.rest {
+            if cc.is_some() {
+                buf.push_str("::");
+            }
 
             match segment {
                 PathSegment::SelfType(_) => {
@@ -190,6 +343,10 @@ pub enum PathSegment {
     /// The `super` keyword use as a path segment.
     Super(T![super]),
     /// A path segment that is a generic argument.
+    ///
+    /// Generic arguments are either preceded by a scope, as in `Vec::<int>`,
+    /// or directly follow a segment, as in `Vec<int>` when they can't be
+    /// confused with a comparison.
     Generics(ast::AngleBracketed<PathSegmentExpr, T![,]>),
 }
 
@@ -111,6 +111,32 @@ impl Span {
         }
     }
 
+    /// Construct the smallest span which covers all of the given spans, or
+    /// `None` if there are no spans.
+    ///
+    /// The spans do not need to be provided in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::ast::Span;
+    ///
+    /// assert_eq!(Span::from_spans([]), None);
+    /// assert_eq!(Span::from_spans([Span::new(4, 8)]), Some(Span::new(4, 8)));
+    ///
+    /// let spans = [Span::new(20, 22), Span::new(10, 12), Span::new(14, 30)];
+    /// assert_eq!(Span::from_spans(spans), Some(Span::new(10, 30)));
+    ///
+    /// let spans = [Span::new(10, 40), Span::new(15, 20)];
+    /// assert_eq!(Span::from_spans(spans), Some(Span::new(10, 40)));
+    /// ```
+    pub fn from_spans<I>(spans: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = Span>,
+    {
+        spans.into_iter().reduce(Self::join)
+    }
+
     /// Narrow the span with the given amount.
     ///
     /// If the narrowing causes the span to become empty, the resulting span
@@ -69,6 +69,14 @@ impl Token {
                 }
             },
             Kind::Str(s) => match s {
+                StrSource::Text(text) if text.raw.is_some() => {
+                    let s = ctx
+                        .q
+                        .sources
+                        .source(text.source_id, self.span)
+                        .ok_or(fmt::Error)?;
+                    write!(f, "{}", s)?;
+                }
                 StrSource::Text(text) => {
                     let span = if text.wrapped {
                         self.span.narrow(1u32)
@@ -360,6 +368,9 @@ pub struct StrText {
     pub escaped: bool,
     /// Indicated if the buffer is wrapped or not.
     pub wrapped: bool,
+    /// The number of `#` delimiting the string if it's a raw string, like
+    /// `r#"..."#`.
+    pub raw: Option<u32>,
 }
 
 /// The source of a number.
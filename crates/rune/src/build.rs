@@ -8,9 +8,12 @@ use crate::runtime::unit::{DefaultStorage, UnitEncoder};
 
 use thiserror::Error;
 
-use crate::ast::Span;
+use crate::ast::{LitKind, Span};
 use crate::compile;
-use crate::compile::{CompileVisitor, FileSourceLoader, Options, Pool, SourceLoader};
+use crate::compile::{
+    CompileVisitor, CompiledItems, DependencyGraph, FileSourceLoader, Options, Pool, Prelude,
+    SourceLoader,
+};
 use crate::runtime::Unit;
 use crate::{Context, Diagnostics, SourceId, Sources};
 
@@ -78,8 +81,12 @@ where
         context: None,
         diagnostics: None,
         options: None,
+        prelude: None,
         visitors: Vec::new(),
         source_loader: None,
+        unused_sources: None,
+        compiled_items: None,
+        dependency_graph: None,
         _unit_storage: PhantomData,
     }
 }
@@ -90,8 +97,12 @@ pub struct Build<'a, S> {
     context: Option<&'a Context>,
     diagnostics: Option<&'a mut Diagnostics>,
     options: Option<&'a Options>,
+    prelude: Option<&'a Prelude>,
     visitors: Vec<&'a mut dyn compile::CompileVisitor>,
     source_loader: Option<&'a mut dyn SourceLoader>,
+    unused_sources: Option<&'a [SourceId]>,
+    compiled_items: Option<&'a mut CompiledItems>,
+    dependency_graph: Option<&'a mut DependencyGraph>,
     _unit_storage: PhantomData<S>,
 }
 
@@ -125,6 +136,18 @@ impl<'a> compile::CompileVisitor for CompileVisitorGroup<'a> {
         }
     }
 
+    fn visit_unused_import(&mut self, location: compile::Location, item: &compile::Item) {
+        for v in self.visitors.iter_mut() {
+            v.visit_unused_import(location, item)
+        }
+    }
+
+    fn visit_literal(&mut self, location: compile::Location, kind: LitKind) {
+        for v in self.visitors.iter_mut() {
+            v.visit_literal(location, kind)
+        }
+    }
+
     fn visit_doc_comment(
         &mut self,
         location: compile::Location,
@@ -178,6 +201,42 @@ impl<'a, S> Build<'a, S> {
         self
     }
 
+    /// Modify the current [Build] to use the given [Prelude].
+    ///
+    /// If unspecified, a prelude appropriate for the current [Context] will be
+    /// constructed for every build. Hosts which perform many small builds can
+    /// construct a [Prelude] once and share it across them, or use a custom
+    /// prelude to control which names are available without being imported.
+    ///
+    /// Note that this only shares the name mapping of the prelude, items are
+    /// still resolved against the [Context] for every build.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::compile::Prelude;
+    /// use rune::{Context, Source, Sources};
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let prelude = Prelude::with_context(&context);
+    ///
+    /// for n in 0..4 {
+    ///     let mut sources = Sources::new();
+    ///     sources.insert(Source::new("entry", format!("pub fn main() {{ Some({n}) }}")));
+    ///
+    ///     let unit = rune::prepare(&mut sources)
+    ///         .with_context(&context)
+    ///         .with_prelude(&prelude)
+    ///         .build()?;
+    /// }
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    #[inline]
+    pub fn with_prelude(mut self, prelude: &'a Prelude) -> Self {
+        self.prelude = Some(prelude);
+        self
+    }
+
     /// Modify the current [Build] to configure the given [CompileVisitor].
     ///
     /// A compile visitor allows for custom collecting of compile-time metadata.
@@ -199,6 +258,39 @@ impl<'a, S> Build<'a, S> {
         self
     }
 
+    /// Modify the current [Build] to only build unused entries which belong to
+    /// the given sources.
+    ///
+    /// By default every indexed item is built, even if nothing refers to it.
+    /// When incrementally rebuilding a project, such as in a language server,
+    /// this can be used so that editing one file doesn't force building unused
+    /// items from untouched files. Items from other sources are still built if
+    /// they are used.
+    #[inline]
+    pub fn with_unused_entries_from(mut self, sources: &'a [SourceId]) -> Self {
+        self.unused_sources = Some(sources);
+        self
+    }
+
+    /// Modify the current [Build] to collect every item which was resolved
+    /// while building into the given [CompiledItems].
+    ///
+    /// This is read-only, collecting items doesn't cause anything else to be
+    /// built.
+    #[inline]
+    pub fn with_compiled_items(mut self, compiled_items: &'a mut CompiledItems) -> Self {
+        self.compiled_items = Some(compiled_items);
+        self
+    }
+
+    /// Modify the current [Build] to record which items each built item
+    /// references into the given [DependencyGraph].
+    #[inline]
+    pub fn with_dependency_graph(mut self, dependency_graph: &'a mut DependencyGraph) -> Self {
+        self.dependency_graph = Some(dependency_graph);
+        self
+    }
+
     /// Build a [`Unit`] with the current configuration.
     pub fn build(mut self) -> Result<Unit<S>, BuildError>
     where
@@ -216,10 +308,14 @@ impl<'a, S> Build<'a, S> {
 
         let mut unit = compile::UnitBuilder::default();
 
-        let prelude = if context.has_default_modules() {
-            compile::Prelude::with_default_prelude()
-        } else {
-            compile::Prelude::default()
+        let default_prelude;
+
+        let prelude = match self.prelude.take() {
+            Some(prelude) => prelude,
+            None => {
+                default_prelude = Prelude::with_context(context);
+                &default_prelude
+            }
         };
 
         let mut default_diagnostics;
@@ -271,7 +367,7 @@ impl<'a, S> Build<'a, S> {
 
         let result = compile::compile(
             &mut unit,
-            &prelude,
+            prelude,
             self.sources,
             &mut pool,
             context,
@@ -280,6 +376,9 @@ impl<'a, S> Build<'a, S> {
             visitors,
             source_loader,
             &mut unit_storage,
+            self.unused_sources,
+            self.compiled_items.take(),
+            self.dependency_graph.take(),
         );
 
         if let Err(()) = result {
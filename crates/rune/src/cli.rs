@@ -572,6 +572,16 @@ struct SharedFlags {
     /// macros[=<true/false>] - Enable or disable macros (experimental).
     ///
     /// bytecode[=<true/false>] - Enable or disable bytecode caching (experimental).
+    ///
+    /// file-modules[=<true/false>] - Enable or disable loading modules from files.
+    ///
+    /// warn-shadowing[=<true/false>] - Warn when a `let` binding shadows an existing local.
+    ///
+    /// warn-unreachable[=<true/false>] - Warn about statements following a `return`, `break` or `continue`.
+    ///
+    /// import-recursion-limit=<number> - The number of re-exports an import may be followed through.
+    ///
+    /// const-eval-budget=<number> - The number of evaluations permitted for a single constant expression.
     #[arg(name = "option", short = 'O', number_of_values = 1)]
     compiler_options: Vec<String>,
 
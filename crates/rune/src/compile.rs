@@ -14,6 +14,12 @@ pub(crate) use self::error::{
 };
 pub use self::error::{Error, ImportStep};
 
+mod compiled_items;
+pub use self::compiled_items::{CompiledItem, CompiledItems};
+
+mod dependency_graph;
+pub use self::dependency_graph::DependencyGraph;
+
 mod compile_visitor;
 pub use self::compile_visitor::CompileVisitor;
 pub(crate) use self::compile_visitor::NoopCompileVisitor;
@@ -21,6 +27,9 @@ pub(crate) use self::compile_visitor::NoopCompileVisitor;
 pub(crate) mod context;
 pub use self::context::Context;
 
+mod api_snapshot;
+pub use self::api_snapshot::{ApiDiff, ApiFieldsSignature, ApiItem, ApiSignature, ApiSnapshot};
+
 pub(crate) mod context_error;
 pub use self::context_error::ContextError;
 
@@ -31,7 +40,7 @@ mod docs;
 pub(crate) use self::docs::Docs;
 
 mod prelude;
-pub(crate) use self::prelude::Prelude;
+pub use self::prelude::Prelude;
 
 pub(crate) mod ir;
 pub(crate) use self::ir::{IrBudget, IrCompiler, IrEvalContext, IrEvalOutcome, IrInterpreter};
@@ -68,7 +77,7 @@ mod names;
 pub(crate) use self::names::Names;
 
 mod visibility;
-pub(crate) use self::visibility::Visibility;
+pub use self::visibility::Visibility;
 
 mod with_span;
 pub use self::with_span::{HasSpan, WithSpan};
@@ -87,7 +96,7 @@ use crate::query::{Build, BuildEntry, Query};
 use crate::runtime::unit::UnitEncoder;
 use crate::shared::{Consts, Gen};
 use crate::worker::{LoadFileKind, Task, Worker};
-use crate::{Diagnostics, Sources};
+use crate::{Diagnostics, SourceId, Sources};
 
 /// Encode the given object into a collection of asm.
 pub(crate) fn compile(
@@ -101,6 +110,9 @@ pub(crate) fn compile(
     visitor: &mut dyn CompileVisitor,
     source_loader: &mut dyn SourceLoader,
     unit_storage: &mut dyn UnitEncoder,
+    unused_sources: Option<&[SourceId]>,
+    compiled_items: Option<&mut CompiledItems>,
+    dependency_graph: Option<&mut DependencyGraph>,
 ) -> Result<(), ()> {
     // Shared id generator.
     let gen = Gen::new();
@@ -111,6 +123,7 @@ pub(crate) fn compile(
     let q = Query::new(
         unit,
         prelude,
+        options,
         &mut consts,
         &mut storage,
         sources,
@@ -163,7 +176,14 @@ pub(crate) fn compile(
             }
         }
 
-        match worker.q.queue_unused_entries() {
+        let queued = match unused_sources {
+            Some(sources) => worker
+                .q
+                .queue_unused_entries_by(|source_id| sources.contains(&source_id)),
+            None => worker.q.queue_unused_entries(),
+        };
+
+        match queued {
             Ok(true) => (),
             Ok(false) => break,
             Err((source_id, error)) => {
@@ -172,6 +192,14 @@ pub(crate) fn compile(
         }
     }
 
+    if let Some(compiled_items) = compiled_items {
+        compiled_items.collect(worker.q.pool, worker.q.iter_meta());
+    }
+
+    if let Some(dependency_graph) = dependency_graph {
+        dependency_graph.collect(worker.q.pool, worker.q.iter_dependencies());
+    }
+
     if worker.diagnostics.has_error() {
         return Err(());
     }
@@ -189,12 +217,14 @@ struct CompileBuildEntry<'a> {
 impl CompileBuildEntry<'_> {
     fn compiler1<'a>(
         &'a mut self,
+        item: ItemId,
         location: Location,
         span: Span,
         asm: &'a mut Assembly,
     ) -> self::v1::Assembler<'a> {
         self::v1::Assembler {
             source_id: location.source_id,
+            item,
             context: self.context,
             q: self.q.borrow(),
             asm,
@@ -249,7 +279,7 @@ impl CompileBuildEntry<'_> {
                 let arena = hir::Arena::new();
                 let ctx = hir::lowering::Ctx::new(&arena, self.q.borrow());
                 let hir = hir::lowering::item_fn(&ctx, &f.ast)?;
-                let mut c = self.compiler1(location, span, &mut asm);
+                let mut c = self.compiler1(item_meta.item, location, span, &mut asm);
                 assemble::fn_from_item_fn(&hir, &mut c, false)?;
 
                 if used.is_unused() {
@@ -277,7 +307,7 @@ impl CompileBuildEntry<'_> {
                 let span = f.ast.span();
                 let count = f.ast.args.len();
 
-                let mut c = self.compiler1(location, span, &mut asm);
+                let mut c = self.compiler1(item_meta.item, location, span, &mut asm);
                 let meta = c.lookup_meta(
                     f.instance_span,
                     f.impl_item,
@@ -326,7 +356,7 @@ impl CompileBuildEntry<'_> {
                 let arena = hir::Arena::new();
                 let ctx = hir::lowering::Ctx::new(&arena, self.q.borrow());
                 let hir = hir::lowering::expr_closure(&ctx, &closure.ast)?;
-                let mut c = self.compiler1(location, span, &mut asm);
+                let mut c = self.compiler1(item_meta.item, location, span, &mut asm);
                 assemble::closure_from_expr_closure(span, &mut c, &hir, &closure.captures)?;
 
                 if used.is_unused() {
@@ -356,7 +386,7 @@ impl CompileBuildEntry<'_> {
                 let ctx = hir::lowering::Ctx::new(&arena, self.q.borrow());
                 let hir = hir::lowering::block(&ctx, &b.ast)?;
 
-                let mut c = self.compiler1(location, span, &mut asm);
+                let mut c = self.compiler1(item_meta.item, location, span, &mut asm);
                 assemble::closure_from_block(&hir, &mut c, &b.captures)?;
 
                 if used.is_unused() {
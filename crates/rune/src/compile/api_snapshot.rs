@@ -0,0 +1,208 @@
+use core::fmt;
+
+use crate::no_std::collections::BTreeMap;
+use crate::no_std::prelude::*;
+
+use serde::{Deserialize, Serialize};
+
+use crate::compile::context::ContextMeta;
+use crate::compile::meta;
+use crate::Hash;
+
+/// A snapshot of all items registered in a [`Context`], which can be compared
+/// against another snapshot to detect API changes.
+///
+/// See [`Context::snapshot`].
+///
+/// [`Context`]: crate::Context
+/// [`Context::snapshot`]: crate::Context::snapshot
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApiSnapshot {
+    items: BTreeMap<Hash, ApiItem>,
+}
+
+impl ApiSnapshot {
+    pub(crate) fn insert(&mut self, meta: &ContextMeta) {
+        let name = match &meta.item {
+            Some(item) => item.to_string(),
+            None => meta.hash.to_string(),
+        };
+
+        let signature = match &meta.kind {
+            meta::Kind::Function { signature, .. }
+            | meta::Kind::AssociatedFunction { signature, .. } => Some(ApiSignature::Function {
+                args: signature.args,
+            }),
+            meta::Kind::Struct { fields, .. } | meta::Kind::Variant { fields, .. } => {
+                Some(ApiSignature::Fields(ApiFieldsSignature::new(fields)))
+            }
+            _ => None,
+        };
+
+        self.items.insert(
+            meta.hash,
+            ApiItem {
+                name,
+                kind: meta.info().kind_name().into(),
+                signature,
+            },
+        );
+    }
+
+    /// Iterate over all items in the snapshot.
+    pub fn iter(&self) -> impl Iterator<Item = &ApiItem> {
+        self.items.values()
+    }
+
+    /// Compare this snapshot against a newer snapshot `other`, categorizing
+    /// the items which have been added, removed or changed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Context, Module};
+    ///
+    /// let mut m = Module::with_crate("api");
+    /// m.function(["add"], |a: i64, b: i64| a + b)?;
+    /// m.function(["sub"], |a: i64, b: i64| a - b)?;
+    ///
+    /// let mut old = Context::new();
+    /// old.install(m)?;
+    ///
+    /// let mut m = Module::with_crate("api");
+    /// m.function(["add"], |a: i64, b: i64, c: i64| a + b + c)?;
+    ///
+    /// let mut new = Context::new();
+    /// new.install(m)?;
+    ///
+    /// let diff = old.snapshot().diff(&new.snapshot());
+    /// assert_eq!(diff.removed, ["::api::sub"]);
+    /// assert_eq!(diff.changed, ["::api::add"]);
+    /// assert!(diff.added.is_empty());
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn diff(&self, other: &ApiSnapshot) -> ApiDiff {
+        let mut diff = ApiDiff::default();
+
+        for (hash, item) in &self.items {
+            match other.items.get(hash) {
+                Some(new) if new != item => diff.changed.push(item.name.clone()),
+                Some(..) => {}
+                None => diff.removed.push(item.name.clone()),
+            }
+        }
+
+        for (hash, item) in &other.items {
+            if !self.items.contains_key(hash) {
+                diff.added.push(item.name.clone());
+            }
+        }
+
+        diff.added.sort();
+        diff.removed.sort();
+        diff.changed.sort();
+        diff
+    }
+}
+
+/// A single item in an [`ApiSnapshot`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ApiItem {
+    /// The path of the item, or its hash if it is not addressable by path.
+    pub name: String,
+    /// The kind of the item, like `fn` or `struct`.
+    pub kind: String,
+    /// The signature of the item, if it has one.
+    pub signature: Option<ApiSignature>,
+}
+
+/// The signature of an [`ApiItem`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ApiSignature {
+    /// A function with the given number of arguments, if known.
+    Function {
+        /// The number of arguments.
+        args: Option<usize>,
+    },
+    /// A type or variant with the given fields.
+    Fields(ApiFieldsSignature),
+}
+
+/// The fields of a struct or variant in an [`ApiItem`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ApiFieldsSignature {
+    /// Named fields, in sorted order.
+    Named(Vec<String>),
+    /// The given number of unnamed fields.
+    Unnamed(usize),
+    /// No fields.
+    Empty,
+}
+
+impl ApiFieldsSignature {
+    fn new(fields: &meta::Fields) -> Self {
+        match fields {
+            meta::Fields::Named(named) => {
+                let mut names = named
+                    .fields
+                    .iter()
+                    .map(|f| f.to_string())
+                    .collect::<Vec<_>>();
+                names.sort();
+                Self::Named(names)
+            }
+            meta::Fields::Unnamed(n) => Self::Unnamed(*n),
+            meta::Fields::Empty => Self::Empty,
+        }
+    }
+}
+
+/// The difference between two [`ApiSnapshot`]s, as produced by
+/// [`ApiSnapshot::diff`].
+///
+/// Each collection contains the names of the affected items in sorted order.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct ApiDiff {
+    /// Items which only exist in the newer snapshot.
+    pub added: Vec<String>,
+    /// Items which only exist in the older snapshot.
+    pub removed: Vec<String>,
+    /// Items which exist in both snapshots, but whose kind or signature has
+    /// changed.
+    pub changed: Vec<String>,
+}
+
+impl ApiDiff {
+    /// Test if the diff contains no changes.
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+
+    /// Test if the diff contains any changes which might break users of the
+    /// older API, which is any removed or changed item.
+    pub fn is_breaking(&self) -> bool {
+        !self.removed.is_empty() || !self.changed.is_empty()
+    }
+}
+
+impl fmt::Display for ApiDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for name in &self.added {
+            writeln!(f, "+ {name}")?;
+        }
+
+        for name in &self.removed {
+            writeln!(f, "- {name}")?;
+        }
+
+        for name in &self.changed {
+            writeln!(f, "~ {name}")?;
+        }
+
+        Ok(())
+    }
+}
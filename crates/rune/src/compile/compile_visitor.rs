@@ -1,4 +1,4 @@
-use crate::ast::Span;
+use crate::ast::{LitKind, Span};
 use crate::compile::{Item, Location, MetaRef};
 use crate::hash::Hash;
 use crate::SourceId;
@@ -17,6 +17,20 @@ pub trait CompileVisitor {
     /// Visit something that is a module.
     fn visit_mod(&mut self, _source_id: SourceId, _span: Span) {}
 
+    /// Visit an import which was never used.
+    ///
+    /// The item is the name the import brings into scope. Wildcard imports
+    /// are never visited, since their individual members can't be tracked,
+    /// and neither are re-exports like `pub use`.
+    fn visit_unused_import(&mut self, _location: Location, _item: &Item) {}
+
+    /// Visit a literal value as it's being compiled, such as a string or a
+    /// number.
+    ///
+    /// Negative number literals like `-42` are visited with the span of the
+    /// number excluding its sign.
+    fn visit_literal(&mut self, _location: Location, _kind: LitKind) {}
+
     /// Visit anterior `///`-style comments, and interior `//!`-style doc
     /// comments for an item.
     ///
@@ -0,0 +1,110 @@
+use crate::no_std::prelude::*;
+
+use crate::compile::{meta, ItemBuf, Location, Pool, Visibility};
+use crate::Hash;
+
+/// A read-only collection of every item which was resolved while building a
+/// unit.
+///
+/// This is populated by passing it to [`Build::with_compiled_items`].
+///
+/// # Examples
+///
+/// ```
+/// use rune::compile::{meta, CompiledItems};
+/// use rune::{Context, Source, Sources};
+///
+/// let context = Context::with_default_modules()?;
+///
+/// let mut sources = Sources::new();
+/// sources.insert(Source::new("entry", r#"
+/// pub fn main() { helper() }
+/// fn helper() { 42 }
+/// "#));
+///
+/// let mut items = CompiledItems::new();
+///
+/// let unit = rune::prepare(&mut sources)
+///     .with_context(&context)
+///     .with_compiled_items(&mut items)
+///     .build()?;
+///
+/// let public = items
+///     .iter()
+///     .filter(|i| i.is_public && matches!(i.kind, meta::Kind::Function { .. }))
+///     .map(|i| i.item.to_string())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(public, ["main"]);
+/// # Ok::<_, rune::Error>(())
+/// ```
+///
+/// [`Build::with_compiled_items`]: crate::Build::with_compiled_items
+#[derive(Debug, Default, Clone)]
+pub struct CompiledItems {
+    items: Vec<CompiledItem>,
+}
+
+impl CompiledItems {
+    /// Construct a new empty collection of compiled items.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Populate the collection from resolved metadata, replacing anything
+    /// collected by a previous build.
+    pub(crate) fn collect<'a, I>(&mut self, pool: &Pool, metas: I)
+    where
+        I: IntoIterator<Item = &'a meta::Meta>,
+    {
+        self.items.clear();
+
+        for meta in metas {
+            self.items.push(CompiledItem {
+                item: pool.item(meta.item_meta.item).to_owned(),
+                hash: meta.hash,
+                kind: meta.kind.clone(),
+                visibility: meta.item_meta.visibility,
+                is_public: meta.item_meta.is_public(pool),
+                location: meta.item_meta.location,
+            });
+        }
+
+        self.items
+            .sort_by(|a, b| (&a.item, a.hash).cmp(&(&b.item, b.hash)));
+    }
+
+    /// Iterate over all compiled items, ordered by their item path.
+    pub fn iter(&self) -> impl Iterator<Item = &CompiledItem> {
+        self.items.iter()
+    }
+
+    /// Get the number of compiled items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Test if no items were compiled.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+}
+
+/// A single item in [`CompiledItems`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub struct CompiledItem {
+    /// The path of the item.
+    pub item: ItemBuf,
+    /// The hash of the item.
+    pub hash: Hash,
+    /// The kind of the item.
+    pub kind: meta::Kind,
+    /// The declared visibility of the item.
+    pub visibility: Visibility,
+    /// If the item is exported, which requires it and every module it's
+    /// declared in to be public.
+    pub is_public: bool,
+    /// Where the item is declared.
+    pub location: Location,
+}
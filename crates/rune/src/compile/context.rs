@@ -7,7 +7,9 @@ use crate::no_std::sync::Arc;
 use crate::compile::meta;
 #[cfg(feature = "doc")]
 use crate::compile::Docs;
-use crate::compile::{ComponentRef, ContextError, IntoComponent, Item, ItemBuf, MetaInfo, Names};
+use crate::compile::{
+    ApiSnapshot, ComponentRef, ContextError, IntoComponent, Item, ItemBuf, MetaInfo, Names,
+};
 use crate::module::{
     Fields, Function, InternalEnum, Module, ModuleAssociated, ModuleConstant, ModuleFunction,
     ModuleMacro, ModuleType, TypeSpecification, UnitType,
@@ -19,7 +21,7 @@ use crate::runtime::{
 use crate::Hash;
 
 /// Context metadata.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[non_exhaustive]
 pub(crate) struct ContextMeta {
     /// Type hash for the given meta item.
@@ -31,6 +33,11 @@ pub(crate) struct ContextMeta {
     /// Documentation associated with a context meta.
     #[cfg(feature = "doc")]
     pub(crate) docs: Docs,
+    /// Deprecation notice associated with a context meta.
+    pub(crate) deprecated: Option<&'static str>,
+    /// If the context meta is marked as unstable.
+    #[cfg(feature = "doc")]
+    pub(crate) unstable: bool,
 }
 
 impl ContextMeta {
@@ -73,7 +80,7 @@ impl fmt::Display for ContextType {
 /// * Native functions.
 /// * Native instance functions.
 /// * And native type definitions.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct Context {
     /// Unique modules installed in the context.
     unique: HashSet<&'static str>,
@@ -90,6 +97,12 @@ pub struct Context {
     /// Information on associated types.
     #[cfg(feature = "doc")]
     associated: HashMap<Hash, Vec<Hash>>,
+    /// Named instance functions registered for each type, in the order that
+    /// they were registered.
+    instance_methods: HashMap<Hash, Vec<(Box<str>, Hash)>>,
+    /// Protocols implemented by each type, in the order that they were
+    /// registered.
+    protocols: HashMap<Hash, Vec<Protocol>>,
     /// Registered native macro handlers.
     macros: HashMap<Hash, Arc<MacroHandler>>,
     /// Registered types.
@@ -147,6 +160,7 @@ impl Context {
         this.install(crate::modules::object::module()?)?;
         this.install(crate::modules::ops::module()?)?;
         this.install(crate::modules::option::module()?)?;
+        this.install(crate::modules::rc::module()?)?;
         this.install(crate::modules::result::module()?)?;
         this.install(crate::modules::stream::module()?)?;
         this.install(crate::modules::string::module()?)?;
@@ -191,8 +205,68 @@ impl Context {
     where
         M: AsRef<Module>,
     {
-        let module = module.as_ref();
+        self.install_with(module.as_ref(), Err)
+    }
 
+    /// Validate the specified module against the context without installing
+    /// it.
+    ///
+    /// Unlike [`Context::install`], which stops at the first problem it
+    /// encounters, this collects every problem which would prevent the module
+    /// from being installed. Such as associated functions registered for types
+    /// which are neither registered in the context nor in the module itself,
+    /// or types, functions, macros and constants which conflict with ones
+    /// already in the context.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Any, Context, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Unregistered;
+    ///
+    /// impl Unregistered {
+    ///     fn len(&self) -> usize {
+    ///         0
+    ///     }
+    /// }
+    ///
+    /// let mut module = Module::new();
+    /// module.associated_function("len", Unregistered::len)?;
+    ///
+    /// let context = Context::new();
+    /// assert_eq!(context.validate(&module).len(), 1);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn validate<M>(&self, module: M) -> Vec<ContextError>
+    where
+        M: AsRef<Module>,
+    {
+        let mut errors = Vec::new();
+
+        // Install into a scratch copy of the context, so that the module is
+        // checked by exactly the same rules as when it's installed.
+        let mut scratch = self.clone();
+
+        let _ = scratch.install_with(module.as_ref(), |error| {
+            errors.push(error);
+            Ok(())
+        });
+
+        errors
+    }
+
+    /// Install the specified module, calling `report` with every error that
+    /// is encountered.
+    ///
+    /// If `report` returns an error, installation stops. Otherwise the
+    /// offending part of the module is skipped and installation continues.
+    fn install_with(
+        &mut self,
+        module: &Module,
+        mut report: impl FnMut(ContextError) -> Result<(), ContextError>,
+    ) -> Result<(), ContextError> {
         if let Some(id) = module.unique {
             if !self.unique.insert(id) {
                 return Ok(());
@@ -203,39 +277,75 @@ impl Context {
             self.crates.insert(name.into());
         }
 
-        self.install_module(module)?;
+        if let Err(error) = self.install_module(module) {
+            report(error)?;
+        }
 
         for ty in &module.types {
-            self.install_type(module, ty)?;
+            if let Err(error) = self.install_type(module, ty) {
+                report(error)?;
+            }
         }
 
         for f in &module.functions {
-            self.install_function(module, f)?;
+            if let Err(error) = self.install_function(module, f) {
+                report(error)?;
+            }
         }
 
         for m in &module.macros {
-            self.install_macro(module, m)?;
+            if let Err(error) = self.install_macro(module, m) {
+                report(error)?;
+            }
         }
 
         for m in &module.constants {
-            self.install_constant(module, m)?;
+            if let Err(error) = self.install_constant(module, m) {
+                report(error)?;
+            }
         }
 
         if let Some(unit_type) = &module.unit_type {
-            self.install_unit_type(module, unit_type)?;
+            if let Err(error) = self.install_unit_type(module, unit_type) {
+                report(error)?;
+            }
         }
 
         for internal_enum in &module.internal_enums {
-            self.install_internal_enum(module, internal_enum)?;
+            if let Err(error) = self.install_internal_enum(module, internal_enum) {
+                report(error)?;
+            }
         }
 
         for assoc in &module.associated {
-            self.install_associated(assoc)?;
+            if let Err(error) = self.install_associated(assoc) {
+                report(error)?;
+            }
         }
 
         Ok(())
     }
 
+    /// Take a snapshot of all items registered in the context.
+    ///
+    /// Snapshots of two different versions of a context can be compared using
+    /// [`ApiSnapshot::diff`] to detect changes to the API they expose.
+    pub fn snapshot(&self) -> ApiSnapshot {
+        let mut snapshot = ApiSnapshot::default();
+
+        for meta in &self.meta {
+            snapshot.insert(meta);
+        }
+
+        snapshot
+    }
+
+    /// Iterate over all metadata in the [Context].
+    #[cfg(feature = "doc")]
+    pub(crate) fn iter_meta(&self) -> impl Iterator<Item = &ContextMeta> {
+        self.meta.iter()
+    }
+
     /// Iterate over all available functions in the [Context].
     #[cfg(feature = "cli")]
     pub(crate) fn iter_functions(&self) -> impl Iterator<Item = (&ContextMeta, &meta::Signature)> {
@@ -291,7 +401,6 @@ impl Context {
     }
 
     /// Lookup meta by its hash.
-    #[cfg(feature = "doc")]
     pub(crate) fn lookup_meta_by_hash(
         &self,
         hash: Hash,
@@ -305,6 +414,13 @@ impl Context {
         indexes.iter().map(|&i| &self.meta[i])
     }
 
+    /// Lookup the deprecation notice of the item with the given hash, if it
+    /// has been deprecated.
+    pub(crate) fn lookup_deprecation(&self, hash: Hash) -> Option<&'static str> {
+        self.lookup_meta_by_hash(hash)
+            .find_map(|meta| meta.deprecated)
+    }
+
     /// Check if unit contains the given name by prefix.
     pub(crate) fn contains_prefix(&self, item: &Item) -> bool {
         self.names.contains_prefix(item)
@@ -326,6 +442,73 @@ impl Context {
             .copied()
     }
 
+    /// Iterate over the named instance functions which have been registered
+    /// for the type with the given hash, in the order that they were
+    /// registered.
+    ///
+    /// Each function is returned as its name and the hash it's registered
+    /// under.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Context;
+    /// use rune::runtime::TypeOf;
+    ///
+    /// let context = Context::with_default_modules()?;
+    ///
+    /// let names = context
+    ///     .instance_methods_of(String::type_hash())
+    ///     .map(|(name, _)| name)
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(names.contains(&"len"));
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn instance_methods_of(&self, hash: Hash) -> impl Iterator<Item = (&str, Hash)> + '_ {
+        self.instance_methods
+            .get(&hash)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+            .iter()
+            .map(|(name, hash)| (name.as_ref(), *hash))
+    }
+
+    /// Get the protocols implemented by the type with the given hash, in the
+    /// order that they were registered.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::fmt::{self, Write};
+    ///
+    /// use rune::runtime::{Protocol, TypeOf};
+    /// use rune::{Any, Context, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Point;
+    ///
+    /// impl Point {
+    ///     fn display(&self, f: &mut String) -> fmt::Result {
+    ///         write!(f, "Point")
+    ///     }
+    /// }
+    ///
+    /// let mut module = Module::new();
+    /// module.ty::<Point>()?;
+    /// module.associated_function(Protocol::STRING_DISPLAY, Point::display)?;
+    ///
+    /// let mut context = Context::new();
+    /// context.install(module)?;
+    ///
+    /// let protocols = context.implemented_protocols(<Point as TypeOf>::type_hash());
+    /// assert_eq!(protocols, [Protocol::STRING_DISPLAY]);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn implemented_protocols(&self, hash: Hash) -> Vec<Protocol> {
+        self.protocols.get(&hash).cloned().unwrap_or_default()
+    }
+
     /// Lookup the given macro handler.
     pub(crate) fn lookup_macro(&self, hash: Hash) -> Option<&Arc<MacroHandler>> {
         self.macros.get(&hash)
@@ -384,19 +567,27 @@ impl Context {
     fn install_module(&mut self, m: &Module) -> Result<(), ContextError> {
         self.names.insert(&m.item);
 
-        let mut current = Some((m.item.as_ref(), Some(&m.docs)));
+        let mut current = Some((
+            m.item.as_ref(),
+            Some(&m.docs),
+            m.deprecated.as_ref(),
+            m.unstable,
+        ));
 
         #[allow(unused)]
-        while let Some((item, docs)) = current.take() {
+        while let Some((item, docs, deprecated, unstable)) = current.take() {
             self.install_meta(ContextMeta {
                 hash: Hash::type_hash(item),
                 item: Some(item.to_owned()),
                 kind: meta::Kind::Module,
                 #[cfg(feature = "doc")]
                 docs: docs.cloned().unwrap_or_default(),
+                deprecated: deprecated.copied(),
+                #[cfg(feature = "doc")]
+                unstable,
             })?;
 
-            current = item.parent().map(|item| (item, None));
+            current = item.parent().map(|item| (item, None, None, false));
         }
 
         Ok(())
@@ -420,9 +611,9 @@ impl Context {
             match spec {
                 TypeSpecification::Struct(fields) => meta::Kind::Struct {
                     fields: match fields {
-                        Fields::Named(fields) => meta::Fields::Named(meta::FieldsNamed {
-                            fields: fields.iter().copied().map(Box::<str>::from).collect(),
-                        }),
+                        Fields::Named(fields) => {
+                            meta::Fields::Named(meta::FieldsNamed::new(fields.iter().copied()))
+                        }
                         Fields::Unnamed(args) => meta::Fields::Unnamed(*args),
                         Fields::Empty => meta::Fields::Empty,
                     },
@@ -454,12 +645,12 @@ impl Context {
                             let signature = meta::Signature {
                                 #[cfg(feature = "doc")]
                                 is_async: false,
-                                #[cfg(feature = "doc")]
                                 args: Some(match fields {
                                     Fields::Named(names) => names.len(),
                                     Fields::Unnamed(args) => *args,
                                     Fields::Empty => 0,
                                 }),
+                                generics: Box::from([]),
                                 #[cfg(feature = "doc")]
                                 return_type: Some(ty.hash),
                                 #[cfg(feature = "doc")]
@@ -479,15 +670,9 @@ impl Context {
                                 enum_hash: ty.hash,
                                 index,
                                 fields: match fields {
-                                    Fields::Named(names) => {
-                                        meta::Fields::Named(meta::FieldsNamed {
-                                            fields: names
-                                                .iter()
-                                                .copied()
-                                                .map(Box::<str>::from)
-                                                .collect(),
-                                        })
-                                    }
+                                    Fields::Named(names) => meta::Fields::Named(
+                                        meta::FieldsNamed::new(names.iter().copied()),
+                                    ),
                                     Fields::Unnamed(args) => meta::Fields::Unnamed(*args),
                                     Fields::Empty => meta::Fields::Empty,
                                 },
@@ -495,6 +680,9 @@ impl Context {
                             },
                             #[cfg(feature = "doc")]
                             docs: variant.docs.clone(),
+                            deprecated: variant.deprecated,
+                            #[cfg(feature = "doc")]
+                            unstable: variant.unstable,
                         })?;
                     }
 
@@ -511,6 +699,9 @@ impl Context {
             kind,
             #[cfg(feature = "doc")]
             docs: ty.docs.clone(),
+            deprecated: ty.deprecated,
+            #[cfg(feature = "doc")]
+            unstable: ty.unstable,
         })?;
 
         Ok(())
@@ -563,8 +754,8 @@ impl Context {
         let signature = meta::Signature {
             #[cfg(feature = "doc")]
             is_async: f.is_async,
-            #[cfg(feature = "doc")]
             args: f.args,
+            generics: Box::from([]),
             #[cfg(feature = "doc")]
             return_type: f.return_type.as_ref().map(|f| f.hash),
             #[cfg(feature = "doc")]
@@ -588,6 +779,9 @@ impl Context {
             },
             #[cfg(feature = "doc")]
             docs: f.docs.clone(),
+            deprecated: f.deprecated,
+            #[cfg(feature = "doc")]
+            unstable: f.unstable,
         })?;
 
         Ok(())
@@ -605,6 +799,9 @@ impl Context {
             kind: meta::Kind::Macro,
             #[cfg(feature = "doc")]
             docs: m.docs.clone(),
+            deprecated: m.deprecated,
+            #[cfg(feature = "doc")]
+            unstable: m.unstable,
         })?;
 
         Ok(())
@@ -628,6 +825,9 @@ impl Context {
             },
             #[cfg(feature = "doc")]
             docs: m.docs.clone(),
+            deprecated: m.deprecated,
+            #[cfg(feature = "doc")]
+            unstable: m.unstable,
         })?;
 
         Ok(())
@@ -651,8 +851,8 @@ impl Context {
         let signature = meta::Signature {
             #[cfg(feature = "doc")]
             is_async: assoc.is_async,
-            #[cfg(feature = "doc")]
             args: assoc.args,
+            generics: Box::from([]),
             #[cfg(feature = "doc")]
             return_type: assoc.return_type.as_ref().map(|f| f.hash),
             #[cfg(feature = "doc")]
@@ -663,6 +863,16 @@ impl Context {
                 .collect(),
         };
 
+        // Associated functions might be installed by a different module than
+        // the one which declared the type, so conflicts can only be detected
+        // here.
+        if self.functions.contains_key(&hash) {
+            return Err(ContextError::conflicting_associated(
+                &assoc.name.kind,
+                assoc.container_type_info.clone(),
+            ));
+        }
+
         self.insert_native_fn(hash, &assoc.handler)?;
 
         // If the associated function is a named instance function - register it
@@ -672,6 +882,11 @@ impl Context {
         // The other alternatives are protocol functions (which are not free)
         // and plain hashes.
         let item = if let meta::AssociatedKind::Instance(name) = &assoc.name.kind {
+            self.instance_methods
+                .entry(assoc.container.hash)
+                .or_default()
+                .push((name.as_ref().into(), hash));
+
             let item = info.item.extended(name.as_ref());
 
             let hash = Hash::type_hash(&item)
@@ -705,8 +920,20 @@ impl Context {
             },
             #[cfg(feature = "doc")]
             docs: assoc.docs.clone(),
+            deprecated: assoc.deprecated,
+            #[cfg(feature = "doc")]
+            unstable: assoc.unstable,
         })?;
 
+        // Only record the protocol once the function has been successfully
+        // installed.
+        if let meta::AssociatedKind::Protocol(protocol) = &assoc.name.kind {
+            self.protocols
+                .entry(assoc.container.hash)
+                .or_default()
+                .push(*protocol);
+        }
+
         Ok(())
     }
 
@@ -731,8 +958,8 @@ impl Context {
         let signature = meta::Signature {
             #[cfg(feature = "doc")]
             is_async: false,
-            #[cfg(feature = "doc")]
             args: Some(0),
+            generics: Box::from([]),
             #[cfg(feature = "doc")]
             return_type: Some(hash),
             #[cfg(feature = "doc")]
@@ -755,6 +982,9 @@ impl Context {
             },
             #[cfg(feature = "doc")]
             docs: unit_type.docs.clone(),
+            deprecated: None,
+            #[cfg(feature = "doc")]
+            unstable: false,
         })?;
 
         self.constants.insert(
@@ -789,6 +1019,9 @@ impl Context {
             },
             #[cfg(feature = "doc")]
             docs: internal_enum.docs.clone(),
+            deprecated: None,
+            #[cfg(feature = "doc")]
+            unstable: false,
         })?;
 
         self.install_type_info(ContextType {
@@ -821,12 +1054,12 @@ impl Context {
                 Some(meta::Signature {
                     #[cfg(feature = "doc")]
                     is_async: false,
-                    #[cfg(feature = "doc")]
                     args: Some(match fields {
                         Fields::Named(names) => names.len(),
                         Fields::Unnamed(args) => *args,
                         Fields::Empty => 0,
                     }),
+                    generics: Box::from([]),
                     #[cfg(feature = "doc")]
                     return_type: Some(enum_hash),
                     #[cfg(feature = "doc")]
@@ -843,9 +1076,9 @@ impl Context {
                     enum_hash,
                     index,
                     fields: match fields {
-                        Fields::Named(fields) => meta::Fields::Named(meta::FieldsNamed {
-                            fields: fields.iter().copied().map(Box::<str>::from).collect(),
-                        }),
+                        Fields::Named(fields) => {
+                            meta::Fields::Named(meta::FieldsNamed::new(fields.iter().copied()))
+                        }
                         Fields::Unnamed(args) => meta::Fields::Unnamed(*args),
                         Fields::Empty => meta::Fields::Empty,
                     },
@@ -853,6 +1086,9 @@ impl Context {
                 },
                 #[cfg(feature = "doc")]
                 docs: variant.docs.clone(),
+                deprecated: variant.deprecated,
+                #[cfg(feature = "doc")]
+                unstable: variant.unstable,
             })?;
         }
 
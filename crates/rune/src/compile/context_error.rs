@@ -4,7 +4,7 @@ use crate::no_std::thiserror;
 
 use thiserror::Error;
 
-use crate::compile::ItemBuf;
+use crate::compile::{meta, ItemBuf};
 use crate::runtime::{TypeInfo, VmError};
 use crate::Hash;
 
@@ -77,6 +77,8 @@ pub enum ContextError {
     MissingContainer { container: TypeInfo },
     #[error("Missing variant {index} for `{type_info}`")]
     MissingVariant { index: usize, type_info: TypeInfo },
+    #[error("Function with hash `{hash}` isn't registered")]
+    MissingFunction { hash: Hash },
     #[error("Expected associated function")]
     ExpectedAssociated,
     #[error("Type hash mismatch for `{type_info}`, from module is `{hash}` while from item `{item}` is `{item_hash}`. A possibility is that it has the wrong #[rune(item = ..)] setting.")]
@@ -87,3 +89,34 @@ pub enum ContextError {
         item_hash: Hash,
     },
 }
+
+impl ContextError {
+    /// Construct the error raised when an associated function of the given
+    /// kind conflicts with an existing one.
+    pub(crate) fn conflicting_associated(kind: &meta::AssociatedKind, type_info: TypeInfo) -> Self {
+        match kind {
+            meta::AssociatedKind::Protocol(protocol) => ContextError::ConflictingProtocolFunction {
+                type_info,
+                name: protocol.name.into(),
+            },
+            meta::AssociatedKind::FieldFn(protocol, field) => {
+                ContextError::ConflictingFieldFunction {
+                    type_info,
+                    name: protocol.name.into(),
+                    field: field.as_ref().into(),
+                }
+            }
+            meta::AssociatedKind::IndexFn(protocol, index) => {
+                ContextError::ConflictingIndexFunction {
+                    type_info,
+                    name: protocol.name.into(),
+                    index: *index,
+                }
+            }
+            meta::AssociatedKind::Instance(name) => ContextError::ConflictingInstanceFunction {
+                type_info,
+                name: name.as_ref().into(),
+            },
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use crate::no_std::collections::BTreeMap;
+use crate::no_std::prelude::*;
+
+use crate::compile::{Item, ItemBuf, ItemId, Pool};
+
+/// A graph of the items referenced by each item while building a unit, like
+/// the functions it calls, the constants it uses and the targets of imports.
+///
+/// This is populated by passing it to [`Build::with_dependency_graph`].
+///
+/// # Examples
+///
+/// ```
+/// use rune::compile::{DependencyGraph, ItemBuf};
+/// use rune::{Source, Sources};
+///
+/// let mut sources = Sources::new();
+/// sources.insert(Source::new("entry", r#"
+/// pub fn main() { a() + b() }
+/// fn a() { 1 }
+/// fn b() { 2 }
+/// "#));
+///
+/// let mut graph = DependencyGraph::new();
+///
+/// let unit = rune::prepare(&mut sources)
+///     .with_dependency_graph(&mut graph)
+///     .build()?;
+///
+/// let main = ItemBuf::with_item(["main"]);
+/// let a = ItemBuf::with_item(["a"]);
+/// let b = ItemBuf::with_item(["b"]);
+///
+/// assert_eq!(graph.dependencies(&main), [a, b]);
+/// # Ok::<_, rune::Error>(())
+/// ```
+///
+/// [`Build::with_dependency_graph`]: crate::Build::with_dependency_graph
+#[derive(Debug, Default, Clone)]
+pub struct DependencyGraph {
+    dependencies: BTreeMap<ItemBuf, Vec<ItemBuf>>,
+}
+
+impl DependencyGraph {
+    /// Construct a new empty dependency graph.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Populate the graph from recorded dependencies, replacing anything
+    /// collected by a previous build.
+    pub(crate) fn collect<'a, I>(&mut self, pool: &Pool, dependencies: I)
+    where
+        I: IntoIterator<Item = (ItemId, &'a [ItemId])>,
+    {
+        self.dependencies.clear();
+
+        for (item, dependencies) in dependencies {
+            let dependencies = dependencies
+                .iter()
+                .map(|item| pool.item(*item).to_owned())
+                .collect();
+
+            self.dependencies
+                .insert(pool.item(item).to_owned(), dependencies);
+        }
+    }
+
+    /// Get the items referenced by the given item, in the order they were
+    /// first referenced.
+    ///
+    /// Returns an empty slice if the item doesn't reference anything or wasn't
+    /// built.
+    pub fn dependencies(&self, item: &Item) -> &[ItemBuf] {
+        match self.dependencies.get(item) {
+            Some(dependencies) => dependencies,
+            None => &[],
+        }
+    }
+
+    /// Iterate over every item which references other items, ordered by their
+    /// item path.
+    pub fn iter(&self) -> impl Iterator<Item = (&Item, &[ItemBuf])> {
+        self.dependencies
+            .iter()
+            .map(|(item, dependencies)| (&**item, dependencies.as_slice()))
+    }
+}
@@ -250,6 +250,8 @@ pub(crate) enum CompileErrorKind {
     UnsupportedGlobal,
     #[error("Cannot load modules using a source without an associated URL")]
     UnsupportedModuleSource,
+    #[error("Loading modules from files is disabled")]
+    FileModulesDisabled,
     #[error("Cannot load modules relative to `{root}`")]
     UnsupportedModuleRoot { root: PathBuf },
     #[error("Cannot load module for `{item}`")]
@@ -282,6 +284,13 @@ pub(crate) enum CompileErrorKind {
         expected: usize,
         actual: usize,
     },
+    #[error("Function `{meta}` expects `{expected}` arguments but was called with `{actual}`")]
+    BadArgumentCount {
+        meta: MetaInfo,
+        expected: usize,
+        actual: usize,
+        declared: Option<Location>,
+    },
     #[error("This kind of expression is not supported as a pattern")]
     UnsupportedPatternExpr,
     #[error("Not a valid binding")]
@@ -369,6 +378,11 @@ pub(crate) enum CompileErrorKind {
     ExpectedLeadingPathSegment,
     #[error("Visibility modifier not supported")]
     UnsupportedVisibility,
+    #[error("Visibility restriction `{restriction}` is not an ancestor of the current module `{module}`")]
+    VisibilityRestrictionNotAncestor {
+        restriction: ItemBuf,
+        module: ItemBuf,
+    },
     #[error("Expected {expected} but got `{meta}`")]
     ExpectedMeta {
         expected: &'static str,
@@ -418,7 +432,7 @@ pub(crate) enum QueryErrorKind {
         "Item `{item}` with visibility `{visibility}`, is not accessible from module `{from}`"
     )]
     NotVisible {
-        chain: Vec<Location>,
+        chain: Vec<ImportStep>,
         location: Location,
         visibility: Visibility,
         item: ItemBuf,
@@ -428,7 +442,7 @@ pub(crate) enum QueryErrorKind {
         "Module `{item}` with {visibility} visibility, is not accessible from module `{from}`"
     )]
     NotVisibleMod {
-        chain: Vec<Location>,
+        chain: Vec<ImportStep>,
         location: Location,
         visibility: Visibility,
         item: ItemBuf,
@@ -440,8 +454,12 @@ pub(crate) enum QueryErrorKind {
     MissingMod { item: ItemBuf },
     #[error("Cycle in import")]
     ImportCycle { path: Vec<ImportStep> },
-    #[error("Import recursion limit reached ({count})")]
-    ImportRecursionLimit { count: usize, path: Vec<ImportStep> },
+    #[error("Import recursion limit of {limit} reached ({count})")]
+    ImportRecursionLimit {
+        count: usize,
+        limit: usize,
+        path: Vec<ImportStep>,
+    },
     #[error("Missing last use component")]
     LastUseComponent,
     #[error("Tried to insert variant runtime type information, but conflicted with hash `{hash}`")]
@@ -522,6 +540,8 @@ pub(crate) enum ParseErrorKind {
     UnexpectedChar { c: char },
     #[error("Group required in expression to determine precedence")]
     PrecedenceGroupRequired,
+    #[error("Comparison operators cannot be chained; consider `(a {lhs} b) && (b {rhs} c)`")]
+    ChainedComparison { lhs: ast::BinOp, rhs: ast::BinOp },
     #[error("Number literal out of bounds `-9223372036854775808` to `9223372036854775807`")]
     BadNumberOutOfBounds,
     #[error("Unsupported field access")]
@@ -539,6 +559,8 @@ pub(crate) enum ParseErrorKind {
     MissingSourceId { source_id: SourceId },
     #[error("Expected multiline comment to be terminated with a `*/`")]
     ExpectedMultilineCommentTerm,
+    #[error("Unclosed `{}` opened here", delim.open())]
+    UnclosedDelimiter { delim: ast::Delimiter },
 }
 
 /// Error when encoding AST.
@@ -568,8 +590,11 @@ pub(crate) enum IrErrorKind {
         actual: TypeInfo,
     },
     /// Exceeded evaluation budget.
-    #[error("Evaluation budget exceeded")]
-    BudgetExceeded,
+    #[error("Evaluation budget of {limit} exceeded")]
+    BudgetExceeded {
+        /// The budget which was exceeded.
+        limit: usize,
+    },
     /// Missing a tuple index.
     #[error("Missing index {index}")]
     MissingIndex {
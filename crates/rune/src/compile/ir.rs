@@ -26,6 +26,7 @@ use crate::compile::ir::eval::IrEvalBreak;
 use crate::compile::{self, ItemMeta, WithSpan};
 use crate::hir;
 use crate::query::Used;
+use crate::runtime::AccessError;
 
 /// Context used for [IrEval].
 pub struct IrEvalContext<'a> {
@@ -53,7 +54,7 @@ impl IrEval for ast::Expr {
         };
 
         let mut ir_interpreter = IrInterpreter {
-            budget: IrBudget::new(1_000_000),
+            budget: IrBudget::new(ctx.c.q.options.const_eval_budget),
             scopes: Default::default(),
             module: ctx.item.module,
             item: ctx.item.item,
@@ -154,6 +155,8 @@ decl_kind! {
         Value(IrValue),
         /// A sequence of conditional branches.
         Branches(IrBranches),
+        /// A match over a value.
+        Match(IrMatch),
         /// A loop.
         Loop(IrLoop),
         /// A break to the given target.
@@ -308,6 +311,32 @@ pub struct IrBranches {
     pub(crate) default_branch: Option<IrScope>,
 }
 
+/// A match expression in intermediate representation.
+#[derive(Debug, Clone, Spanned)]
+pub struct IrMatch {
+    /// Span associated with the match.
+    #[rune(span)]
+    pub(crate) span: Span,
+    /// The expression being matched over.
+    pub(crate) expr: Box<Ir>,
+    /// The branches of the match, in the order they are tested.
+    pub(crate) branches: Vec<IrMatchBranch>,
+}
+
+/// A single branch of a match.
+#[derive(Debug, Clone, Spanned)]
+pub struct IrMatchBranch {
+    /// Span associated with the branch.
+    #[rune(span)]
+    pub(crate) span: Span,
+    /// The pattern of the branch.
+    pub(crate) pat: IrPat,
+    /// The optional guard of the branch.
+    pub(crate) condition: Option<Ir>,
+    /// The body of the branch.
+    pub(crate) body: Ir,
+}
+
 /// The condition for a branch.
 #[derive(Debug, Clone, Spanned)]
 pub enum IrCondition {
@@ -336,6 +365,8 @@ pub enum IrPat {
     Ignore,
     /// A named binding.
     Binding(Box<str>),
+    /// A literal value which has to be equal to the matched value.
+    Value(IrValue),
 }
 
 impl IrPat {
@@ -348,6 +379,11 @@ impl IrPat {
                     return Ok(ir::IrPat::Binding(name.into()));
                 }
             }
+            hir::PatKind::PatLit(hir) => {
+                if let ir::IrKind::Value(value) = compiler::expr(hir, c)?.kind {
+                    return Ok(ir::IrPat::Value(value));
+                }
+            }
             _ => (),
         }
 
@@ -369,10 +405,28 @@ impl IrPat {
                 interp.scopes.decl(name, value).with_span(spanned)?;
                 Ok(true)
             }
+            IrPat::Value(expected) => Ok(value_eq(expected, &value).with_span(spanned)?),
         }
     }
 }
 
+/// Test if two constant values are equal, as used by literal patterns.
+///
+/// Values of different types are never equal.
+fn value_eq(a: &IrValue, b: &IrValue) -> Result<bool, AccessError> {
+    Ok(match (a, b) {
+        (IrValue::Unit, IrValue::Unit) => true,
+        (IrValue::Byte(a), IrValue::Byte(b)) => a == b,
+        (IrValue::Char(a), IrValue::Char(b)) => a == b,
+        (IrValue::Bool(a), IrValue::Bool(b)) => a == b,
+        (IrValue::Integer(a), IrValue::Integer(b)) => a == b,
+        (IrValue::Float(a), IrValue::Float(b)) => a == b,
+        (IrValue::String(a), IrValue::String(b)) => *a.borrow_ref()? == *b.borrow_ref()?,
+        (IrValue::Bytes(a), IrValue::Bytes(b)) => *a.borrow_ref()? == *b.borrow_ref()?,
+        _ => false,
+    })
+}
+
 /// A loop with an optional condition.
 #[derive(Debug, Clone, Spanned)]
 pub struct IrLoop {
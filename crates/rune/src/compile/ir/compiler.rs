@@ -3,8 +3,8 @@ use core::mem::{replace, take};
 use crate::no_std::prelude::*;
 
 use crate::ast::{self, Span, Spanned};
-use crate::compile;
 use crate::compile::ir::{self, IrValue};
+use crate::compile::{self, Location};
 use crate::hir;
 use crate::parse::Resolve;
 use crate::query::Query;
@@ -89,6 +89,7 @@ pub(crate) fn expr(hir: &hir::Expr<'_>, c: &mut IrCompiler<'_>) -> compile::Resu
         hir::ExprKind::Assign(hir) => expr_assign(span, c, hir)?,
         hir::ExprKind::Call(hir) => ir::Ir::new(span, expr_call(span, c, hir)?),
         hir::ExprKind::If(hir) => ir::Ir::new(span, expr_if(span, c, hir)?),
+        hir::ExprKind::Match(hir) => ir::Ir::new(span, expr_match(span, c, hir)?),
         hir::ExprKind::Loop(hir) => ir::Ir::new(span, expr_loop(span, c, hir)?),
         hir::ExprKind::Lit(hir) => lit(hir, c)?,
         hir::ExprKind::Block(hir) => expr_block(span, c, hir)?,
@@ -230,6 +231,9 @@ fn expr_binary(
 fn lit(hir: &ast::Lit, c: &mut IrCompiler<'_>) -> compile::Result<ir::Ir> {
     let span = hir.span();
 
+    c.q.visitor
+        .visit_literal(Location::new(c.source_id, span), hir.kind());
+
     Ok(match hir {
         ast::Lit::Bool(b) => ir::Ir::new(span, IrValue::Bool(b.value)),
         ast::Lit::Str(s) => {
@@ -401,6 +405,9 @@ fn builtin_template(
 
     for e in template.exprs {
         if let hir::ExprKind::Lit(ast::Lit::Str(s)) = e.kind {
+            c.q.visitor
+                .visit_literal(Location::new(c.source_id, s.span), ast::LitKind::Str);
+
             let s = s.resolve_template_string(resolve_context!(c.q))?;
 
             components.push(ir::IrTemplateComponent::String(
@@ -507,6 +514,40 @@ fn expr_if(
     })
 }
 
+#[instrument]
+fn expr_match(
+    span: Span,
+    c: &mut IrCompiler<'_>,
+    hir: &hir::ExprMatch<'_>,
+) -> compile::Result<ir::IrMatch> {
+    let target = expr(hir.expr, c)?;
+    let mut branches = Vec::new();
+
+    for branch in hir.branches {
+        let pat = ir::IrPat::compile_ast(branch.pat, c)?;
+
+        let condition = match branch.condition {
+            Some(condition) => Some(expr(condition, c)?),
+            None => None,
+        };
+
+        let body = expr(branch.body, c)?;
+
+        branches.push(ir::IrMatchBranch {
+            span: branch.span(),
+            pat,
+            condition,
+            body,
+        });
+    }
+
+    Ok(ir::IrMatch {
+        span,
+        expr: Box::new(target),
+        branches,
+    })
+}
+
 #[instrument]
 fn expr_loop(
     span: Span,
@@ -6,7 +6,7 @@ use crate::no_std::prelude::*;
 
 use crate::ast::{Span, Spanned};
 use crate::compile::ir;
-use crate::compile::ir::{IrInterpreter, IrValue};
+use crate::compile::ir::{IrBudget, IrInterpreter, IrValue};
 use crate::compile::{self, WithSpan};
 use crate::query::Used;
 use crate::runtime::Shared;
@@ -142,6 +142,20 @@ fn eval_ir_binary(
                 return Ok(IrValue::String(add_strings(span, &a, &b)?));
             }
         }
+        (IrValue::String(a), IrValue::Integer(b)) => {
+            if let ir::IrBinaryOp::Mul = ir.op {
+                let count = usize::try_from(b).map_err(|_| {
+                    compile::Error::msg(&ir.rhs, "cannot be converted to a repeat count")
+                })?;
+
+                return Ok(IrValue::String(repeat_string(
+                    span,
+                    &mut interp.budget,
+                    &a,
+                    count,
+                )?));
+            }
+        }
         _ => (),
     }
 
@@ -158,6 +172,35 @@ fn eval_ir_binary(
         a.push_str(&b);
         Ok(Shared::new(a))
     }
+
+    fn repeat_string(
+        span: Span,
+        budget: &mut IrBudget,
+        a: &Shared<String>,
+        count: usize,
+    ) -> compile::Result<Shared<String>> {
+        let a = a.borrow_ref().with_span(span)?;
+
+        let Some(size) = a.len().checked_mul(count) else {
+            return Err(compile::Error::msg(span, "repeated string is too large"));
+        };
+
+        // Every byte produced counts against the budget, so that constant
+        // evaluation can't be used to allocate arbitrarily large strings.
+        budget.take_n(span, size)?;
+
+        let mut string = String::new();
+
+        if string.try_reserve_exact(size).is_err() {
+            return Err(compile::Error::msg(span, "repeated string is too large"));
+        }
+
+        for _ in 0..count {
+            string.push_str(&a);
+        }
+
+        Ok(Shared::new(string))
+    }
 }
 
 fn eval_ir_branches(
@@ -190,6 +233,45 @@ fn eval_ir_branches(
     Ok(IrValue::Unit)
 }
 
+fn eval_ir_match(
+    ir: &ir::IrMatch,
+    interp: &mut IrInterpreter<'_>,
+    used: Used,
+) -> Result<IrValue, IrEvalOutcome> {
+    interp.budget.take(ir)?;
+    let value = eval_ir(&ir.expr, interp, used)?;
+
+    for branch in &ir.branches {
+        let guard = interp.scopes.push();
+
+        let mut matched = branch.pat.matches(interp, value.clone(), branch)?;
+
+        if matched {
+            if let Some(condition) = &branch.condition {
+                let value = eval_ir(condition, interp, used)?;
+                matched = as_bool(condition.span(), value)?;
+            }
+        }
+
+        let output = if matched {
+            Some(eval_ir(&branch.body, interp, used)?)
+        } else {
+            None
+        };
+
+        interp.scopes.pop(guard).with_span(branch)?;
+
+        if let Some(output) = output {
+            return Ok(output);
+        }
+    }
+
+    Err(IrEvalOutcome::from(compile::Error::msg(
+        ir,
+        "no branch matched the value of the match expression",
+    )))
+}
+
 fn eval_ir_call(
     ir: &ir::IrCall,
     interp: &mut IrInterpreter<'_>,
@@ -422,6 +504,7 @@ pub(crate) fn eval_ir(
         ir::IrKind::Target(target) => Ok(interp.scopes.get_target(target)?),
         ir::IrKind::Value(value) => Ok(value.clone()),
         ir::IrKind::Branches(ir) => eval_ir_branches(ir, interp, used),
+        ir::IrKind::Match(ir) => eval_ir_match(ir, interp, used),
         ir::IrKind::Loop(ir) => eval_ir_loop(ir, interp, used),
         ir::IrKind::Break(ir) => Err(ir.as_outcome(interp, used)),
         ir::IrKind::Vec(ir) => eval_ir_vec(ir, interp, used),
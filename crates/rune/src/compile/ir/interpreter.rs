@@ -17,7 +17,9 @@ pub struct IrInterpreter<'a> {
     pub(crate) budget: IrBudget,
     /// The module in which the interpreter is run.
     pub(crate) module: ModId,
-    /// The item where the constant expression is located.
+    /// The item currently being evaluated. This is the item where the constant
+    /// expression is located, or the constant function whose body is being
+    /// evaluated.
     pub(crate) item: ItemId,
     /// Constant scopes.
     pub(crate) scopes: IrScopes,
@@ -103,13 +105,17 @@ impl IrInterpreter<'_> {
             let item = self.q.pool.alloc_item(base.extended(name));
 
             if let Some(const_value) = self.q.consts.get(item) {
-                return Ok(IrValue::from_const(const_value));
+                let value = IrValue::from_const(const_value);
+                self.q.insert_dependency(self.item, item);
+                return Ok(value);
             }
 
             if let Some(meta) = self.q.query_meta(spanned, item, used)? {
                 match &meta.kind {
                     meta::Kind::Const { const_value, .. } => {
-                        return Ok(IrValue::from_const(const_value));
+                        let value = IrValue::from_const(const_value);
+                        self.q.insert_dependency(self.item, item);
+                        return Ok(value);
                     }
                     _ => {
                         return Err(compile::Error::new(
@@ -158,7 +164,9 @@ impl IrInterpreter<'_> {
             if let Some(meta) = self.q.query_meta(span, item, used)? {
                 match &meta.kind {
                     meta::Kind::ConstFn { id, .. } => {
-                        break *id;
+                        let id = *id;
+                        self.q.insert_dependency(self.item, item);
+                        break id;
                     }
                     _ => {
                         return Err(compile::Error::new(
@@ -196,7 +204,11 @@ impl IrInterpreter<'_> {
             self.scopes.decl(name, value).with_span(span)?;
         }
 
-        let value = self.eval_value(&const_fn.ir_fn.ir, used)?;
+        let item = core::mem::replace(&mut self.item, const_fn.item_meta.item);
+        let value = self.eval_value(&const_fn.ir_fn.ir, used);
+        self.item = item;
+
+        let value = value?;
         self.scopes.pop(guard).with_span(span)?;
         Ok(value)
     }
@@ -398,13 +410,17 @@ impl IrScopes {
 
 /// A budget dictating the number of evaluations the compiler is allowed to do.
 pub(crate) struct IrBudget {
+    limit: usize,
     budget: usize,
 }
 
 impl IrBudget {
     /// Construct a new constant evaluation budget with the given constraint.
     pub(crate) fn new(budget: usize) -> Self {
-        Self { budget }
+        Self {
+            limit: budget,
+            budget,
+        }
     }
 
     /// Take an item from the budget. Errors if the budget is exceeded.
@@ -413,10 +429,30 @@ impl IrBudget {
         S: Spanned,
     {
         if self.budget == 0 {
-            return Err(compile::Error::new(spanned, IrErrorKind::BudgetExceeded));
+            return Err(compile::Error::new(
+                spanned,
+                IrErrorKind::BudgetExceeded { limit: self.limit },
+            ));
         }
 
         self.budget -= 1;
         Ok(())
     }
+
+    /// Take `n` items from the budget, used for operations whose cost grows
+    /// with their input. Errors if the budget is exceeded.
+    pub(crate) fn take_n<S>(&mut self, spanned: S, n: usize) -> compile::Result<()>
+    where
+        S: Spanned,
+    {
+        let Some(budget) = self.budget.checked_sub(n) else {
+            return Err(compile::Error::new(
+                spanned,
+                IrErrorKind::BudgetExceeded { limit: self.limit },
+            ));
+        };
+
+        self.budget = budget;
+        Ok(())
+    }
 }
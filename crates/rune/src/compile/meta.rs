@@ -3,14 +3,14 @@
 use core::fmt;
 
 use crate::no_std::borrow::Cow;
-use crate::no_std::collections::HashSet;
+use crate::no_std::collections::HashMap;
 use crate::no_std::path::Path;
 use crate::no_std::prelude::*;
 use crate::no_std::sync::Arc;
 
 use crate::ast::{LitStr, Span};
 use crate::compile::attrs::Attributes;
-use crate::compile::{self, Item, ItemId, Location, MetaInfo, ModId, Pool, Visibility};
+use crate::compile::{self, Docs, Item, ItemId, Location, MetaInfo, ModId, Pool, Visibility};
 use crate::hash::Hash;
 use crate::parse::{Id, ResolveContext};
 use crate::runtime::{ConstValue, Protocol};
@@ -281,8 +281,29 @@ pub struct Import {
 #[derive(Debug, Clone)]
 #[non_exhaustive]
 pub struct FieldsNamed {
-    /// Fields associated with the type.
-    pub(crate) fields: HashSet<Box<str>>,
+    /// Fields associated with the type, in the order they were declared.
+    pub(crate) fields: Box<[Box<str>]>,
+    /// Documentation for fields which have any.
+    pub(crate) docs: HashMap<Box<str>, Docs>,
+}
+
+impl FieldsNamed {
+    /// Construct named fields without any documentation.
+    pub(crate) fn new<I>(fields: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<Box<str>>,
+    {
+        Self {
+            fields: fields.into_iter().map(Into::into).collect(),
+            docs: HashMap::new(),
+        }
+    }
+
+    /// Get the documentation associated with the given field, if any.
+    pub(crate) fn field_docs(&self, field: &str) -> Option<&Docs> {
+        self.docs.get(field)
+    }
 }
 
 /// Item and the module that the item belongs to.
@@ -314,9 +335,10 @@ pub struct Signature {
     /// An asynchronous function.
     #[cfg(feature = "doc")]
     pub(crate) is_async: bool,
-    /// Arguments.
-    #[cfg(feature = "doc")]
+    /// Arguments, if the number of arguments is known.
     pub(crate) args: Option<usize>,
+    /// Generic parameters declared on the function.
+    pub(crate) generics: Box<[GenericParam]>,
     /// Return type of the function.
     #[cfg(feature = "doc")]
     pub(crate) return_type: Option<Hash>,
@@ -325,6 +347,27 @@ pub struct Signature {
     pub(crate) argument_types: Box<[Option<Hash>]>,
 }
 
+impl Signature {
+    /// Generic parameters declared on the function, like `T` in
+    /// `fn f<T: Display>(x)`.
+    pub fn generics(&self) -> &[GenericParam] {
+        &self.generics
+    }
+}
+
+/// A generic parameter declared on a function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct GenericParam {
+    /// The name of the parameter.
+    pub name: Box<str>,
+    /// The bounds of the parameter as they were written, like `Display`.
+    ///
+    /// These are recorded for documentation and introspection, but are
+    /// currently not checked.
+    pub bounds: Box<[Box<str>]>,
+}
+
 /// The kind of an associated function.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 #[non_exhaustive]
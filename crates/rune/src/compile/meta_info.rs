@@ -26,6 +26,25 @@ impl MetaInfo {
             item: item.map(|item| item.to_owned()),
         }
     }
+
+    /// The name of the kind of the meta item, like `fn` or `struct`.
+    pub(crate) fn kind_name(&self) -> &'static str {
+        match self.kind {
+            MetaInfoKind::Type => "type",
+            MetaInfoKind::Struct => "struct",
+            MetaInfoKind::Variant => "variant",
+            MetaInfoKind::Enum => "enum",
+            MetaInfoKind::Macro => "macro",
+            MetaInfoKind::Function => "fn",
+            MetaInfoKind::Associated => "associated fn",
+            MetaInfoKind::Closure => "closure",
+            MetaInfoKind::AsyncBlock => "async block",
+            MetaInfoKind::Const => "const",
+            MetaInfoKind::ConstFn => "const fn",
+            MetaInfoKind::Import => "import",
+            MetaInfoKind::Module => "module",
+        }
+    }
 }
 
 impl fmt::Display for MetaInfo {
@@ -43,50 +62,7 @@ impl fmt::Display for MetaInfo {
         }
 
         let name = Name(self.hash, self.item.as_deref());
-
-        match self.kind {
-            MetaInfoKind::Type => {
-                write!(fmt, "type {name}")?;
-            }
-            MetaInfoKind::Struct => {
-                write!(fmt, "struct {name}")?;
-            }
-            MetaInfoKind::Variant => {
-                write!(fmt, "variant {name}")?;
-            }
-            MetaInfoKind::Enum => {
-                write!(fmt, "enum {name}")?;
-            }
-            MetaInfoKind::Macro => {
-                write!(fmt, "macro {name}")?;
-            }
-            MetaInfoKind::Function => {
-                write!(fmt, "fn {name}")?;
-            }
-            MetaInfoKind::Associated => {
-                write!(fmt, "associated fn {name}")?;
-            }
-            MetaInfoKind::Closure => {
-                write!(fmt, "closure {name}")?;
-            }
-            MetaInfoKind::AsyncBlock => {
-                write!(fmt, "async block {name}")?;
-            }
-            MetaInfoKind::Const => {
-                write!(fmt, "const {name}")?;
-            }
-            MetaInfoKind::ConstFn => {
-                write!(fmt, "const fn {name}")?;
-            }
-            MetaInfoKind::Import => {
-                write!(fmt, "import {name}")?;
-            }
-            MetaInfoKind::Module => {
-                write!(fmt, "module {name}")?;
-            }
-        }
-
-        Ok(())
+        write!(fmt, "{} {name}", self.kind_name())
     }
 }
 
@@ -26,6 +26,19 @@ pub struct Options {
     pub(crate) macros: bool,
     /// Support (experimental) bytecode caching.
     pub bytecode: bool,
+    /// Allow loading modules from files through module declarations like
+    /// `mod foo;`.
+    pub(crate) file_modules: bool,
+    /// Warn when a `let` binding shadows a local which is already in scope.
+    pub(crate) warn_shadowing: bool,
+    /// Warn about statements which follow a diverging expression like
+    /// `return`.
+    pub(crate) warn_unreachable: bool,
+    /// The permitted number of import recursions when resolving a path.
+    pub(crate) import_recursion_limit: usize,
+    /// The number of evaluations permitted when evaluating a single constant
+    /// expression or constant function call.
+    pub(crate) const_eval_budget: usize,
 
     /// Compile for and enable test features
     pub cfg_test: bool,
@@ -59,6 +72,37 @@ impl Options {
             Some("bytecode") => {
                 self.bytecode = it.next() != Some("false");
             }
+            Some("file-modules") => {
+                self.file_modules = it.next() != Some("false");
+            }
+            Some("warn-shadowing") => {
+                self.warn_shadowing = it.next() != Some("false");
+            }
+            Some("warn-unreachable") => {
+                self.warn_unreachable = it.next() != Some("false");
+            }
+            Some("import-recursion-limit") => {
+                let limit = it.next().and_then(|limit| limit.parse().ok());
+
+                let Some(limit) = limit else {
+                    return Err(ParseOptionError {
+                        option: option.into(),
+                    });
+                };
+
+                self.import_recursion_limit = limit;
+            }
+            Some("const-eval-budget") => {
+                let budget = it.next().and_then(|budget| budget.parse().ok());
+
+                let Some(budget) = budget else {
+                    return Err(ParseOptionError {
+                        option: option.into(),
+                    });
+                };
+
+                self.const_eval_budget = budget;
+            }
             Some("test") => {
                 self.cfg_test = it.next() != Some("false");
             }
@@ -102,6 +146,52 @@ impl Options {
         self.bytecode = enabled;
     }
 
+    /// Set if modules are allowed to be loaded from files through
+    /// declarations like `mod foo;`. Defaults to `true`.
+    ///
+    /// Disabling this causes any such declaration to be a compile error
+    /// without consulting the configured [SourceLoader], which is useful when
+    /// evaluating untrusted single-file programs. Items from the context can
+    /// still be imported.
+    ///
+    /// [SourceLoader]: crate::compile::SourceLoader
+    pub fn file_modules(&mut self, enabled: bool) {
+        self.file_modules = enabled;
+    }
+
+    /// Set if a warning should be emitted when a `let` binding shadows a local
+    /// which is already in scope, like `let x = 1; let x = 2;`. Defaults to
+    /// `false`, since deliberate shadowing is idiomatic.
+    pub fn warn_shadowing(&mut self, enabled: bool) {
+        self.warn_shadowing = enabled;
+    }
+
+    /// Set if a warning should be emitted for statements which can never be
+    /// reached since they follow a `return`, `break` or `continue` in the same
+    /// block. Defaults to `false`.
+    pub fn warn_unreachable(&mut self, enabled: bool) {
+        self.warn_unreachable = enabled;
+    }
+
+    /// Set the number of times an import is permitted to be followed through
+    /// re-exports while resolving a path before it is considered an error.
+    /// Defaults to `128`.
+    ///
+    /// Deeply nested chains of re-exports, as might be produced by generated
+    /// code, can require this to be raised.
+    pub fn import_recursion_limit(&mut self, limit: usize) {
+        self.import_recursion_limit = limit;
+    }
+
+    /// Set the number of evaluations the compiler is permitted to perform when
+    /// evaluating a single constant expression or constant function call.
+    /// Defaults to `1_000_000`.
+    ///
+    /// Large compile-time constant tables can require this to be raised.
+    pub fn const_eval_budget(&mut self, budget: usize) {
+        self.const_eval_budget = budget;
+    }
+
     /// Memoize the instance function in a loop. Defaults to `false`.
     pub fn memoize_instance_fn(&mut self, enabled: bool) {
         self.memoize_instance_fn = enabled;
@@ -116,6 +206,11 @@ impl Default for Options {
             debug_info: true,
             macros: true,
             bytecode: false,
+            file_modules: true,
+            warn_shadowing: false,
+            warn_unreachable: false,
+            import_recursion_limit: 128,
+            const_eval_budget: 1_000_000,
             cfg_test: false,
             v2: false,
         }
@@ -149,6 +149,11 @@ impl Pool {
         alloc_item!(self, item.as_ref())
     }
 
+    /// Get the identifier of an item which has already been allocated.
+    pub(crate) fn get_item(&self, item: &Item) -> Option<ItemId> {
+        self.hash_to_item.get(&Hash::type_hash(item)).copied()
+    }
+
     /// Map a value into a new item.
     pub(crate) fn try_map_alloc<M>(&mut self, id: ItemId, m: M) -> Option<ItemId>
     where
@@ -3,17 +3,41 @@ use crate::no_std::prelude::*;
 use crate::no_std::collections::HashMap;
 
 use crate::compile::{IntoComponent, Item, ItemBuf};
+use crate::Context;
 
 /// The contents of a prelude.
-#[derive(Default)]
+///
+/// A prelude only maps names to the items they refer to, like `Some` to
+/// `::std::option::Option::Some`. It can be constructed once and shared across
+/// many builds through [Build::with_prelude][crate::Build::with_prelude], which
+/// avoids rebuilding the map for every compilation. Nothing compiled from the
+/// prelude items themselves is cached.
+#[derive(Default, Clone)]
 pub struct Prelude {
     /// Prelude imports.
     prelude: HashMap<Box<str>, ItemBuf>,
 }
 
 impl Prelude {
-    /// Construct a new unit with the default prelude.
-    pub(crate) fn with_default_prelude() -> Self {
+    /// Construct a new empty prelude.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct the prelude which is appropriate for the given [Context].
+    ///
+    /// This is the default prelude if the context has the default modules
+    /// installed, and an empty prelude otherwise.
+    pub fn with_context(context: &Context) -> Self {
+        if context.has_default_modules() {
+            Self::with_default_prelude()
+        } else {
+            Self::default()
+        }
+    }
+
+    /// Construct a new prelude with the default prelude items.
+    pub fn with_default_prelude() -> Self {
         let mut this = Self::default();
 
         this.add_prelude("any", ["Type"]);
@@ -37,6 +61,7 @@ impl Prelude {
         this.add_prelude("Ok", ["result", "Result", "Ok"]);
         this.add_prelude("Option", ["option", "Option"]);
         this.add_prelude("panic", ["panic"]);
+        this.add_prelude("Range", ["ops", "Range"]);
         this.add_prelude("print", ["io", "print"]);
         this.add_prelude("println", ["io", "println"]);
         this.add_prelude("Result", ["result", "Result"]);
@@ -562,6 +562,10 @@ impl UnitBuilder {
         self.debug_info_mut().functions.insert(hash, signature);
 
         self.add_assembly(location, assembly, unit_encoder)?;
+
+        let end = unit_encoder.offset();
+        self.debug_info_mut()
+            .insert_function_range(hash, offset..end);
         Ok(())
     }
 
@@ -635,6 +639,10 @@ impl UnitBuilder {
             .insert(instance_fn, signature);
         self.functions_rev.insert(offset, hash);
         self.add_assembly(location, assembly, unit_storage)?;
+
+        let end = unit_storage.offset();
+        self.debug_info_mut()
+            .insert_function_range(instance_fn, offset..end);
         Ok(())
     }
 
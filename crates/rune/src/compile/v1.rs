@@ -64,6 +64,8 @@ impl Needs {
 pub(crate) struct Assembler<'a> {
     /// The source id of the source.
     pub(crate) source_id: SourceId,
+    /// The item being assembled.
+    pub(crate) item: ItemId,
     /// The context we are compiling for.
     pub(crate) context: &'a Context,
     /// Query system to compile required items.
@@ -198,10 +200,12 @@ impl<'a> Assembler<'a> {
         if parameters.is_empty() {
             if let Some(meta) = self.q.query_meta(span, item, Default::default())? {
                 tracing::trace!("found in query: {:?}", meta);
+                self.q.insert_dependency(self.item, meta.item_meta.item);
                 self.q.visitor.visit_meta(
                     Location::new(self.source_id, span),
                     meta.as_meta_ref(self.q.pool),
                 );
+                self.check_deprecated(span, &meta);
                 return Ok(Some(meta));
             }
         }
@@ -215,7 +219,12 @@ impl<'a> Assembler<'a> {
             .with_span(span)?
         {
             ContextMatch::None => return Ok(None),
-            ContextMatch::Meta(meta) => return Ok(Some(meta.clone())),
+            ContextMatch::Meta(meta) => {
+                let meta = meta.clone();
+                self.q.insert_dependency(self.item, meta.item_meta.item);
+                self.check_deprecated(span, &meta);
+                return Ok(Some(meta));
+            }
             ContextMatch::Context(meta, parameters) => (meta, parameters),
         };
 
@@ -244,15 +253,34 @@ impl<'a> Assembler<'a> {
         self.q.insert_meta(meta.clone()).with_span(span)?;
 
         tracing::trace!("Found in context: {:?}", meta);
+        self.q.insert_dependency(self.item, meta.item_meta.item);
 
         self.q.visitor.visit_meta(
             Location::new(self.source_id, span),
             meta.as_meta_ref(self.q.pool),
         );
 
+        self.check_deprecated(span, &meta);
         Ok(Some(meta))
     }
 
+    /// Emit a warning if the given meta refers to a deprecated item in the
+    /// context.
+    fn check_deprecated(&mut self, span: Span, meta: &meta::Meta) {
+        if !meta.context {
+            return;
+        }
+
+        if let Some(deprecated) = self.context.lookup_deprecation(meta.hash) {
+            self.diagnostics.used_deprecated(
+                self.source_id,
+                span,
+                self.contexts.last().copied(),
+                deprecated,
+            );
+        }
+    }
+
     /// Access the meta for the given language item.
     pub fn lookup_meta(
         &mut self,
@@ -266,7 +294,7 @@ impl<'a> Assembler<'a> {
             return Ok(meta);
         }
 
-        let kind = if !parameters.parameters.is_empty() {
+        let kind = if !parameters.is_empty() {
             CompileErrorKind::MissingItemParameters {
                 item: self.q.pool.item(item).to_owned(),
                 parameters: parameters.as_boxed(),
@@ -371,7 +399,7 @@ impl<'a> Assembler<'a> {
         }
 
         let mut interpreter = IrInterpreter {
-            budget: IrBudget::new(1_000_000),
+            budget: IrBudget::new(self.options.const_eval_budget),
             scopes: Default::default(),
             module: from.module,
             item: from.item,
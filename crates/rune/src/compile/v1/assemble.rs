@@ -9,14 +9,14 @@ use num::ToPrimitive;
 use crate::ast::{self, Span, Spanned};
 use crate::compile::meta;
 use crate::compile::v1::{Assembler, GenericsParameters, Loop, Needs, Scope, Var};
-use crate::compile::{self, CompileErrorKind, Item, ParseErrorKind, WithSpan};
+use crate::compile::{self, CompileErrorKind, Item, Location, ParseErrorKind, WithSpan};
 use crate::hash::ParametersBuilder;
 use crate::hir;
 use crate::parse::{Id, Resolve};
 use crate::query::Named;
 use crate::runtime::{
     ConstValue, Inst, InstAddress, InstAssignOp, InstOp, InstRangeLimits, InstTarget, InstValue,
-    InstVariant, Label, PanicReason, Protocol, Type, TypeCheck,
+    InstVariant, Label, PanicReason, Protocol, Type, TypeCheck, BYTE_TYPE, CHAR_TYPE, INTEGER_TYPE,
 };
 use crate::Hash;
 
@@ -307,6 +307,10 @@ fn pat(
             pat_object(span, c, hir, false_label, &load)?;
             Ok(true)
         }
+        hir::PatKind::PatRange(hir) => {
+            pat_range(span, c, hir, false_label, &load)?;
+            Ok(true)
+        }
         _ => Err(compile::Error::new(
             hir,
             CompileErrorKind::UnsupportedPatternExpr,
@@ -341,12 +345,88 @@ fn pat_lit(
     Ok(true)
 }
 
+/// Assemble a range pattern.
+///
+/// The value is first checked to have the same type as the bounds, so that
+/// values of other types fall through rather than error when compared.
+#[instrument]
+fn pat_range(
+    span: Span,
+    c: &mut Assembler<'_>,
+    hir: &hir::PatRange<'_>,
+    false_label: &Label,
+    load: &dyn Fn(&mut Assembler<'_>, Needs) -> compile::Result<()>,
+) -> compile::Result<()> {
+    let start = pat_range_bound(hir.start, c)?;
+    let end = pat_range_bound(hir.end, c)?;
+
+    let hash = match (start, end) {
+        (InstValue::Byte(..), InstValue::Byte(..)) => BYTE_TYPE.hash,
+        (InstValue::Char(..), InstValue::Char(..)) => CHAR_TYPE.hash,
+        (InstValue::Integer(..), InstValue::Integer(..)) => INTEGER_TYPE.hash,
+        _ => {
+            return Err(compile::Error::new(
+                hir.end,
+                CompileErrorKind::UnsupportedPatternExpr,
+            ));
+        }
+    };
+
+    load(c, Needs::Value)?;
+    c.asm.push(Inst::MatchType { hash }, span);
+    c.asm
+        .pop_and_jump_if_not(c.scopes.local_var_count(span)?, false_label, span);
+
+    let end_op = match hir.limits {
+        hir::ExprRangeLimits::HalfOpen => InstOp::Lt,
+        hir::ExprRangeLimits::Closed => InstOp::Lte,
+    };
+
+    for (value, op) in [(start, InstOp::Gte), (end, end_op)] {
+        load(c, Needs::Value)?;
+        c.asm.push(Inst::Push { value }, span);
+        c.asm.push(
+            Inst::Op {
+                op,
+                a: InstAddress::Top,
+                b: InstAddress::Top,
+            },
+            span,
+        );
+        c.asm
+            .pop_and_jump_if_not(c.scopes.local_var_count(span)?, false_label, span);
+    }
+
+    Ok(())
+}
+
+/// Resolve the bound of a range pattern into a value.
+#[instrument]
+fn pat_range_bound(hir: &hir::Expr<'_>, c: &mut Assembler<'_>) -> compile::Result<InstValue> {
+    Ok(match pat_lit_inst(hir.span(), c, hir)? {
+        Some(Inst::EqByte { byte }) => InstValue::Byte(byte),
+        Some(Inst::EqChar { char }) => InstValue::Char(char),
+        Some(Inst::EqInteger { integer }) => InstValue::Integer(integer),
+        _ => {
+            return Err(compile::Error::new(
+                hir,
+                CompileErrorKind::UnsupportedPatternExpr,
+            ));
+        }
+    })
+}
+
 #[instrument]
 fn pat_lit_inst(
     span: Span,
     c: &mut Assembler<'_>,
     hir: &hir::Expr<'_>,
 ) -> compile::Result<Option<Inst>> {
+    if let hir::ExprKind::Lit(lit) = hir.kind {
+        c.q.visitor
+            .visit_literal(Location::new(c.source_id, lit.span()), lit.kind());
+    }
+
     match hir.kind {
         hir::ExprKind::Unary(hir::ExprUnary {
             op: ast::UnOp::Neg(..),
@@ -357,6 +437,9 @@ fn pat_lit_inst(
                 },
             ..
         }) => {
+            c.q.visitor
+                .visit_literal(Location::new(c.source_id, lit.span()), ast::LitKind::Number);
+
             let integer = lit
                 .resolve(resolve_context!(c.q))?
                 .as_i64(true)
@@ -743,7 +826,11 @@ fn pat_object(
                 }
             };
 
-            let mut fields = st.fields.clone();
+            let mut fields = st
+                .fields
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<HashSet<&str>>();
 
             for binding in &bindings {
                 if !fields.remove(binding.key()) {
@@ -892,6 +979,10 @@ fn block(hir: &hir::Block<'_>, c: &mut Assembler<'_>, needs: Needs) -> compile::
     c.contexts.push(span);
     let scopes_count = c.scopes.push_child(span)?;
 
+    if c.options.warn_unreachable {
+        block_unreachable(hir, c);
+    }
+
     let mut last = None::<(&hir::Expr<'_>, bool)>;
 
     for stmt in hir.statements {
@@ -949,6 +1040,34 @@ fn block(hir: &hir::Block<'_>, c: &mut Assembler<'_>, needs: Needs) -> compile::
     Ok(Asm::top(span))
 }
 
+/// Warn about statements in a block which follow a diverging expression.
+fn block_unreachable(hir: &hir::Block<'_>, c: &mut Assembler<'_>) {
+    let mut statements = hir
+        .statements
+        .iter()
+        .filter(|stmt| !matches!(stmt, hir::Stmt::Item(..)));
+
+    while let Some(stmt) = statements.next() {
+        let (hir::Stmt::Expr(e) | hir::Stmt::Semi(e)) = stmt else {
+            continue;
+        };
+
+        if !matches!(
+            e.kind,
+            hir::ExprKind::Return(..) | hir::ExprKind::Break(..) | hir::ExprKind::Continue(..)
+        ) {
+            continue;
+        }
+
+        if let Some(span) = statements.map(Spanned::span).reduce(Span::join) {
+            c.diagnostics
+                .unreachable(c.source_id, span, e.span(), c.context());
+        }
+
+        break;
+    }
+}
+
 /// Assemble #[builtin] format_args!(...) macro.
 #[instrument]
 fn builtin_format(
@@ -1023,6 +1142,9 @@ fn builtin_template(
 
     for hir in template.exprs {
         if let hir::ExprKind::Lit(ast::Lit::Str(s)) = hir.kind {
+            c.q.visitor
+                .visit_literal(Location::new(c.source_id, s.span), ast::LitKind::Str);
+
             let s = s.resolve_template_string(resolve_context!(c.q))?;
             size_hint += s.len();
 
@@ -1834,7 +1956,22 @@ fn convert_expr_call(
                         );
                     }
                 }
-                meta::Kind::Function { .. } | meta::Kind::AssociatedFunction { .. } => (),
+                meta::Kind::Function { signature, .. }
+                | meta::Kind::AssociatedFunction { signature, .. } => {
+                    if let Some(expected) = signature.args {
+                        if expected != hir.args.len() {
+                            return Err(compile::Error::new(
+                                span,
+                                CompileErrorKind::BadArgumentCount {
+                                    meta: meta.info(c.q.pool),
+                                    expected,
+                                    actual: hir.args.len(),
+                                    declared: meta.source.as_ref().map(|s| s.location),
+                                },
+                            ));
+                        }
+                    }
+                }
                 meta::Kind::ConstFn { id, .. } => {
                     let id = *id;
                     return Ok(Call::ConstFn { meta, id });
@@ -2659,7 +2796,7 @@ fn expr_object(
                     fields: meta::Fields::Empty,
                     ..
                 } => {
-                    check_object_fields(&HashSet::new(), check_keys, span, item)?;
+                    check_object_fields(&[], check_keys, span, item)?;
 
                     let hash = Hash::type_hash(item);
                     c.asm.push(Inst::UnitStruct { hash }, span);
@@ -2707,15 +2844,15 @@ fn expr_object(
     return Ok(Asm::top(span));
 
     fn check_object_fields(
-        fields: &HashSet<Box<str>>,
+        fields: &[Box<str>],
         check_keys: Vec<(Box<str>, Span)>,
         span: Span,
         item: &Item,
     ) -> compile::Result<()> {
-        let mut fields = fields.clone();
+        let mut missing = fields.iter().map(AsRef::as_ref).collect::<HashSet<&str>>();
 
         for (field, span) in check_keys {
-            if !fields.remove(&field) {
+            if !missing.remove(&*field) {
                 return Err(compile::Error::new(
                     span,
                     CompileErrorKind::LitObjectNotField {
@@ -2726,11 +2863,12 @@ fn expr_object(
             }
         }
 
-        if let Some(field) = fields.into_iter().next() {
+        // Report the first missing field in declaration order.
+        if let Some(field) = fields.iter().find(|f| missing.contains(&f[..])) {
             return Err(compile::Error::new(
                 span,
                 CompileErrorKind::LitObjectMissingField {
-                    field,
+                    field: field.clone(),
                     item: item.to_owned(),
                 },
             ));
@@ -3126,6 +3264,9 @@ fn expr_unary(
     }
 
     if let (ast::UnOp::Neg(..), hir::ExprKind::Lit(ast::Lit::Number(n))) = (hir.op, hir.expr.kind) {
+        c.q.visitor
+            .visit_literal(Location::new(c.source_id, n.span), ast::LitKind::Number);
+
         match n.resolve(resolve_context!(c.q))? {
             ast::Number::Float(n) => {
                 c.asm.push(Inst::float(-n), span);
@@ -3343,6 +3484,9 @@ pub(crate) fn fn_from_item_fn(
 fn lit(hir: &ast::Lit, c: &mut Assembler<'_>, needs: Needs) -> compile::Result<Asm> {
     let span = hir.span();
 
+    c.q.visitor
+        .visit_literal(Location::new(c.source_id, span), hir.kind());
+
     // Elide the entire literal if it's not needed.
     if !needs.value() {
         c.diagnostics.not_used(c.source_id, span, c.context());
@@ -3442,7 +3586,30 @@ fn local(hir: &hir::Local<'_>, c: &mut Assembler<'_>, needs: Needs) -> compile::
 
     let false_label = c.asm.new_label("let_panic");
 
-    if pat(hir.pat, c, &false_label, &load)? {
+    let shadowing = if c.options.warn_shadowing {
+        Some((c.scopes.total_var_count(span)?, c.scopes.visible_vars()))
+    } else {
+        None
+    };
+
+    let panics = pat(hir.pat, c, &false_label, &load)?;
+
+    if let Some((offset, visible)) = shadowing {
+        let mut shadows = c
+            .scopes
+            .vars_since(offset, span)?
+            .filter_map(|(name, var_span)| Some((var_span, *visible.get(name)?)))
+            .collect::<Vec<_>>();
+
+        shadows.sort_by_key(|(var_span, _)| var_span.start);
+
+        for (var_span, shadowed) in shadows {
+            c.diagnostics
+                .shadowed_local(c.source_id, var_span, shadowed, c.context());
+        }
+    }
+
+    if panics {
         c.diagnostics
             .let_pattern_might_panic(c.source_id, span, c.context());
 
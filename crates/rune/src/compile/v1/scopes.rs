@@ -308,6 +308,35 @@ impl Scopes {
         self.last_mut(span)?.undecl_anon(span, n)
     }
 
+    /// Collect the names of all visible locals and the spans where they were
+    /// declared.
+    pub(crate) fn visible_vars(&self) -> HashMap<String, Span> {
+        let mut vars = HashMap::new();
+
+        for scope in &self.scopes {
+            for (name, var) in &scope.locals {
+                vars.insert(name.clone(), var.span);
+            }
+        }
+
+        vars
+    }
+
+    /// Iterate over the locals declared in the last scope with an offset at
+    /// or above the given one.
+    pub(crate) fn vars_since(
+        &self,
+        offset: usize,
+        span: Span,
+    ) -> compile::Result<impl Iterator<Item = (&str, Span)>> {
+        Ok(self
+            .last(span)?
+            .locals
+            .iter()
+            .filter(move |(_, var)| var.offset >= offset)
+            .map(|(name, var)| (name.as_str(), var.span)))
+    }
+
     /// Push a scope and return an index.
     pub(crate) fn push(&mut self, scope: Scope) -> ScopeGuard {
         self.scopes.push(scope);
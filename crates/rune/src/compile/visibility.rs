@@ -16,6 +16,9 @@ pub enum Visibility {
     Super,
     /// Only visible in the same crate.
     SelfValue,
+    /// Only visible inside of the module the given number of levels above the
+    /// module of the item, like `pub(in a::b)`.
+    In(usize),
 }
 
 impl Visibility {
@@ -24,11 +27,20 @@ impl Visibility {
         matches!(self, Self::Public)
     }
 
+    /// Check if the module `from` is allowed to access an item in the module
+    /// `to` based on which crates they belong to.
+    ///
+    /// Only public items are visible from a dependent crate.
+    pub(crate) fn is_visible_from_crate(self, from: &Item, to: &Item) -> bool {
+        self.is_public() || from.as_crate() == to.as_crate()
+    }
+
     /// Check if `from` can access `to` with the current visibility.
     pub(crate) fn is_visible(self, from: &Item, to: &Item) -> bool {
         match self {
             Visibility::Inherited | Visibility::SelfValue => from.is_super_of(to, 1),
             Visibility::Super => from.is_super_of(to, 2),
+            Visibility::In(n) => from.is_super_of(to, n.saturating_add(1)),
             Visibility::Public => true,
             Visibility::Crate => true,
         }
@@ -39,6 +51,7 @@ impl Visibility {
         match self {
             Visibility::Inherited | Visibility::SelfValue => from == to,
             Visibility::Super => from.is_super_of(to, 1),
+            Visibility::In(n) => from.is_super_of(to, n),
             Visibility::Public => true,
             Visibility::Crate => true,
         }
@@ -59,6 +72,16 @@ impl fmt::Display for Visibility {
             Visibility::Crate => write!(f, "pub(crate)")?,
             Visibility::Super => write!(f, "pub(super)")?,
             Visibility::SelfValue => write!(f, "pub(self)")?,
+            Visibility::In(0) => write!(f, "pub(in self)")?,
+            Visibility::In(n) => {
+                write!(f, "pub(in super")?;
+
+                for _ in 1..*n {
+                    write!(f, "::super")?;
+                }
+
+                write!(f, ")")?;
+            }
         }
 
         Ok(())
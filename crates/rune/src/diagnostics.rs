@@ -244,6 +244,64 @@ impl Diagnostics {
         );
     }
 
+    /// Indicate that a deprecated item is being used.
+    pub(crate) fn used_deprecated(
+        &mut self,
+        source_id: SourceId,
+        span: Span,
+        context: Option<Span>,
+        message: &'static str,
+    ) {
+        self.warning(
+            source_id,
+            WarningDiagnosticKind::UsedDeprecated {
+                span,
+                context,
+                message,
+            },
+        );
+    }
+
+    /// Indicate that a `let` binding shadows an existing local.
+    ///
+    /// Like `let x = 1; let x = 2;`.
+    pub(crate) fn shadowed_local(
+        &mut self,
+        source_id: SourceId,
+        span: Span,
+        shadowed: Span,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningDiagnosticKind::ShadowedLocal {
+                span,
+                shadowed,
+                context,
+            },
+        );
+    }
+
+    /// Indicate that code following a diverging expression is unreachable.
+    ///
+    /// Like `return 1; let x = 2;`.
+    pub(crate) fn unreachable(
+        &mut self,
+        source_id: SourceId,
+        span: Span,
+        cause: Span,
+        context: Option<Span>,
+    ) {
+        self.warning(
+            source_id,
+            WarningDiagnosticKind::Unreachable {
+                span,
+                cause,
+                context,
+            },
+        );
+    }
+
     /// Add a warning about an unecessary semi-colon.
     pub(crate) fn uneccessary_semi_colon(&mut self, source_id: SourceId, span: Span) {
         self.warning(
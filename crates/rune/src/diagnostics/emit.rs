@@ -14,7 +14,7 @@ use codespan_reporting::term;
 use codespan_reporting::term::termcolor::WriteColor;
 pub use codespan_reporting::term::termcolor;
 
-use crate::compile::{CompileErrorKind, Location, LinkerError, QueryErrorKind};
+use crate::compile::{CompileErrorKind, ImportStep, Location, LinkerError, QueryErrorKind};
 use crate::diagnostics::{
     Diagnostic, FatalDiagnostic, FatalDiagnosticKind, WarningDiagnostic, WarningDiagnosticKind,
 };
@@ -380,6 +380,18 @@ where
                 notes.push(note);
             }
         }
+        WarningDiagnosticKind::ShadowedLocal { shadowed, .. } => {
+            labels.push(
+                d::Label::secondary(this.source_id(), shadowed.range())
+                    .with_message("Shadowed local declared here"),
+            );
+        }
+        WarningDiagnosticKind::Unreachable { cause, .. } => {
+            labels.push(
+                d::Label::secondary(this.source_id(), cause.range())
+                    .with_message("Any code following this expression is unreachable"),
+            );
+        }
         _ => {}
     };
 
@@ -475,7 +487,7 @@ where
     ) -> fmt::Result {
         match kind {
             CompileErrorKind::QueryError(kind) => {
-                format_query_error(this, span, kind, labels)?;
+                format_query_error(this, span, kind, labels, notes)?;
             }
             CompileErrorKind::DuplicateObjectKey { existing, object } => {
                 labels.push(
@@ -488,6 +500,15 @@ where
                         .with_message("Object being defined here"),
                 );
             }
+            CompileErrorKind::BadArgumentCount {
+                declared: Some(declared),
+                ..
+            } => {
+                labels.push(
+                    d::Label::secondary(declared.source_id, declared.span.range())
+                        .with_message("Declared here"),
+                );
+            }
             CompileErrorKind::ModAlreadyLoaded { existing, .. } => {
                 let (existing_source_id, existing_span) = *existing;
 
@@ -555,6 +576,7 @@ where
         span: Span,
         kind: &QueryErrorKind,
         labels: &mut Vec<d::Label<SourceId>>,
+        notes: &mut Vec<String>,
     ) -> fmt::Result {
         match kind {
             QueryErrorKind::ImportCycle { path } => {
@@ -580,12 +602,7 @@ where
                 location: Location { source_id, span },
                 ..
             } => {
-                for Location { source_id, span } in chain {
-                    labels.push(
-                        d::Label::secondary(*source_id, span.range())
-                            .with_message("Re-exported here"),
-                    );
-                }
+                format_import_chain(chain, labels, notes);
 
                 labels.push(
                     d::Label::secondary(*source_id, span.range()).with_message("defined here"),
@@ -596,12 +613,7 @@ where
                 location: Location { source_id, span },
                 ..
             } => {
-                for Location { source_id, span } in chain {
-                    labels.push(
-                        d::Label::secondary(*source_id, span.range())
-                            .with_message("Re-exported here"),
-                    );
-                }
+                format_import_chain(chain, labels, notes);
 
                 labels.push(
                     d::Label::secondary(*source_id, span.range())
@@ -629,4 +641,40 @@ where
 
         Ok(())
     }
+
+    /// Label each step of an import chain, and note the full chain.
+    fn format_import_chain(
+        chain: &[ImportStep],
+        labels: &mut Vec<d::Label<SourceId>>,
+        notes: &mut Vec<String>,
+    ) {
+        if chain.is_empty() {
+            return;
+        }
+
+        for (step, entry) in (1..).zip(chain) {
+            let what = if step == 1 {
+                "imported"
+            } else {
+                "re-exported"
+            };
+
+            labels.push(
+                d::Label::secondary(entry.location.source_id, entry.location.span.range())
+                    .with_message(format!("Step #{step}: `{}` {what} here", entry.item)),
+            );
+        }
+
+        let mut note = String::from("Import chain: ");
+
+        for (n, entry) in chain.iter().enumerate() {
+            if n > 0 {
+                note.push_str(" -> ");
+            }
+
+            let _ = write!(note, "`{}`", entry.item);
+        }
+
+        notes.push(note);
+    }
 }
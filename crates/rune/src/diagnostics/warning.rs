@@ -43,7 +43,10 @@ impl WarningDiagnostic {
             WarningDiagnosticKind::LetPatternMightPanic { context, .. }
             | WarningDiagnosticKind::RemoveTupleCallParams { context, .. }
             | WarningDiagnosticKind::NotUsed { context, .. }
-            | WarningDiagnosticKind::TemplateWithoutExpansions { context, .. } => *context,
+            | WarningDiagnosticKind::TemplateWithoutExpansions { context, .. }
+            | WarningDiagnosticKind::UsedDeprecated { context, .. }
+            | WarningDiagnosticKind::ShadowedLocal { context, .. }
+            | WarningDiagnosticKind::Unreachable { context, .. } => *context,
             WarningDiagnosticKind::UnecessarySemiColon { .. } => None,
         }
     }
@@ -58,6 +61,9 @@ impl Spanned for WarningDiagnostic {
             WarningDiagnosticKind::TemplateWithoutExpansions { span, .. } => *span,
             WarningDiagnosticKind::RemoveTupleCallParams { span, .. } => *span,
             WarningDiagnosticKind::UnecessarySemiColon { span, .. } => *span,
+            WarningDiagnosticKind::UsedDeprecated { span, .. } => *span,
+            WarningDiagnosticKind::ShadowedLocal { span, .. } => *span,
+            WarningDiagnosticKind::Unreachable { span, .. } => *span,
         }
     }
 }
@@ -120,4 +126,34 @@ pub enum WarningDiagnosticKind {
         /// Span where the semi-colon is.
         span: Span,
     },
+    /// A deprecated item is used.
+    #[error("Used deprecated item: {message}")]
+    UsedDeprecated {
+        /// The span where the item is used.
+        span: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+        /// The deprecation notice of the item.
+        message: &'static str,
+    },
+    /// A `let` binding shadows a local which is already in scope.
+    #[error("Local shadows an existing local with the same name")]
+    ShadowedLocal {
+        /// The span of the new binding.
+        span: Span,
+        /// The span where the shadowed local was declared.
+        shadowed: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
+    /// Code which follows a diverging expression can never be reached.
+    #[error("Unreachable code")]
+    Unreachable {
+        /// The span of the unreachable code.
+        span: Span,
+        /// The span of the diverging expression.
+        cause: Span,
+        /// The context in which it is used.
+        context: Option<Span>,
+    },
 }
@@ -1,6 +1,6 @@
 //! Helper to generate documentation from a context.
 
-mod context;
+pub(crate) mod context;
 pub(crate) use self::context::Context;
 
 mod templating;
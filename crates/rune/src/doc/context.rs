@@ -28,6 +28,67 @@ pub(crate) struct Meta<'a> {
     pub(crate) kind: Kind<'a>,
     /// Documentation for the meta item.
     pub(crate) docs: &'a [String],
+    /// If the meta item is marked as unstable.
+    pub(crate) unstable: bool,
+}
+
+impl Meta<'_> {
+    /// Extract the fenced code blocks from the documentation of this item.
+    pub(crate) fn examples(&self) -> Vec<Example> {
+        examples(self.docs)
+    }
+}
+
+/// An example code block extracted from documentation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Example {
+    /// The code inside of the block.
+    pub(crate) code: String,
+    /// The language of the block, which defaults to `rune` if none is
+    /// specified.
+    pub(crate) language: String,
+}
+
+/// Extract all code blocks from the given documentation lines in the order in
+/// which they appear.
+pub(crate) fn examples(docs: &[String]) -> Vec<Example> {
+    use pulldown_cmark::{CodeBlockKind, Event, Parser, Tag};
+
+    let input = docs.join("\n");
+
+    let mut out = Vec::new();
+    let mut current = None;
+
+    for event in Parser::new(&input) {
+        match event {
+            Event::Start(Tag::CodeBlock(kind)) => {
+                let language = match &kind {
+                    CodeBlockKind::Fenced(fences) => {
+                        fences.split(',').next().unwrap_or_default().trim()
+                    }
+                    CodeBlockKind::Indented => "",
+                };
+
+                let language = if language.is_empty() { "rune" } else { language };
+
+                current = Some(Example {
+                    code: String::new(),
+                    language: language.to_owned(),
+                });
+            }
+            Event::Text(text) => {
+                if let Some(example) = &mut current {
+                    example.code.push_str(&text);
+                }
+            }
+            Event::End(Tag::CodeBlock(..)) => {
+                out.extend(current.take());
+            }
+            _ => {}
+        }
+    }
+
+    out
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -52,12 +113,16 @@ pub(crate) enum AssocFnKind<'a> {
     Method(&'a str, Option<usize>, Signature),
 }
 
-/// Information on an associated function.
+/// Information on an associated variant.
 pub(crate) struct AssocVariant<'a> {
     /// Name of variant.
     pub(crate) name: &'a str,
     /// Documentation for variant.
     pub(crate) docs: &'a [String],
+    /// The hash of the enum the variant belongs to.
+    pub(crate) enum_hash: Hash,
+    /// The fields of the variant.
+    pub(crate) fields: &'a meta::Fields,
 }
 
 /// Information on an associated function.
@@ -86,7 +151,7 @@ pub(crate) enum Assoc<'a> {
 pub(crate) enum Kind<'a> {
     Unsupported,
     Type,
-    Struct,
+    Struct(&'a meta::Fields),
     Variant,
     Enum,
     Macro,
@@ -101,6 +166,37 @@ pub(crate) enum Signature {
     Instance,
 }
 
+/// A single child item of a module.
+pub(crate) struct ModuleEntry<'a> {
+    /// The full item of the entry.
+    pub(crate) item: ItemBuf,
+    /// The name of the entry inside of its module.
+    pub(crate) name: ComponentRef<'a>,
+    /// Meta of the entry.
+    pub(crate) meta: Meta<'a>,
+    /// The first line of documentation for the entry, if any.
+    pub(crate) summary: Option<&'a str>,
+}
+
+/// The child items of a module, grouped by kind.
+#[derive(Default)]
+pub(crate) struct ModuleContents<'a> {
+    /// Plain types.
+    pub(crate) types: Vec<ModuleEntry<'a>>,
+    /// Structs.
+    pub(crate) structs: Vec<ModuleEntry<'a>>,
+    /// Enums.
+    pub(crate) enums: Vec<ModuleEntry<'a>>,
+    /// Free functions.
+    pub(crate) functions: Vec<ModuleEntry<'a>>,
+    /// Constants.
+    pub(crate) consts: Vec<ModuleEntry<'a>>,
+    /// Macros.
+    pub(crate) macros: Vec<ModuleEntry<'a>>,
+    /// Submodules.
+    pub(crate) modules: Vec<ModuleEntry<'a>>,
+}
+
 /// Build context for documentation.
 ///
 /// Provides a unified API for querying information about known types.
@@ -140,10 +236,12 @@ impl<'a> Context<'a> {
                         f.is_async,
                         AssocFnKind::Method(data.item.last()?.as_str()?, f.args, Signature::Instance),
                     ),
-                    Some(meta::Kind::Variant { .. }) => {
+                    Some(meta::Kind::Variant { enum_hash, ref fields, .. }) => {
                         return Some(Assoc::Variant(AssocVariant {
                             name: data.item.last()?.as_str()?,
                             docs: &data.docs,
+                            enum_hash,
+                            fields,
                         }));
                     }
                     _ => return None,
@@ -166,8 +264,13 @@ impl<'a> Context<'a> {
             let name = meta.item.as_deref()?.last()?.as_str()?;
 
             match &meta.kind {
-                meta::Kind::Variant { .. } => {
-                    Some(Assoc::Variant(AssocVariant { name, docs: meta.docs.lines() }))
+                meta::Kind::Variant { enum_hash, fields, .. } => {
+                    Some(Assoc::Variant(AssocVariant {
+                        name,
+                        docs: meta.docs.lines(),
+                        enum_hash: *enum_hash,
+                        fields,
+                    }))
                 }
                 meta::Kind::AssociatedFunction { kind, parameter_types, signature, .. } => {
                     let kind = match *kind {
@@ -276,10 +379,46 @@ impl<'a> Context<'a> {
         out
     }
 
+    /// Collect the child items of the given module, grouped by kind.
+    ///
+    /// Instance functions are skipped, since they are documented on the type
+    /// they are associated with.
+    pub(crate) fn module_contents(&self, item: &'a Item) -> ModuleContents<'a> {
+        let mut contents = ModuleContents::default();
+
+        for (_, name) in self.iter_components(item) {
+            let item = item.join([name]);
+
+            for meta in self.meta(&item) {
+                let group = match meta.kind {
+                    Kind::Type => &mut contents.types,
+                    Kind::Struct(..) => &mut contents.structs,
+                    Kind::Enum => &mut contents.enums,
+                    Kind::Function(f) if matches!(f.signature, Signature::Function) => {
+                        &mut contents.functions
+                    }
+                    Kind::Const(..) => &mut contents.consts,
+                    Kind::Macro => &mut contents.macros,
+                    Kind::Module => &mut contents.modules,
+                    _ => continue,
+                };
+
+                group.push(ModuleEntry {
+                    item: item.clone(),
+                    name,
+                    meta,
+                    summary: meta.docs.first().map(String::as_str),
+                });
+            }
+        }
+
+        contents
+    }
+
     fn context_meta_to_meta(&self, meta: &'a ContextMeta) -> Option<Meta<'a>> {
         let kind = match &meta.kind {
             meta::Kind::Type { .. } => Kind::Type,
-            meta::Kind::Struct { .. } => Kind::Struct,
+            meta::Kind::Struct { fields, .. } => Kind::Struct(fields),
             meta::Kind::Variant { .. } => Kind::Variant,
             meta::Kind::Enum { .. } => Kind::Enum,
             meta::Kind::Function {
@@ -319,10 +458,25 @@ impl<'a> Context<'a> {
             item: meta.item.as_deref(),
             hash: meta.hash,
             docs: meta.docs.lines(),
+            unstable: meta.unstable,
             kind,
         })
     }
 
+    /// Get the protocols implemented by the type with the given hash, in the
+    /// order that they were registered.
+    pub(crate) fn implemented_protocols(&self, hash: Hash) -> Vec<Protocol> {
+        self.context.implemented_protocols(hash)
+    }
+
+    /// Iterate over all items in the context which are marked as unstable.
+    pub(crate) fn unstable_items(&self) -> impl Iterator<Item = Meta<'a>> + '_ {
+        self.context
+            .iter_meta()
+            .filter(|meta| meta.unstable)
+            .flat_map(|meta| self.context_meta_to_meta(meta))
+    }
+
     /// Iterate over known modules.
     pub(crate) fn iter_modules(&self) -> impl IntoIterator<Item = ItemBuf> + '_ {
         self.visitors
@@ -335,7 +489,7 @@ impl<'a> Context<'a> {
 fn visitor_meta_to_meta<'a>(base: &'a Item, data: &'a VisitorData) -> Meta<'a> {
     let kind = match &data.kind {
         Some(meta::Kind::Type { .. }) => Kind::Type,
-        Some(meta::Kind::Struct { .. }) => Kind::Struct,
+        Some(meta::Kind::Struct { fields, .. }) => Kind::Struct(fields),
         Some(meta::Kind::Variant { .. }) => Kind::Variant,
         Some(meta::Kind::Enum { .. }) => Kind::Enum,
         Some(meta::Kind::Function { signature: f, .. }) => Kind::Function(Function {
@@ -354,6 +508,7 @@ fn visitor_meta_to_meta<'a>(base: &'a Item, data: &'a VisitorData) -> Meta<'a> {
             return_type: f.return_type,
             argument_types: &f.argument_types,
         }),
+        Some(meta::Kind::Const { const_value }) => Kind::Const(const_value),
         Some(meta::Kind::Module) => Kind::Module,
         _ => Kind::Unsupported,
     };
@@ -363,6 +518,7 @@ fn visitor_meta_to_meta<'a>(base: &'a Item, data: &'a VisitorData) -> Meta<'a> {
         item: Some(&data.item),
         hash: data.hash,
         docs: data.docs.as_slice(),
+        unstable: false,
         kind,
     }
 }
@@ -11,7 +11,7 @@ use std::str;
 
 use crate::no_std::prelude::*;
 use crate::no_std::borrow::Cow;
-use crate::no_std::collections::VecDeque;
+use crate::no_std::collections::{HashMap, VecDeque};
 
 use anyhow::{anyhow, bail, Context as _, Error, Result};
 use relative_path::{RelativePath, RelativePathBuf};
@@ -25,9 +25,10 @@ use base64::{display::Base64Display};
 use base64::engine::general_purpose::URL_SAFE_NO_PAD;
 
 use crate::compile::{ComponentRef, Item, ItemBuf};
-use crate::doc::context::{Function, Kind, Signature, Meta};
+use crate::doc::context::{Example, Function, Kind, Meta, ModuleEntry, Signature};
 use crate::doc::templating;
 use crate::doc::{Context, Visitor};
+use crate::runtime::ConstValue;
 use crate::Hash;
 
 // InspiredGitHub
@@ -108,6 +109,8 @@ pub fn write_html(
 
     let partials = [
         ("layout", asset_str("layout.html.hbs")?),
+        ("implements", asset_str("implements.html.hbs")?),
+        ("unstable", asset_str("unstable.html.hbs")?),
     ];
 
     let templating = templating::Templating::new(partials, paths.clone())?;
@@ -166,6 +169,7 @@ pub fn write_html(
         root,
         state: State::default(),
         index: Vec::new(),
+        pages: HashMap::new(),
         name,
         context: &context,
         search_index: Some(search_index),
@@ -329,6 +333,13 @@ pub(crate) struct IndexEntry<'m> {
     pub(crate) doc: Option<String>,
 }
 
+/// A page which has been generated for an item.
+struct Page<'m> {
+    kind: ItemKind,
+    item: &'m Item,
+    path: RelativePathBuf,
+}
+
 #[derive(Default, Clone)]
 pub(crate) struct State {
     path: RelativePathBuf,
@@ -339,6 +350,9 @@ pub(crate) struct Ctxt<'a, 'm> {
     state: State,
     /// A collection of all items visited.
     index: Vec<IndexEntry<'m>>,
+    /// Pages generated for each item hash, regardless of whether the item
+    /// came from the context or from a source visitor.
+    pages: HashMap<Hash, Page<'m>>,
     name: &'a str,
     context: &'a Context<'m>,
     search_index: Option<&'a RelativePath>,
@@ -358,7 +372,7 @@ impl<'m> Ctxt<'_, 'm> {
     fn set_path(&mut self, meta: Meta<'m>) -> Result<()> {
         let item_kind = match &meta.kind {
             Kind::Type => ItemKind::Type,
-            Kind::Struct => ItemKind::Struct,
+            Kind::Struct(..) => ItemKind::Struct,
             Kind::Enum => ItemKind::Enum,
             Kind::Macro => ItemKind::Macro,
             Kind::Function(..) => ItemKind::Function,
@@ -373,6 +387,12 @@ impl<'m> Ctxt<'_, 'm> {
 
         let doc = self.render_docs(meta, meta.docs.get(..1).unwrap_or_default())?;
 
+        self.pages.insert(meta.hash, Page {
+            kind: item_kind,
+            item,
+            path: self.state.path.clone(),
+        });
+
         self.index.push(IndexEntry {
             path: self.state.path.clone(),
             item: Cow::Borrowed(item),
@@ -416,12 +436,59 @@ impl<'m> Ctxt<'_, 'm> {
         ))
     }
 
+    /// Render an example extracted from documentation.
+    fn render_example(&self, example: &Example) -> Result<String> {
+        let lookup = match example.language.as_str() {
+            markdown::RUNE_TOKEN => markdown::RUST_TOKEN,
+            language => language,
+        };
+
+        let syntax = match self.syntax_set.find_syntax_by_token(lookup) {
+            Some(syntax) => syntax,
+            None => self.syntax_set.find_syntax_plain_text(),
+        };
+
+        let mut o = String::new();
+        o.push_str("<pre><code class=\"language-");
+        pulldown_cmark::escape::escape_href(&mut o, &example.language)?;
+        o.push_str("\">");
+        o.push_str(&render_code_by_syntax(&self.syntax_set, example.code.lines(), syntax)?);
+        o.push_str("</code></pre>");
+        Ok(o)
+    }
+
+    /// Render documentation, with its examples in a separate section.
+    fn render_docs_and_examples(&self, meta: Meta<'_>) -> Result<(Option<String>, Vec<String>)> {
+        let examples = meta.examples();
+
+        if examples.is_empty() {
+            return Ok((self.render_docs(meta, meta.docs)?, Vec::new()));
+        }
+
+        let doc = self.render_markdown(meta, meta.docs, false)?;
+        let examples = examples.iter().map(|e| self.render_example(e)).collect::<Result<_>>()?;
+        Ok((doc, examples))
+    }
+
     /// Render documentation.
     fn render_docs<S>(&self, meta: Meta<'_>, docs: &[S]) -> Result<Option<String>>
     where
         S: AsRef<str>,
     {
-        use pulldown_cmark::{Options, Parser, BrokenLink};
+        self.render_markdown(meta, docs, true)
+    }
+
+    /// Render markdown documentation, optionally leaving out code blocks.
+    fn render_markdown<S>(
+        &self,
+        meta: Meta<'_>,
+        docs: &[S],
+        code_blocks: bool,
+    ) -> Result<Option<String>>
+    where
+        S: AsRef<str>,
+    {
+        use pulldown_cmark::{Event, Options, Parser, BrokenLink, Tag};
         use std::fmt::Write;
 
         if docs.is_empty() {
@@ -449,6 +516,26 @@ impl<'m> Ctxt<'_, 'm> {
 
         let iter = Parser::new_with_broken_link_callback(&input, options, Some(&mut callback));
 
+        let mut in_code_block = false;
+
+        let iter = iter.filter(|event| {
+            if code_blocks {
+                return true;
+            }
+
+            match event {
+                Event::Start(Tag::CodeBlock(..)) => {
+                    in_code_block = true;
+                    false
+                }
+                Event::End(Tag::CodeBlock(..)) => {
+                    in_code_block = false;
+                    false
+                }
+                _ => !in_code_block,
+            }
+        });
+
         markdown::push_html(&self.syntax_set, &mut o, iter)?;
         write!(o, "</div>")?;
         Ok(Some(o))
@@ -487,11 +574,30 @@ impl<'m> Ctxt<'_, 'm> {
     }
 
     /// Convert a hash into a link.
+    ///
+    /// This prefers pages which have already been generated, falling back to
+    /// looking up the meta by hash and constructing the path of the page it
+    /// would be generated to.
     fn link(&self, hash: Hash, text: Option<&str>) -> Result<Option<String>> {
+        if let Some(page) = self.pages.get(&hash) {
+            let name = match text {
+                Some(text) => text,
+                None => page
+                    .item
+                    .last()
+                    .and_then(|c| c.as_str())
+                    .context("missing name")?,
+            };
+
+            let kind = page.kind;
+            let path = self.dir().relative(&page.path);
+            return Ok(Some(format!("<a class=\"{kind}\" href=\"{path}\">{name}</a>")));
+        }
+
         fn into_item_kind(meta: Meta<'_>) -> Option<ItemKind> {
             match &meta.kind {
                 Kind::Type => Some(ItemKind::Type),
-                Kind::Struct => Some(ItemKind::Struct),
+                Kind::Struct(..) => Some(ItemKind::Struct),
                 Kind::Enum => Some(ItemKind::Enum),
                 Kind::Function { .. } => Some(ItemKind::Function),
                 _ => None,
@@ -664,7 +770,12 @@ impl<'m> Ctxt<'_, 'm> {
         let link = link.trim_matches(|c| matches!(c, '`'));
         let (link, flavor) = flavor(link);
 
-        let item = if matches!(meta.kind, Kind::Module) {
+        // Absolute links such as `::std::option::Option` can refer to items in
+        // any crate, regardless of where they are documented from.
+        let item = if let Some(link) = link.strip_prefix("::") {
+            let mut it = link.split("::");
+            ItemBuf::with_crate_item(it.next()?, it)
+        } else if matches!(meta.kind, Kind::Module) {
             meta.item?.join([link])
         } else {
             meta.item?.parent()?.join([link])
@@ -675,7 +786,7 @@ impl<'m> Ctxt<'_, 'm> {
 
             for meta in self.context.meta(&item) {
                 alts.push(match meta.kind {
-                    Kind::Struct if flavor.is_struct() => ItemKind::Struct,
+                    Kind::Struct(..) if flavor.is_struct() => ItemKind::Struct,
                     Kind::Enum if flavor.is_enum() => ItemKind::Enum,
                     Kind::Macro if flavor.is_macro() => ItemKind::Macro,
                     Kind::Function(_) if flavor.is_function() => ItemKind::Function,
@@ -738,6 +849,7 @@ fn build_index<'m>(cx: &Ctxt<'_, 'm>, mods: Vec<(&'m Item, RelativePathBuf)>) ->
         #[serde(flatten)]
         shared: Shared<'a>,
         modules: Vec<Module<'a>>,
+        unstable: Vec<Unstable<'a>>,
     }
 
     #[derive(Serialize)]
@@ -747,6 +859,13 @@ fn build_index<'m>(cx: &Ctxt<'_, 'm>, mods: Vec<(&'m Item, RelativePathBuf)>) ->
         path: RelativePathBuf,
     }
 
+    #[derive(Serialize)]
+    struct Unstable<'a> {
+        #[serde(serialize_with = "serialize_item")]
+        item: &'a Item,
+        path: Option<RelativePathBuf>,
+    }
+
     let mut modules = Vec::new();
 
     for (item, path) in mods {
@@ -765,10 +884,25 @@ fn build_index<'m>(cx: &Ctxt<'_, 'm>, mods: Vec<(&'m Item, RelativePathBuf)>) ->
         modules.push(Module { item, path });
     }
 
+    let mut unstable = Vec::new();
+
+    for meta in cx.context.unstable_items() {
+        let Some(item) = meta.item else {
+            continue;
+        };
+
+        // Not every item has a page of its own, like constants.
+        let path = cx.pages.get(&meta.hash).map(|page| cx.dir().relative(&page.path));
+        unstable.push(Unstable { item, path });
+    }
+
+    unstable.sort_by(|a, b| a.item.cmp(b.item));
+
     Ok(Builder::new(cx, move |cx| {
         cx.index_template.render(&Params {
             shared: cx.shared(),
             modules,
+            unstable,
         })
     }))
 }
@@ -783,12 +917,14 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
         #[serde(serialize_with = "serialize_item")]
         item: &'a Item,
         module: String,
+        unstable: bool,
         doc: Option<String>,
         types: Vec<Type<'a>>,
         structs: Vec<Struct<'a>>,
         enums: Vec<Enum<'a>>,
         macros: Vec<Macro<'a>>,
         functions: Vec<Function<'a>>,
+        consts: Vec<Const<'a>>,
         modules: Vec<Module<'a>>,
     }
 
@@ -799,7 +935,7 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
         #[serde(serialize_with = "serialize_component_ref")]
         name: ComponentRef<'a>,
         path: RelativePathBuf,
-        first: Option<&'a String>,
+        first: Option<&'a str>,
     }
 
     #[derive(Serialize)]
@@ -809,7 +945,7 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
         item: ItemBuf,
         #[serde(serialize_with = "serialize_component_ref")]
         name: ComponentRef<'a>,
-        first: Option<&'a String>,
+        first: Option<&'a str>,
     }
 
     #[derive(Serialize)]
@@ -819,7 +955,7 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
         item: ItemBuf,
         #[serde(serialize_with = "serialize_component_ref")]
         name: ComponentRef<'a>,
-        first: Option<&'a String>,
+        first: Option<&'a str>,
     }
 
     #[derive(Serialize)]
@@ -844,6 +980,14 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
         doc: Option<String>,
     }
 
+    #[derive(Serialize)]
+    struct Const<'a> {
+        #[serde(serialize_with = "serialize_component_ref")]
+        name: ComponentRef<'a>,
+        value: String,
+        doc: Option<String>,
+    }
+
     #[derive(Serialize)]
     struct Module<'a> {
         #[serde(serialize_with = "serialize_item")]
@@ -855,94 +999,91 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
 
     let meta_item = meta.item.context("Missing item")?;
 
+    let contents = cx.context.module_contents(meta_item);
+
     let mut types = Vec::new();
     let mut structs = Vec::new();
     let mut enums = Vec::new();
     let mut macros = Vec::new();
     let mut functions = Vec::new();
+    let mut consts = Vec::new();
     let mut modules = Vec::new();
 
-    for (_, name) in cx.context.iter_components(meta_item) {
-        let item = meta_item.join([name]);
-
-        for m in cx.context.meta(&item) {
-            match m.kind {
-                Kind::Type { .. } => {
-                    queue.push_front(Build::Type(m));
-                    let path = cx.item_path(&item, ItemKind::Type)?;
-                    types.push(Type {
-                        item: item.clone(),
-                        path,
-                        name,
-                        first: m.docs.first(),
-                    });
-                }
-                Kind::Struct { .. } => {
-                    queue.push_front(Build::Struct(m));
-                    let path = cx.item_path(&item, ItemKind::Struct)?;
-                    structs.push(Struct {
-                        item: item.clone(),
-                        path,
-                        name,
-                        first: m.docs.first(),
-                    });
-                }
-                Kind::Enum { .. } => {
-                    queue.push_front(Build::Enum(m));
-                    let path = cx.item_path(&item, ItemKind::Enum)?;
-                    enums.push(Enum {
-                        item: item.clone(),
-                        path,
-                        name,
-                        first: m.docs.first(),
-                    });
-                }
-                Kind::Macro => {
-                    let item = m.item.context("Missing macro item")?;
+    for ModuleEntry { item, name, meta: m, summary } in contents.types {
+        queue.push_front(Build::Type(m));
+        let path = cx.item_path(&item, ItemKind::Type)?;
+        types.push(Type { item, path, name, first: summary });
+    }
 
-                    queue.push_front(Build::Macro(m));
+    for ModuleEntry { item, name, meta: m, summary } in contents.structs {
+        queue.push_front(Build::Struct(m));
+        let path = cx.item_path(&item, ItemKind::Struct)?;
+        structs.push(Struct { item, path, name, first: summary });
+    }
 
-                    macros.push(Macro {
-                        path: cx.item_path(item, ItemKind::Macro)?,
-                        item,
-                        name,
-                        doc: cx.render_docs(m, m.docs.get(..1).unwrap_or_default())?,
-                    });
-                }
-                Kind::Function(f) => {
-                    if matches!(f.signature, Signature::Instance { .. }) {
-                        continue;
-                    }
+    for ModuleEntry { item, name, meta: m, summary } in contents.enums {
+        queue.push_front(Build::Enum(m));
+        let path = cx.item_path(&item, ItemKind::Enum)?;
+        enums.push(Enum { item, path, name, first: summary });
+    }
 
-                    queue.push_front(Build::Function(m));
+    for ModuleEntry { name, meta: m, .. } in contents.macros {
+        let item = m.item.context("Missing macro item")?;
 
-                    functions.push(Function {
-                        is_async: f.is_async,
-                        path: cx.item_path(&item, ItemKind::Function)?,
-                        item: item.clone(),
-                        name,
-                        args: cx.args_to_string(f.arg_names, f.args, f.signature, f.argument_types)?,
-                        doc: cx.render_docs(m, m.docs.get(..1).unwrap_or_default())?,
-                    });
-                }
-                Kind::Module => {
-                    let item = m.item.context("Missing module item")?;
+        queue.push_front(Build::Macro(m));
 
-                    // Skip over crate items, since they are added separately.
-                    if meta_item.is_empty() && item.as_crate().is_some() {
-                        continue;
-                    }
+        macros.push(Macro {
+            path: cx.item_path(item, ItemKind::Macro)?,
+            item,
+            name,
+            doc: cx.render_docs(m, m.docs.get(..1).unwrap_or_default())?,
+        });
+    }
 
-                    queue.push_front(Build::Module(m));
-                    let path = cx.item_path(item, ItemKind::Module)?;
-                    let name = item.last().context("missing name of module")?;
-                    modules.push(Module { item, name, path })
-                }
-                _ => {
-                    continue;
-                }
-            }
+    for ModuleEntry { item, name, meta: m, .. } in contents.functions {
+        let Kind::Function(f) = m.kind else {
+            continue;
+        };
+
+        queue.push_front(Build::Function(m));
+
+        functions.push(Function {
+            is_async: f.is_async,
+            path: cx.item_path(&item, ItemKind::Function)?,
+            item,
+            name,
+            args: cx.args_to_string(f.arg_names, f.args, f.signature, f.argument_types)?,
+            doc: cx.render_docs(m, m.docs.get(..1).unwrap_or_default())?,
+        });
+    }
+
+    for ModuleEntry { name, meta: m, .. } in contents.consts {
+        let Kind::Const(value) = m.kind else {
+            continue;
+        };
+
+        let mut string = String::new();
+        const_value(&mut string, value)?;
+
+        consts.push(Const {
+            name,
+            value: string,
+            doc: cx.render_docs(m, m.docs.get(..1).unwrap_or_default())?,
+        });
+    }
+
+    for ModuleEntry { meta: m, .. } in contents.modules {
+        let item = m.item.context("Missing module item")?;
+
+        // Skip over crate items, since they are added separately.
+        if meta_item.is_empty() && item.as_crate().is_some() {
+            continue;
         }
+
+        queue.push_front(Build::Module(m));
+        let path = cx.item_path(item, ItemKind::Module)?;
+        let name = item.last().context("missing name of module")?;
+        modules.push(Module { item, name, path })
     }
 
     Ok(Builder::new(cx, move |cx| {
@@ -950,17 +1091,90 @@ fn module<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, queue: &mut VecDeque<Build<'m>>
             shared: cx.shared(),
             item: meta_item,
             module: cx.module_path_html(meta, true)?,
+            unstable: meta.unstable,
             doc: cx.render_docs(meta, meta.docs)?,
             types,
             structs,
             enums,
             macros,
             functions,
+            consts,
             modules,
         })
     }))
 }
 
+/// Render a constant value using rune syntax.
+fn const_value(out: &mut String, value: &ConstValue) -> fmt::Result {
+    match value {
+        ConstValue::Unit => out.write_str("()")?,
+        ConstValue::Byte(b) => write!(out, "b'{}'", b.escape_ascii())?,
+        ConstValue::Char(c) => write!(out, "{c:?}")?,
+        ConstValue::Bool(b) => write!(out, "{b}")?,
+        ConstValue::Integer(n) => write!(out, "{n}")?,
+        ConstValue::Float(n) => write!(out, "{n:?}")?,
+        ConstValue::String(s) => write!(out, "{s:?}")?,
+        ConstValue::StaticString(s) => write!(out, "{:?}", s.as_str())?,
+        ConstValue::Bytes(b) => write!(out, "b\"{}\"", b.escape_ascii())?,
+        ConstValue::Vec(values) => {
+            out.write_char('[')?;
+            const_values(out, values)?;
+            out.write_char(']')?;
+        }
+        ConstValue::Tuple(values) => {
+            out.write_char('(')?;
+            const_values(out, values)?;
+
+            if values.len() == 1 {
+                out.write_char(',')?;
+            }
+
+            out.write_char(')')?;
+        }
+        ConstValue::Object(object) => {
+            let mut entries = object.iter().collect::<Vec<_>>();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+
+            out.write_str("#{")?;
+
+            let mut it = entries.into_iter().peekable();
+
+            while let Some((key, value)) = it.next() {
+                write!(out, "{key}: ")?;
+                const_value(out, value)?;
+
+                if it.peek().is_some() {
+                    out.write_str(", ")?;
+                }
+            }
+
+            out.write_char('}')?;
+        }
+        ConstValue::Option(None) => out.write_str("None")?,
+        ConstValue::Option(Some(value)) => {
+            out.write_str("Some(")?;
+            const_value(out, value)?;
+            out.write_char(')')?;
+        }
+    }
+
+    Ok(())
+}
+
+fn const_values(out: &mut String, values: &[ConstValue]) -> fmt::Result {
+    let mut it = values.iter().peekable();
+
+    while let Some(value) = it.next() {
+        const_value(out, value)?;
+
+        if it.peek().is_some() {
+            out.write_str(", ")?;
+        }
+    }
+
+    Ok(())
+}
+
 /// Build a macro.
 #[tracing::instrument(skip_all)]
 fn build_macro<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>> {
@@ -973,10 +1187,12 @@ fn build_macro<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>> {
         item: &'a Item,
         #[serde(serialize_with = "serialize_component_ref")]
         name: ComponentRef<'a>,
+        unstable: bool,
         doc: Option<String>,
+        examples: Vec<String>,
     }
 
-    let doc = cx.render_docs(meta, meta.docs)?;
+    let (doc, examples) = cx.render_docs_and_examples(meta)?;
     let item = meta.item.context("Missing item")?;
     let name = item.last().context("Missing macro name")?;
 
@@ -986,7 +1202,9 @@ fn build_macro<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>> {
             module: cx.module_path_html(meta, false)?,
             item,
             name,
+            unstable: meta.unstable,
             doc,
+            examples,
         })
     }))
 }
@@ -1005,7 +1223,9 @@ fn build_function<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>>
         #[serde(serialize_with = "serialize_component_ref")]
         name: ComponentRef<'a>,
         args: String,
+        unstable: bool,
         doc: Option<String>,
+        examples: Vec<String>,
         return_type: Option<String>,
     }
 
@@ -1017,17 +1237,18 @@ fn build_function<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>>
         _ => bail!("found meta, but not a function"),
     };
 
-    let doc = cx.render_docs(meta, meta.docs)?;
-
-    let return_type = match f.return_type {
-        Some(hash) => cx.link(hash, None)?,
-        None => None,
-    };
+    let (doc, examples) = cx.render_docs_and_examples(meta)?;
 
     let item = meta.item.context("Missing item")?;
     let name = item.last().context("Missing item name")?;
 
     Ok(Builder::new(cx, move |cx| {
+        // Links are resolved once all pages are known.
+        let return_type = match f.return_type {
+            Some(hash) => cx.link(hash, None)?,
+            None => None,
+        };
+
         cx.function_template.render(&Params {
             shared: cx.shared(),
             module: cx.module_path_html(meta, false)?,
@@ -1035,7 +1256,9 @@ fn build_function<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<Builder<'m>>
             item,
             name,
             args: cx.args_to_string(f.arg_names, f.args, f.signature, f.argument_types)?,
+            unstable: meta.unstable,
             doc,
+            examples,
             return_type,
         })
     }))
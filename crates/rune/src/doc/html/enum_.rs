@@ -19,6 +19,8 @@ pub(super) fn build<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<(Builder<'m
         name: ComponentRef<'a>,
         #[serde(serialize_with = "super::serialize_item")]
         item: &'a Item,
+        unstable: bool,
+        implements: Vec<super::type_::Implements>,
         variants: Vec<super::type_::Variant<'a>>,
         methods: Vec<super::type_::Method<'a>>,
         protocols: Vec<super::type_::Protocol<'a>>,
@@ -30,6 +32,7 @@ pub(super) fn build<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<(Builder<'m
     let (protocols, methods, variants, index) = super::type_::build_assoc_fns(cx, meta)?;
     let item = meta.item.context("Missing enum item")?;
     let name = item.last().context("Missing enum name")?;
+    let implements = super::type_::implements(cx, meta);
 
     let builder = Builder::new(cx, move |cx| {
         cx.enum_template.render(&Params {
@@ -37,6 +40,8 @@ pub(super) fn build<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>) -> Result<(Builder<'m
             module,
             name,
             item,
+            unstable: meta.unstable,
+            implements,
             variants,
             methods,
             protocols,
@@ -4,8 +4,8 @@ use crate::no_std::borrow::Cow;
 use anyhow::{Context, Result};
 use serde::Serialize;
 
-use crate::compile::{ComponentRef, Item};
-use crate::doc::context::{Assoc, AssocFnKind, Meta};
+use crate::compile::{meta, ComponentRef, Item};
+use crate::doc::context::{Assoc, AssocFnKind, Kind, Meta};
 use crate::doc::html::{Ctxt, IndexEntry, IndexKind, Builder};
 
 #[derive(Serialize)]
@@ -30,10 +30,83 @@ pub(super) struct Method<'a> {
 #[derive(Serialize)]
 pub(super) struct Variant<'a> {
     name: &'a str,
+    enum_link: Option<String>,
+    fields: Option<String>,
+    named_fields: Vec<Field<'a>>,
     line_doc: Option<String>,
     doc: Option<String>,
 }
 
+#[derive(Serialize)]
+pub(super) struct Field<'a> {
+    name: &'a str,
+    doc: Option<String>,
+}
+
+/// Build the named fields of a struct or variant along with their
+/// documentation.
+fn build_fields<'m>(cx: &Ctxt<'_, 'm>, meta: Meta<'m>, fields: &'m meta::Fields) -> Result<Vec<Field<'m>>> {
+    let meta::Fields::Named(named) = fields else {
+        return Ok(Vec::new());
+    };
+
+    let mut fields = Vec::with_capacity(named.fields.len());
+
+    for name in named.fields.iter().map(|f| &**f) {
+        let doc = match named.field_docs(name) {
+            Some(docs) => cx.render_docs(meta, docs.lines())?,
+            None => None,
+        };
+
+        fields.push(Field { name, doc });
+    }
+
+    Ok(fields)
+}
+
+#[derive(Serialize)]
+pub(super) struct Implements {
+    protocol: &'static str,
+    name: Cow<'static, str>,
+}
+
+/// The protocols implemented by the given type.
+pub(super) fn implements(cx: &Ctxt<'_, '_>, meta: Meta<'_>) -> Vec<Implements> {
+    cx.context
+        .implemented_protocols(meta.hash)
+        .into_iter()
+        .map(|p| Implements {
+            protocol: p.name,
+            name: protocol_name(p.name),
+        })
+        .collect()
+}
+
+/// The user-facing name of a protocol, like `Display` for `string_display`.
+fn protocol_name(name: &'static str) -> Cow<'static, str> {
+    match name {
+        "string_display" => Cow::Borrowed("Display"),
+        "string_debug" => Cow::Borrowed("Debug"),
+        "into_iter" => Cow::Borrowed("IntoIterator"),
+        "next" => Cow::Borrowed("Iterator"),
+        "into_future" => Cow::Borrowed("Future"),
+        _ => {
+            let mut out = String::with_capacity(name.len());
+
+            for part in name.split('_') {
+                let mut chars = part.chars();
+
+                if let Some(c) = chars.next() {
+                    out.extend(c.to_uppercase());
+                    out.push_str(chars.as_str());
+                }
+            }
+
+            Cow::Owned(out)
+        }
+    }
+}
+
 pub(super) fn build_assoc_fns<'m>(
     cx: &Ctxt<'_, 'm>,
     meta: Meta<'m>,
@@ -50,8 +123,21 @@ pub(super) fn build_assoc_fns<'m>(
                 let line_doc = cx.render_docs(meta, variant.docs.get(..1).unwrap_or_default())?;
                 let doc = cx.render_docs(meta, variant.docs)?;
 
+                let fields = match variant.fields {
+                    meta::Fields::Named(named) => {
+                        Some(format!(" {{ {} }}", named.fields.join(", ")))
+                    }
+                    meta::Fields::Unnamed(0) | meta::Fields::Empty => None,
+                    meta::Fields::Unnamed(count) => {
+                        Some(format!("({})", vec!["_"; *count].join(", ")))
+                    }
+                };
+
                 variants.push(Variant {
                     name: variant.name,
+                    enum_link: cx.link(variant.enum_hash, None)?,
+                    fields,
+                    named_fields: build_fields(cx, meta, variant.fields)?,
                     line_doc,
                     doc,
                 });
@@ -170,6 +256,9 @@ struct Params<'a> {
     name: ComponentRef<'a>,
     #[serde(serialize_with = "super::serialize_item")]
     item: &'a Item,
+    unstable: bool,
+    implements: Vec<Implements>,
+    fields: Vec<Field<'a>>,
     methods: Vec<Method<'a>>,
     protocols: Vec<Protocol<'a>>,
     doc: Option<String>,
@@ -184,6 +273,13 @@ pub(super) fn build<'m>(cx: &Ctxt<'_, 'm>, what: &'static str, what_class: &'sta
     let item = meta.item.context("Missing type item")?;
     let name = item.last().context("Missing module name")?;
 
+    let fields = match meta.kind {
+        Kind::Struct(fields) => build_fields(cx, meta, fields)?,
+        _ => Vec::new(),
+    };
+
+    let implements = implements(cx, meta);
+
     let builder = Builder::new(cx, move |cx| {
         cx.type_template.render(&Params {
             shared: cx.shared(),
@@ -192,6 +288,9 @@ pub(super) fn build<'m>(cx: &Ctxt<'_, 'm>, what: &'static str, what_class: &'sta
             module,
             name,
             item,
+            unstable: meta.unstable,
+            implements,
+            fields,
             methods,
             protocols,
             doc: cx.render_docs(meta, meta.docs)?,
@@ -11,7 +11,7 @@ mod whitespace;
 
 use crate::no_std::prelude::*;
 
-use crate::ast;
+use crate::ast::{self, Span};
 use crate::parse::{Parse, Parser};
 use crate::{Source, SourceId};
 
@@ -27,9 +27,106 @@ pub fn layout_string(contents: String) -> Result<Vec<u8>, FormattingError> {
 /// Format the given source.
 pub fn layout_source(source: &Source) -> Result<Vec<u8>, FormattingError> {
     let mut parser = Parser::new(source.as_str(), SourceId::new(0), true);
-
     let ast = ast::File::parse(&mut parser)?;
+    layout_file(&ast, source)
+}
+
+/// Format an already parsed file back into source code.
+///
+/// The file must have been parsed from `source` with comments retained, since
+/// comments and literals are copied from it verbatim.
+///
+/// # Examples
+///
+/// ```
+/// use rune::{ast, fmt, SourceId, Source};
+/// use rune::parse::{Parse, Parser};
+///
+/// let code = "fn main(){let x=[1,2,];x[0]+x[1]}";
+/// let source = Source::memory(code);
+/// let mut parser = Parser::new(code, SourceId::new(0), true);
+/// let file = ast::File::parse(&mut parser)?;
+///
+/// let formatted = fmt::format_file(&file, &source)?;
+/// assert_eq!(formatted, "fn main() {\n    let x = [1, 2];\n    x[0] + x[1]\n}\n");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn format_file(file: &ast::File, source: &Source) -> Result<String, FormattingError> {
+    Ok(String::from_utf8(layout_file(file, source)?)?)
+}
+
+/// Apply a collection of edits to the given source, where each edit replaces
+/// the text covered by a span with new text.
+///
+/// Spans are typically taken from an [`ast::File`] parsed from `source`, which
+/// makes this the building block for refactorings like renaming. Text not
+/// covered by any edit is left untouched, and edits are not allowed to
+/// overlap.
+///
+/// # Examples
+///
+/// ```
+/// use rune::{ast, fmt, SourceId, Source};
+/// use rune::ast::Spanned;
+/// use rune::parse::{Parse, Parser};
+///
+/// let code = "fn foo() { 42 }";
+/// let source = Source::memory(code);
+/// let mut parser = Parser::new(code, SourceId::new(0), false);
+/// let file = ast::File::parse(&mut parser)?;
+///
+/// let Some((ast::Item::Fn(item), _)) = file.items.first() else {
+///     panic!("expected function");
+/// };
+///
+/// let edited = fmt::apply_edits(&source, [(item.name.span(), "bar")])?;
+/// assert_eq!(edited, "fn bar() { 42 }");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn apply_edits<I, S>(source: &Source, edits: I) -> Result<String, FormattingError>
+where
+    I: IntoIterator<Item = (Span, S)>,
+    S: AsRef<str>,
+{
+    let input = source.as_str();
+
+    let mut edits = edits.into_iter().collect::<Vec<_>>();
+    edits.sort_by_key(|(span, _)| *span);
+
+    let mut output = String::with_capacity(input.len());
+    let mut last: Option<Span> = None;
+
+    for (span, text) in &edits {
+        let range = span.range();
+
+        if input.get(range.clone()).is_none() {
+            return Err(FormattingError::InvalidSpan(
+                range.start,
+                range.end,
+                input.len(),
+            ));
+        }
+
+        let start = match last {
+            Some(last) if last.end > span.start => {
+                return Err(FormattingError::OverlappingEdits(last, *span));
+            }
+            Some(last) => last.range().end,
+            None => 0,
+        };
+
+        output.push_str(&input[start..range.start]);
+        output.push_str(text.as_ref());
+        last = Some(*span);
+    }
+
+    let start = last.map(|span| span.range().end).unwrap_or_default();
+    output.push_str(&input[start..]);
+    Ok(output)
+}
+
+fn layout_file(file: &ast::File, source: &Source) -> Result<Vec<u8>, FormattingError> {
     let mut printer: Printer = Printer::new(source)?;
-    printer.visit_file(&ast)?;
+    printer.visit_file(file)?;
     Ok(printer.commit())
 }
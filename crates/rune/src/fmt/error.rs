@@ -4,8 +4,10 @@ Error types for the formatting functionality.
 
 use crate::no_std as std;
 use crate::no_std::io;
+use crate::no_std::string::FromUtf8Error;
 use crate::no_std::thiserror;
 
+use crate::ast::Span;
 use crate::compile;
 
 use thiserror::Error;
@@ -18,9 +20,15 @@ pub enum FormattingError {
     #[error("Invalid span: {0}..{1} but max is {2}")]
     InvalidSpan(usize, usize, usize),
 
+    #[error("Edit at {0:?} overlaps with edit at {1:?}")]
+    OverlappingEdits(Span, Span),
+
     #[error("Error while parsing source")]
     CompileError(#[from] compile::Error),
 
     #[error("Unexpected end of input")]
     Eof,
+
+    #[error("Formatted output is not valid UTF-8")]
+    Utf8(#[from] FromUtf8Error),
 }
@@ -11,11 +11,11 @@ use crate::ast::{
     ExprClosureArgs, ExprContinue, ExprElse, ExprElseIf, ExprEmpty, ExprField, ExprFieldAccess,
     ExprFor, ExprGroup, ExprIf, ExprIndex, ExprLet, ExprLit, ExprLoop, ExprMatch, ExprMatchBranch,
     ExprObject, ExprRange, ExprReturn, ExprSelect, ExprSelectBranch, ExprSelectPatBranch, ExprTry,
-    ExprTuple, ExprUnary, ExprVec, ExprWhile, ExprYield, Field, FieldAssign, Fields, FnArg, Item,
-    ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemModBody, ItemStruct, ItemVariant,
-    LitSource, Local, MacroCall, ObjectKey, Pat, PatBinding, PatIgnore, PatLit, PatObject, PatPath,
-    PatRest, PatTuple, PatVec, Path, PathSegment, PathSegmentExpr, SelfType, SelfValue, SemiColon,
-    Span, Spanned, Stmt, StmtSemi,
+    ExprTuple, ExprUnary, ExprVec, ExprWhile, ExprYield, Field, FieldAssign, Fields, FnArg,
+    ImplItem, Item, ItemConst, ItemEnum, ItemFn, ItemImpl, ItemMod, ItemModBody, ItemStruct,
+    ItemVariant, LitSource, Local, MacroCall, ObjectKey, Pat, PatBinding, PatIgnore, PatLit,
+    PatObject, PatPath, PatRange, PatRest, PatTuple, PatVec, Path, PathSegment, PathSegmentExpr,
+    SelfType, SelfValue, SemiColon, Span, Spanned, Stmt, StmtSemi,
 };
 use crate::Source;
 
@@ -229,7 +229,7 @@ impl<'a> Printer<'a> {
             impl_,
             path,
             open,
-            functions,
+            items,
             close,
         } = item;
 
@@ -246,8 +246,12 @@ impl<'a> Printer<'a> {
 
         self.writer.indent();
 
-        for function in functions {
-            self.visit_fn(function, None)?;
+        for item in items {
+            match item {
+                ImplItem::Const(item, semi) => self.visit_const(item, Some(*semi))?,
+                ImplItem::Fn(item) => self.visit_fn(item, None)?,
+            }
+
             self.writer.newline()?;
         }
 
@@ -462,6 +466,7 @@ impl<'a> Printer<'a> {
             async_token,
             fn_token,
             name,
+            generics,
             args,
             body,
         } = item;
@@ -485,6 +490,11 @@ impl<'a> Printer<'a> {
         self.writer.write_spanned_raw(fn_token.span, false, true)?;
         self.writer.write_spanned_raw(name.span, false, false)?;
 
+        if let Some(generics) = generics {
+            self.writer
+                .write_spanned_raw(generics.span(), false, false)?;
+        }
+
         self.writer
             .write_spanned_raw(args.open.span, false, false)?;
 
@@ -495,20 +505,11 @@ impl<'a> Printer<'a> {
         } else {
             false
         };
-        for (arg, comma) in args {
-            match arg {
-                FnArg::SelfValue(selfvalue) => self.visit_self_value(selfvalue)?,
-                FnArg::Pat(pattern) => self.visit_pattern(pattern)?,
-            }
-            if let Some(comma) = comma {
-                self.writer
-                    .write_spanned_raw(comma.span, multiline, !multiline)?;
-            }
-        }
-        if args.len() > 5 {
-            self.writer.dedent();
-            self.writer.newline()?;
-        }
+        self.visit_comma_separated(args.as_slice(), multiline, false, |this, arg| match arg {
+            FnArg::SelfValue(selfvalue) => this.visit_self_value(selfvalue),
+            FnArg::Pat(pattern) => this.visit_pattern(pattern),
+        })?;
+
         self.writer
             .write_spanned_raw(args.close.span, false, true)?;
         self.visit_block(body)?;
@@ -728,29 +729,7 @@ impl<'a> Printer<'a> {
             false
         };
 
-        let count = items.len();
-        for (idx, (item, comma)) in items.iter().enumerate() {
-            self.visit_expr(item)?;
-
-            if multiline {
-                if let Some(comma) = comma {
-                    self.writer.write_spanned_raw(comma.span, true, false)?;
-                }
-            } else {
-                let is_last = count == idx + 1;
-                if !is_last {
-                    if let Some(comma) = comma {
-                        self.writer.write_spanned_raw(comma.span, false, true)?;
-                    } else {
-                        write!(self.writer, ", ")?;
-                    }
-                }
-            }
-        }
-
-        if multiline {
-            self.writer.dedent();
-        }
+        self.visit_comma_separated(items.as_slice(), multiline, false, Self::visit_expr)?;
 
         self.writer
             .write_spanned_raw(items.close.span, false, false)?;
@@ -793,32 +772,14 @@ impl<'a> Printer<'a> {
             false
         };
 
-        let count = assignments.len();
-        for (idx, (assignment, comma)) in assignments.iter().enumerate() {
-            self.visit_object_assignment(assignment)?;
-
-            if multiline {
-                if let Some(comma) = comma {
-                    self.writer.write_spanned_raw(comma.span, true, false)?;
-                } else {
-                    self.writer.write_unspanned(",\n")?;
-                }
-            } else {
-                let is_last = count == idx + 1;
-                if !is_last {
-                    if let Some(comma) = comma {
-                        self.writer.write_spanned_raw(comma.span, false, true)?;
-                    } else {
-                        write!(self.writer, ", ")?;
-                    }
-                }
-            }
-        }
+        self.visit_comma_separated(
+            assignments.as_slice(),
+            multiline,
+            false,
+            Self::visit_object_assignment,
+        )?;
 
-        if multiline {
-            self.writer.dedent();
-            self.writer.newline()?;
-        } else if has_items {
+        if !multiline && has_items {
             self.writer.write_unspanned(" ")?;
         }
 
@@ -1014,6 +975,54 @@ impl<'a> Printer<'a> {
         Ok(())
     }
 
+    /// Visit the items of a comma separated list, such as function arguments
+    /// or the elements of a vector.
+    ///
+    /// Multiline lists get a trailing comma after every item, inline lists
+    /// only separate their items. If `keep_single` is set, an inline list with
+    /// a single item keeps its trailing comma, like the tuple `(a,)`.
+    fn visit_comma_separated<T>(
+        &mut self,
+        items: &[(T, Option<Comma>)],
+        multiline: bool,
+        keep_single: bool,
+        mut visit: impl FnMut(&mut Self, &T) -> Result<()>,
+    ) -> Result<()> {
+        let count = items.len();
+
+        for (idx, (item, comma)) in items.iter().enumerate() {
+            visit(self, item)?;
+
+            if multiline {
+                if let Some(comma) = comma {
+                    self.writer.write_spanned_raw(comma.span, true, false)?;
+                } else {
+                    self.writer.write_unspanned(",\n")?;
+                }
+            } else if keep_single && count == 1 {
+                if let Some(comma) = comma {
+                    self.writer.write_spanned_raw(comma.span, false, false)?;
+                } else {
+                    self.writer.write_unspanned(",")?;
+                }
+            } else if idx + 1 != count {
+                if let Some(comma) = comma {
+                    self.writer.write_spanned_raw(comma.span, false, true)?;
+                } else {
+                    write!(self.writer, ", ")?;
+                }
+            }
+        }
+
+        if multiline {
+            // Every item is followed by a comma and a newline, so the closing
+            // delimiter already starts on its own line.
+            self.writer.dedent();
+        }
+
+        Ok(())
+    }
+
     fn visit_tuple(&mut self, tuple: &ExprTuple) -> Result<()> {
         let ExprTuple { attributes, items } = tuple;
 
@@ -1033,30 +1042,7 @@ impl<'a> Printer<'a> {
             false
         };
 
-        let count = items.len();
-        for (idx, (item, comma)) in items.iter().enumerate() {
-            self.visit_expr(item)?;
-            if multiline {
-                if let Some(comma) = comma {
-                    self.writer.write_spanned_raw(comma.span, true, false)?;
-                } else {
-                    self.writer.write_unspanned(",\n")?;
-                }
-            } else {
-                let is_last = idx == count - 1;
-                if !is_last {
-                    if let Some(comma) = comma {
-                        self.writer.write_spanned_raw(comma.span, false, true)?;
-                    } else {
-                        write!(self.writer, ", ")?;
-                    }
-                }
-            }
-        }
-
-        if multiline {
-            self.writer.dedent();
-        }
+        self.visit_comma_separated(items.as_slice(), multiline, true, Self::visit_expr)?;
 
         self.writer
             .write_spanned_raw(items.close.span, false, false)?;
@@ -1442,6 +1428,7 @@ impl<'a> Printer<'a> {
             Pat::PatObject(patobject) => self.visit_pat_object(patobject)?,
             Pat::PatBinding(binding) => self.visit_pat_binding(binding)?,
             Pat::PatRest(rest) => self.visit_pat_rest(rest)?,
+            Pat::PatRange(range) => self.visit_pat_range(range)?,
         }
 
         Ok(())
@@ -1625,6 +1612,30 @@ impl<'a> Printer<'a> {
         Ok(())
     }
 
+    fn visit_pat_range(&mut self, range: &PatRange) -> Result<()> {
+        let PatRange {
+            attributes,
+            start,
+            limits,
+            end,
+        } = range;
+
+        for attribute in attributes {
+            self.visit_attribute(attribute)?;
+        }
+
+        self.visit_expr(start)?;
+
+        match limits {
+            ast::ExprRangeLimits::HalfOpen(_) => write!(self.writer, "..")?,
+            ast::ExprRangeLimits::Closed(_) => write!(self.writer, "..=")?,
+        }
+
+        self.visit_expr(end)?;
+
+        Ok(())
+    }
+
     fn visit_pat_ignore(&mut self, ignore: &PatIgnore) -> Result<()> {
         let PatIgnore {
             attributes,
@@ -1899,7 +1910,10 @@ impl<'a> Printer<'a> {
 
         self.visit_path_segment(first)?;
         for (cc, segment) in rest {
-            self.writer.write_spanned_raw(cc.span, false, false)?;
+            if let Some(cc) = cc {
+                self.writer.write_spanned_raw(cc.span, false, false)?;
+            }
+
             self.visit_path_segment(segment)?;
         }
 
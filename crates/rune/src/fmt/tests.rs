@@ -1,6 +1,26 @@
 use crate::no_std::prelude::*;
 
-use super::layout_string;
+use crate::ast::{self, Spanned};
+use crate::parse::{Parse, Parser};
+use crate::{Source, SourceId};
+
+use super::{apply_edits, format_file, layout_string, FormattingError};
+
+/// Format the input, check it against the expected output and that formatting
+/// the output again doesn't change it.
+#[track_caller]
+fn assert_format(input: &str, expected: &str) {
+    let format = |input: &str| {
+        let source = Source::memory(input);
+        let mut parser = Parser::new(source.as_str(), SourceId::new(0), true);
+        let file = ast::File::parse(&mut parser).unwrap();
+        format_file(&file, &source).unwrap()
+    };
+
+    let output = format(input);
+    assert_eq!(output, expected);
+    assert_eq!(format(&output), expected, "formatting is not idempotent");
+}
 
 #[test]
 fn test_layout_string() {
@@ -86,3 +106,192 @@ fn foo() {
         expected.as_bytes()
     );
 }
+
+#[test]
+fn test_format_operators() {
+    let input = r#"fn main(){let a=1+2*3;let b=a-1;if a>b&&b!=0{a}else{b}}"#;
+
+    let expected = r#"fn main() {
+    let a = 1 + 2 * 3;
+    let b = a - 1;
+    if a > b && b != 0 {
+        a
+    } else {
+        b
+    }
+}
+"#;
+
+    assert_format(input, expected);
+}
+
+#[test]
+fn test_format_trailing_commas() {
+    let input =
+        r#"fn foo(a,b,){let v=[1,2,3,];let o=#{"a":1,"b":2,};let t=(1,);let u=(a,b,);[v,o,t,u]}"#;
+
+    let expected = r#"fn foo(a, b) {
+    let v = [1, 2, 3];
+    let o = #{ "a": 1, "b": 2 };
+    let t = (1,);
+    let u = (a, b);
+    [v, o, t, u]
+}
+"#;
+
+    assert_format(input, expected);
+}
+
+#[test]
+fn test_format_multiline_literals() {
+    let input = r#"
+        fn foo(a,b,c,d,e,f){[1,2,3,4,5,6,7,8,9,10,11]}
+
+        fn bar(){let o=#{a:1,b:2,c:3,d:4,e:5,f:6};(o,o,o,o,o)}
+        "#;
+
+    let expected = r#"fn foo(
+    a,
+    b,
+    c,
+    d,
+    e,
+    f,
+) {
+    [
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+    ]
+}
+
+fn bar() {
+    let o = #{
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+        f: 6,
+    };
+    (
+        o,
+        o,
+        o,
+        o,
+        o,
+    )
+}
+"#;
+
+    assert_format(input, expected);
+}
+
+#[test]
+fn test_format_multiline_trailing_commas() {
+    // Items which already have trailing commas are not followed by an extra
+    // empty line before the closing delimiter.
+    let input = r#"
+        fn foo(a,b,c,d,e,f,){[1,2,3,4,5,6,7,8,9,10,11,]}
+
+        fn bar(){#{a:1,b:2,c:3,d:4,e:5,f:6,}}
+        "#;
+
+    let expected = r#"fn foo(
+    a,
+    b,
+    c,
+    d,
+    e,
+    f,
+) {
+    [
+        1,
+        2,
+        3,
+        4,
+        5,
+        6,
+        7,
+        8,
+        9,
+        10,
+        11,
+    ]
+}
+
+fn bar() {
+    #{
+        a: 1,
+        b: 2,
+        c: 3,
+        d: 4,
+        e: 5,
+        f: 6,
+    }
+}
+"#;
+
+    assert_format(input, expected);
+}
+
+#[test]
+fn test_apply_edits() {
+    let input = r#"
+// The function being renamed.
+fn foo(n) {
+    n + 1
+}
+
+fn main() {
+    /* call it */ foo(41)
+}
+"#;
+
+    let source = Source::memory(input);
+    let mut parser = Parser::new(source.as_str(), SourceId::new(0), true);
+    let file = ast::File::parse(&mut parser).unwrap();
+
+    let mut edits = Vec::new();
+
+    for (item, _) in &file.items {
+        let ast::Item::Fn(item) = item else {
+            continue;
+        };
+
+        if source.get(item.name.span().range()) == Some("foo") {
+            edits.push((item.name.span(), "bar"));
+        }
+
+        for stmt in &item.body.statements {
+            if let ast::Stmt::Expr(ast::Expr::Call(call)) = stmt {
+                edits.push((call.expr.span(), "bar"));
+            }
+        }
+    }
+
+    let expected = r#"
+// The function being renamed.
+fn bar(n) {
+    n + 1
+}
+
+fn main() {
+    /* call it */ bar(41)
+}
+"#;
+
+    assert_eq!(apply_edits(&source, edits).unwrap(), expected);
+
+    let name = file.items[0].0.span();
+    let result = apply_edits(&source, [(name, "a"), (name, "b")]);
+    assert!(matches!(result, Err(FormattingError::OverlappingEdits(..))));
+}
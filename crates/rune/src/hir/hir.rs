@@ -55,6 +55,8 @@ pub enum PatKind<'hir> {
     PatObject(&'hir PatItems<'hir>),
     /// A binding `a: pattern` or `"foo": pattern`.
     PatBinding(&'hir PatBinding<'hir>),
+    /// A range pattern `'a'..='z'` or `0..10`.
+    PatRange(&'hir PatRange<'hir>),
 }
 
 /// A tuple pattern.
@@ -81,6 +83,18 @@ pub struct PatBinding<'hir> {
     pub pat: &'hir Pat<'hir>,
 }
 
+/// A range pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct PatRange<'hir> {
+    /// Start of the range.
+    pub start: &'hir Expr<'hir>,
+    /// The range limits.
+    pub limits: ExprRangeLimits,
+    /// End of the range.
+    pub end: &'hir Expr<'hir>,
+}
+
 /// An expression.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Spanned)]
 #[non_exhaustive]
@@ -401,6 +401,14 @@ fn pat<'hir>(ctx: &Ctx<'hir, '_>, ast: &ast::Pat) -> compile::Result<hir::Pat<'h
                     pat: alloc!(ctx, ast; pat(ctx, &ast.pat)?),
                 }))
             }
+            ast::Pat::PatRange(ast) => hir::PatKind::PatRange(alloc!(ctx, ast; hir::PatRange {
+                start: alloc!(ctx, ast; expr(ctx, &ast.start)?),
+                limits: match ast.limits {
+                    ast::ExprRangeLimits::HalfOpen(_) => hir::ExprRangeLimits::HalfOpen,
+                    ast::ExprRangeLimits::Closed(_) => hir::ExprRangeLimits::Closed,
+                },
+                end: alloc!(ctx, ast; expr(ctx, &ast.end)?),
+            })),
         },
     })
 }
@@ -11,8 +11,8 @@ use crate::ast::{self};
 use crate::ast::{OptionSpanned, Span, Spanned};
 use crate::compile::attrs::Attributes;
 use crate::compile::{
-    self, attrs, ir, CompileErrorKind, Doc, ItemId, Location, ModId, Options, ParseErrorKind,
-    SourceLoader, Visibility, WithSpan,
+    self, attrs, ir, CompileErrorKind, Doc, ItemBuf, ItemId, Location, ModId, Options,
+    ParseErrorKind, SourceLoader, Visibility, WithSpan,
 };
 use crate::indexing::locals;
 use crate::indexing::{self, Indexed};
@@ -408,7 +408,7 @@ impl<'a> Indexer<'a> {
         while let Some((item, semi)) = queue.pop_front() {
             match item {
                 ast::Item::Use(item_use) => {
-                    let visibility = ast_to_visibility(&item_use.visibility)?;
+                    let visibility = ast_to_visibility(self, &item_use.visibility)?;
 
                     let import = Import {
                         kind: ImportKind::Global,
@@ -460,7 +460,7 @@ impl<'a> Indexer<'a> {
         while let Some(stmt) = queue.pop_front() {
             match stmt {
                 ast::Stmt::Item(ast::Item::Use(item_use), _) => {
-                    let visibility = ast_to_visibility(&item_use.visibility)?;
+                    let visibility = ast_to_visibility(self, &item_use.visibility)?;
 
                     let import = Import {
                         kind: ImportKind::Global,
@@ -543,6 +543,14 @@ impl<'a> Indexer<'a> {
         docs: &[Doc],
     ) -> compile::Result<()> {
         let span = item_mod.span();
+
+        if !self.options.file_modules {
+            return Err(compile::Error::new(
+                span,
+                CompileErrorKind::FileModulesDisabled,
+            ));
+        }
+
         let name = item_mod.name.resolve(resolve_context!(self.q))?;
         let _guard = self.items.push_name(name.as_ref());
 
@@ -556,7 +564,7 @@ impl<'a> Indexer<'a> {
             }
         };
 
-        let visibility = ast_to_visibility(&item_mod.visibility)?;
+        let visibility = ast_to_visibility(self, &item_mod.visibility)?;
 
         let mod_item = self.q.insert_mod(
             &self.items,
@@ -644,7 +652,7 @@ fn item_fn(ast: &mut ast::ItemFn, idx: &mut Indexer<'_>) -> compile::Result<()>
     let name = ast.name.resolve(resolve_context!(idx.q))?;
     let _guard = idx.items.push_name(name.as_ref());
 
-    let visibility = ast_to_visibility(&ast.visibility)?;
+    let visibility = ast_to_visibility(idx, &ast.visibility)?;
     let mut attributes = attrs::Attributes::new(ast.attributes.clone());
     let docs = Doc::collect_from(resolve_context!(idx.q), &mut attributes)?;
 
@@ -998,6 +1006,7 @@ fn pat(ast: &mut ast::Pat, idx: &mut Indexer<'_>, is_used: IsUsed) -> compile::R
         }
         ast::Pat::PatIgnore(..) => (),
         ast::Pat::PatLit(..) => (),
+        ast::Pat::PatRange(..) => (),
         ast::Pat::PatRest(..) => (),
     }
 
@@ -1254,7 +1263,7 @@ fn item_enum(ast: &mut ast::ItemEnum, idx: &mut Indexer<'_>) -> compile::Result<
     let name = ast.name.resolve(resolve_context!(idx.q))?;
     let _guard = idx.items.push_name(name.as_ref());
 
-    let visibility = ast_to_visibility(&ast.visibility)?;
+    let visibility = ast_to_visibility(idx, &ast.visibility)?;
     let enum_item = idx.q.insert_new_item(
         &idx.items,
         Location::new(idx.source_id, span),
@@ -1338,7 +1347,7 @@ fn item_struct(ast: &mut ast::ItemStruct, idx: &mut Indexer<'_>) -> compile::Res
     let ident = ast.ident.resolve(resolve_context!(idx.q))?;
     let _guard = idx.items.push_name(ident);
 
-    let visibility = ast_to_visibility(&ast.visibility)?;
+    let visibility = ast_to_visibility(idx, &ast.visibility)?;
     let item_meta = idx.q.insert_new_item(
         &idx.items,
         Location::new(idx.source_id, span),
@@ -1411,8 +1420,11 @@ fn item_impl(ast: &mut ast::ItemImpl, idx: &mut Indexer<'_>) -> compile::Result<
     let new = idx.q.pool.alloc_item(&*idx.items.item());
     let old = replace(&mut idx.impl_item, Some(new));
 
-    for i in &mut ast.functions {
-        item_fn(i, idx)?;
+    for item in &mut ast.items {
+        match item {
+            ast::ImplItem::Const(i, _) => item_const(i, idx)?,
+            ast::ImplItem::Fn(i) => item_fn(i, idx)?,
+        }
     }
 
     idx.impl_item = old;
@@ -1441,7 +1453,7 @@ fn item_mod(ast: &mut ast::ItemMod, idx: &mut Indexer<'_>) -> compile::Result<()
             let name = ast.name.resolve(resolve_context!(idx.q))?;
             let _guard = idx.items.push_name(name.as_ref());
 
-            let visibility = ast_to_visibility(&ast.visibility)?;
+            let visibility = ast_to_visibility(idx, &ast.visibility)?;
             let mod_item = idx.q.insert_mod(
                 &idx.items,
                 Location::new(idx.source_id, name_span),
@@ -1481,7 +1493,7 @@ fn item_const(ast: &mut ast::ItemConst, idx: &mut Indexer<'_>) -> compile::Resul
         &idx.items,
         Location::new(idx.source_id, span),
         idx.mod_item,
-        ast_to_visibility(&ast.visibility)?,
+        ast_to_visibility(idx, &ast.visibility)?,
         &docs,
     )?;
 
@@ -1869,18 +1881,76 @@ fn expr_range(ast: &mut ast::ExprRange, idx: &mut Indexer<'_>) -> compile::Resul
 }
 
 /// Construct visibility from ast.
-fn ast_to_visibility(vis: &ast::Visibility) -> compile::Result<Visibility> {
-    let span = match vis {
+fn ast_to_visibility(idx: &Indexer<'_>, vis: &ast::Visibility) -> compile::Result<Visibility> {
+    let restrict = match vis {
         ast::Visibility::Inherited => return Ok(Visibility::Inherited),
         ast::Visibility::Public(..) => return Ok(Visibility::Public),
         ast::Visibility::Crate(..) => return Ok(Visibility::Crate),
         ast::Visibility::Super(..) => return Ok(Visibility::Super),
         ast::Visibility::SelfValue(..) => return Ok(Visibility::SelfValue),
-        ast::Visibility::In(restrict) => restrict.span(),
+        ast::Visibility::In(restrict) => restrict,
     };
 
-    Err(compile::Error::new(
-        span,
-        CompileErrorKind::UnsupportedVisibility,
-    ))
+    let path = &restrict.restriction.path;
+    let module = idx.q.pool.module_item(idx.mod_item);
+
+    // Paths which don't start with `self` or `super` are relative to the root
+    // of the crate.
+    let mut restriction = match &path.first {
+        ast::PathSegment::SelfValue(..) | ast::PathSegment::Super(..) if path.global.is_none() => {
+            module.to_owned()
+        }
+        _ => ItemBuf::new(),
+    };
+
+    let segments = [&path.first]
+        .into_iter()
+        .chain(path.rest.iter().map(|(_, segment)| segment));
+
+    for (n, segment) in segments.enumerate() {
+        match segment {
+            ast::PathSegment::Ident(ident) => {
+                let ident = ident.resolve(resolve_context!(idx.q))?;
+                restriction.push(ident);
+            }
+            ast::PathSegment::Super(..) => {
+                if restriction.pop().is_none() {
+                    return Err(compile::Error::new(
+                        segment,
+                        CompileErrorKind::UnsupportedSuper,
+                    ));
+                }
+            }
+            ast::PathSegment::Crate(..) | ast::PathSegment::SelfValue(..) if n == 0 => {}
+            ast::PathSegment::Crate(..) | ast::PathSegment::SelfValue(..) => {
+                return Err(compile::Error::new(
+                    segment,
+                    CompileErrorKind::ExpectedLeadingPathSegment,
+                ));
+            }
+            _ => {
+                return Err(compile::Error::new(
+                    segment,
+                    CompileErrorKind::UnsupportedVisibility,
+                ));
+            }
+        }
+    }
+
+    if restriction.is_empty() {
+        return Ok(Visibility::Crate);
+    }
+
+    if !module.starts_with(&restriction) {
+        return Err(compile::Error::new(
+            path,
+            CompileErrorKind::VisibilityRestrictionNotAncestor {
+                restriction,
+                module: module.to_owned(),
+            },
+        ));
+    }
+
+    let depth = module.iter().count() - restriction.iter().count();
+    Ok(Visibility::In(depth))
 }
@@ -26,6 +26,7 @@ pub(crate) fn pat(ast: &mut ast::Pat, idx: &mut Indexer<'_>) -> compile::Result<
         }
         ast::Pat::PatIgnore(..) => (),
         ast::Pat::PatLit(..) => (),
+        ast::Pat::PatRange(..) => (),
         ast::Pat::PatRest(..) => (),
     }
 
@@ -32,9 +32,6 @@ impl MacroCompiler<'_> {
             ));
         }
 
-        // TODO: include information on the module the macro is being called
-        // from.
-        //
         // TODO: Figure out how to avoid performing ad-hoc lowering here.
         let arena = crate::hir::Arena::new();
         let ctx = crate::hir::lowering::Ctx::new(&arena, self.query.borrow());
@@ -2,11 +2,13 @@
 
 use core::fmt;
 
+use crate::no_std::prelude::*;
+
 use crate::ast;
 use crate::ast::Span;
 use crate::compile::{
-    self, IrCompiler, IrEval, IrEvalContext, IrValue, ItemMeta, NoopCompileVisitor, ParseErrorKind,
-    Pool, Prelude, UnitBuilder,
+    self, IrCompiler, IrEval, IrEvalContext, IrValue, Item, ItemMeta, NoopCompileVisitor, Options,
+    ParseErrorKind, Pool, Prelude, UnitBuilder,
 };
 use crate::macros::{IntoLit, Storage, ToTokens, TokenStream};
 use crate::parse::{Parse, Resolve};
@@ -43,6 +45,7 @@ impl<'a> MacroContext<'a> {
     {
         let mut unit = UnitBuilder::default();
         let prelude = Prelude::default();
+        let options = Options::default();
         let gen = Gen::default();
         let mut consts = Consts::default();
         let mut storage = Storage::default();
@@ -54,6 +57,7 @@ impl<'a> MacroContext<'a> {
         let mut query = Query::new(
             &mut unit,
             &prelude,
+            &options,
             &mut consts,
             &mut storage,
             &mut sources,
@@ -227,6 +231,26 @@ impl<'a> MacroContext<'a> {
         crate::parse::parse_all(source.as_str(), id, false)
     }
 
+    /// The item of the definition the macro is being expanded within.
+    ///
+    /// If the macro is called inside of the body of `fn bar` in `mod foo`, this
+    /// would be `foo::bar::$0` where `$0` is the anonymous item of the function
+    /// body.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::compile::ItemBuf;
+    /// use rune::macros::MacroContext;
+    ///
+    /// MacroContext::test(|ctx| {
+    ///     assert_eq!(ctx.item(), ItemBuf::new());
+    /// });
+    /// ```
+    pub fn item(&self) -> &Item {
+        self.q.pool.item(self.item_meta.item)
+    }
+
     /// The span of the macro call including the name of the macro.
     ///
     /// If the macro call was `stringify!(a + b)` this would refer to the whole
@@ -241,6 +265,20 @@ impl<'a> MacroContext<'a> {
     pub fn stream_span(&self) -> Span {
         self.stream_span
     }
+
+    /// Describe where the macro is being called from as
+    /// `<source>:<line>:<column>`, where both line and column are 1-indexed
+    /// since that is what most editors use.
+    pub(crate) fn describe_location(&self) -> String {
+        let source_id = self.item_meta.location.source_id;
+
+        let Some(source) = self.q.sources.get(source_id) else {
+            return String::from("<unknown>");
+        };
+
+        let (line, col) = source.pos_to_utf8_linecol(self.macro_span.start.into_usize());
+        format!("{}:{}:{}", source.name(), line + 1, col + 1)
+    }
 }
 
 pub struct Stringify<'ctx, 'a> {
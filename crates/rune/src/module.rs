@@ -94,10 +94,17 @@ impl InternalEnum {
             fields: Some(Fields::Unnamed(C::args())),
             constructor: Some(constructor),
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         });
 
         let v = self.variants.last_mut().unwrap();
-        ItemMut { docs: &mut v.docs }
+
+        ItemMut {
+            docs: &mut v.docs,
+            deprecated: &mut v.deprecated,
+            unstable: &mut v.unstable,
+        }
     }
 }
 
@@ -117,6 +124,10 @@ pub(crate) struct ModuleType {
     pub(crate) spec: Option<TypeSpecification>,
     /// Documentation for the type.
     pub(crate) docs: Docs,
+    /// Deprecation notice of the type.
+    pub(crate) deprecated: Option<&'static str>,
+    /// If the type is marked as unstable.
+    pub(crate) unstable: bool,
 }
 
 /// The kind of the variant.
@@ -142,6 +153,10 @@ pub struct Variant {
     pub(crate) constructor: Option<Arc<FunctionHandler>>,
     /// Variant documentation.
     pub(crate) docs: Docs,
+    /// Deprecation notice of the variant.
+    pub(crate) deprecated: Option<&'static str>,
+    /// If the variant is marked as unstable.
+    pub(crate) unstable: bool,
 }
 
 impl Variant {
@@ -152,6 +167,8 @@ impl Variant {
             fields: None,
             constructor: None,
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         }
     }
 }
@@ -196,13 +213,14 @@ pub(crate) struct ModuleFunction {
     pub(crate) handler: Arc<FunctionHandler>,
     #[cfg(feature = "doc")]
     pub(crate) is_async: bool,
-    #[cfg(feature = "doc")]
     pub(crate) args: Option<usize>,
     #[cfg(feature = "doc")]
     pub(crate) return_type: Option<FullTypeOf>,
     #[cfg(feature = "doc")]
     pub(crate) argument_types: Box<[Option<FullTypeOf>]>,
     pub(crate) docs: Docs,
+    pub(crate) deprecated: Option<&'static str>,
+    pub(crate) unstable: bool,
 }
 
 #[derive(Clone)]
@@ -213,13 +231,14 @@ pub(crate) struct ModuleAssociated {
     pub(crate) handler: Arc<FunctionHandler>,
     #[cfg(feature = "doc")]
     pub(crate) is_async: bool,
-    #[cfg(feature = "doc")]
     pub(crate) args: Option<usize>,
     #[cfg(feature = "doc")]
     pub(crate) return_type: Option<FullTypeOf>,
     #[cfg(feature = "doc")]
     pub(crate) argument_types: Box<[Option<FullTypeOf>]>,
     pub(crate) docs: Docs,
+    pub(crate) deprecated: Option<&'static str>,
+    pub(crate) unstable: bool,
 }
 
 /// Handle to a macro inserted into a module.
@@ -227,6 +246,8 @@ pub(crate) struct ModuleMacro {
     pub(crate) item: ItemBuf,
     pub(crate) handler: Arc<MacroHandler>,
     pub(crate) docs: Docs,
+    pub(crate) deprecated: Option<&'static str>,
+    pub(crate) unstable: bool,
 }
 
 /// A constant registered in a module.
@@ -234,6 +255,8 @@ pub(crate) struct ModuleConstant {
     pub(crate) item: ItemBuf,
     pub(crate) value: ConstValue,
     pub(crate) docs: Docs,
+    pub(crate) deprecated: Option<&'static str>,
+    pub(crate) unstable: bool,
 }
 
 /// Handle to a an item inserted into a module which allows for mutation of item
@@ -241,6 +264,7 @@ pub(crate) struct ModuleConstant {
 ///
 /// This is returned by methods which insert meta items, such as:
 /// * [`Module::raw_fn`].
+/// * [`Module::raw_value_fn`].
 /// * [`Module::function`].
 /// * [`Module::associated_function`].
 ///
@@ -251,6 +275,8 @@ pub(crate) struct ModuleConstant {
 /// * [`Module::function_meta`].
 pub struct ItemMut<'a> {
     docs: &'a mut Docs,
+    deprecated: &'a mut Option<&'static str>,
+    unstable: &'a mut bool,
 }
 
 impl ItemMut<'_> {
@@ -265,6 +291,56 @@ impl ItemMut<'_> {
         self.docs.set_docs(docs);
         self
     }
+
+    /// Mark the inserted item as deprecated, with the given deprecation
+    /// notice.
+    ///
+    /// Any reference to the item from a compiled script will cause a warning
+    /// which includes the notice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    ///
+    /// fn old_add(a: i64, b: i64) -> i64 {
+    ///     a + b
+    /// }
+    ///
+    /// let mut m = Module::new();
+    ///
+    /// m.function(["old_add"], old_add)?
+    ///     .deprecated("Use the `+` operator instead");
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn deprecated(self, deprecated: &'static str) -> Self {
+        *self.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Mark the inserted item as unstable, meaning that it's experimental and
+    /// might change or be removed without notice.
+    ///
+    /// This is reported in generated documentation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    ///
+    /// fn fast_add(a: i64, b: i64) -> i64 {
+    ///     a + b
+    /// }
+    ///
+    /// let mut m = Module::new();
+    ///
+    /// m.function(["fast_add"], fast_add)?.unstable();
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn unstable(self) -> Self {
+        *self.unstable = true;
+        self
+    }
 }
 
 impl fmt::Debug for ItemMut<'_> {
@@ -274,6 +350,66 @@ impl fmt::Debug for ItemMut<'_> {
     }
 }
 
+/// Handle to several associated functions inserted into a module at once,
+/// which allows for mutation of their shared metadata.
+///
+/// This is returned by [`Module::associated_function_for`].
+pub struct ItemsMut<'a> {
+    associated: &'a mut [ModuleAssociated],
+}
+
+impl ItemsMut<'_> {
+    /// Set documentation for the inserted items.
+    ///
+    /// This completely replaces any existing documentation.
+    pub fn docs<I>(self, docs: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let docs = docs
+            .into_iter()
+            .map(|line| line.as_ref().to_owned())
+            .collect::<Vec<_>>();
+
+        for assoc in self.associated.iter_mut() {
+            assoc.docs.set_docs(&docs);
+        }
+
+        self
+    }
+
+    /// Mark the inserted items as deprecated, with the given deprecation
+    /// notice.
+    ///
+    /// See [`ItemMut::deprecated`].
+    pub fn deprecated(self, deprecated: &'static str) -> Self {
+        for assoc in self.associated.iter_mut() {
+            assoc.deprecated = Some(deprecated);
+        }
+
+        self
+    }
+
+    /// Mark the inserted items as unstable.
+    ///
+    /// See [`ItemMut::unstable`].
+    pub fn unstable(self) -> Self {
+        for assoc in self.associated.iter_mut() {
+            assoc.unstable = true;
+        }
+
+        self
+    }
+}
+
+impl fmt::Debug for ItemsMut<'_> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ItemsMut").finish_non_exhaustive()
+    }
+}
+
 /// Handle to a a variant inserted into a module which allows for mutation of
 /// its metadata.
 pub struct VariantMut<'a, T>
@@ -476,6 +612,8 @@ where
     T: TypeOf,
 {
     docs: &'a mut Docs,
+    deprecated: &'a mut Option<&'static str>,
+    unstable: &'a mut bool,
     spec: &'a mut Option<TypeSpecification>,
     item: &'a Item,
     _marker: PhantomData<&'a mut T>,
@@ -505,6 +643,25 @@ where
         self
     }
 
+    /// Mark the inserted type as deprecated, with the given deprecation
+    /// notice.
+    ///
+    /// Any reference to the type from a compiled script will cause a warning
+    /// which includes the notice.
+    pub fn deprecated(self, deprecated: &'static str) -> Self {
+        *self.deprecated = Some(deprecated);
+        self
+    }
+
+    /// Mark the inserted type as unstable, meaning that it's experimental and
+    /// might change or be removed without notice.
+    ///
+    /// This is reported in generated documentation.
+    pub fn unstable(self) -> Self {
+        *self.unstable = true;
+        self
+    }
+
     /// Mark the current type as a struct with named fields.
     pub fn make_named_struct(self, fields: &'static [&'static str]) -> Result<Self, ContextError> {
         self.make_struct(Fields::Named(fields))
@@ -52,7 +52,6 @@ pub struct FunctionData {
     pub(crate) handler: Arc<FunctionHandler>,
     #[cfg(feature = "doc")]
     pub(crate) is_async: bool,
-    #[cfg(feature = "doc")]
     pub(crate) args: Option<usize>,
     #[cfg(feature = "doc")]
     pub(crate) return_type: Option<FullTypeOf>,
@@ -71,12 +70,14 @@ impl FunctionData {
         A: FunctionArgs,
         K: FunctionKind,
     {
+        let item = ItemBuf::with_item(name);
+        let function: Box<str> = item.to_string().into();
+
         Self {
-            item: ItemBuf::with_item(name),
-            handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
+            item,
+            handler: Arc::new(move |stack, args| f.fn_call(stack, args).with_function(&function)),
             #[cfg(feature = "doc")]
             is_async: K::is_async(),
-            #[cfg(feature = "doc")]
             args: Some(F::args()),
             #[cfg(feature = "doc")]
             return_type: F::Return::maybe_type_of(),
@@ -181,7 +182,6 @@ pub struct AssociatedFunctionData {
     pub(crate) container_type_info: TypeInfo,
     #[cfg(feature = "doc")]
     pub(crate) is_async: bool,
-    #[cfg(feature = "doc")]
     pub(crate) args: Option<usize>,
     #[cfg(feature = "doc")]
     pub(crate) return_type: Option<FullTypeOf>,
@@ -198,14 +198,16 @@ impl AssociatedFunctionData {
         A: FunctionArgs,
         K: FunctionKind,
     {
+        let container_type_info = F::Instance::type_info();
+        let function: Box<str> = format!("{}::{}", container_type_info, name.kind).into();
+
         Self {
             name,
-            handler: Arc::new(move |stack, args| f.fn_call(stack, args)),
+            handler: Arc::new(move |stack, args| f.fn_call(stack, args).with_function(&function)),
             container: F::Instance::type_of(),
-            container_type_info: F::Instance::type_info(),
+            container_type_info,
             #[cfg(feature = "doc")]
             is_async: K::is_async(),
-            #[cfg(feature = "doc")]
             args: Some(F::args()),
             #[cfg(feature = "doc")]
             return_type: F::Return::maybe_type_of(),
@@ -214,6 +216,42 @@ impl AssociatedFunctionData {
         }
     }
 
+    /// Construct one associated function for each of the given containers,
+    /// all sharing the same handler. The first argument of the function
+    /// receives the instance.
+    pub(crate) fn for_containers<F, A, K>(
+        name: AssociatedFunctionName,
+        containers: &[(FullTypeOf, TypeInfo)],
+        f: F,
+    ) -> Vec<Self>
+    where
+        F: Function<A, K>,
+        F::Return: MaybeTypeOf,
+        A: FunctionArgs,
+        K: FunctionKind,
+    {
+        let function: Box<str> = name.kind.to_string().into();
+        let handler: Arc<FunctionHandler> =
+            Arc::new(move |stack, args| f.fn_call(stack, args).with_function(&function));
+
+        containers
+            .iter()
+            .map(|(container, container_type_info)| Self {
+                name: name.clone(),
+                handler: handler.clone(),
+                container: container.clone(),
+                container_type_info: container_type_info.clone(),
+                #[cfg(feature = "doc")]
+                is_async: K::is_async(),
+                args: Some(F::args()),
+                #[cfg(feature = "doc")]
+                return_type: F::Return::maybe_type_of(),
+                #[cfg(feature = "doc")]
+                argument_types: A::into_box(),
+            })
+            .collect()
+    }
+
     /// Get associated key.
     pub(crate) fn assoc_key(&self) -> AssociatedKey {
         AssociatedKey {
@@ -299,14 +337,19 @@ where
         N: ToInstance,
         T: TypeOf + Named,
     {
+        let name = self.name.to_instance();
+        let container_type_info = T::type_info();
+        let function: Box<str> = format!("{}::{}", container_type_info, name.kind).into();
+
         FunctionMetaKind::AssociatedFunction(AssociatedFunctionData {
-            name: self.name.to_instance(),
-            handler: Arc::new(move |stack, args| self.f.fn_call(stack, args)),
+            name,
+            handler: Arc::new(move |stack, args| {
+                self.f.fn_call(stack, args).with_function(&function)
+            }),
             container: T::type_of(),
-            container_type_info: T::type_info(),
+            container_type_info,
             #[cfg(feature = "doc")]
             is_async: K::is_async(),
-            #[cfg(feature = "doc")]
             args: Some(F::args()),
             #[cfg(feature = "doc")]
             return_type: F::Return::maybe_type_of(),
@@ -398,3 +441,30 @@ macro_rules! iter_function_args {
 }
 
 repeat_macro!(iter_function_args);
+
+/// Trait implemented for tuples of types, allowing a function to be registered
+/// for each of them through [`Module::associated_function_for`].
+///
+/// [`Module::associated_function_for`]: crate::Module::associated_function_for
+#[doc(hidden)]
+pub trait FunctionContainers {
+    #[doc(hidden)]
+    fn containers() -> Vec<(FullTypeOf, TypeInfo)>;
+}
+
+macro_rules! iter_function_containers {
+    ($count:expr $(, $ty:ident $var:ident $num:expr)*) => {
+        impl<$($ty,)*> FunctionContainers for ($($ty,)*)
+        where
+            $($ty: TypeOf,)*
+        {
+            #[inline]
+            #[doc(hidden)]
+            fn containers() -> Vec<(FullTypeOf, TypeInfo)> {
+                vec![$((<$ty>::type_of(), <$ty>::type_info()),)*]
+            }
+        }
+    }
+}
+
+repeat_macro!(iter_function_containers);
@@ -4,20 +4,22 @@ use crate::no_std::collections::{HashMap, HashSet};
 use crate::no_std::prelude::*;
 use crate::no_std::sync::Arc;
 
-use crate::compile::{self, meta, ContextError, Docs, IntoComponent, ItemBuf, Named};
+use crate::compile::{self, ContextError, Docs, IntoComponent, ItemBuf, Named};
+use crate::hash::ToTypeHash;
 use crate::macros::{MacroContext, TokenStream};
 use crate::module::function_meta::{
-    AssociatedFunctionData, AssociatedFunctionName, FunctionArgs, FunctionData, FunctionMeta,
-    FunctionMetaKind, MacroMeta, MacroMetaKind, ToFieldFunction, ToInstance,
+    AssociatedFunctionData, AssociatedFunctionName, FunctionArgs, FunctionContainers, FunctionData,
+    FunctionMeta, FunctionMetaKind, MacroMeta, MacroMetaKind, ToFieldFunction, ToInstance,
 };
 use crate::module::{
     AssociatedKey, Async, EnumMut, Function, FunctionKind, InstallWith, InstanceFunction,
-    InternalEnum, InternalEnumMut, ItemMut, ModuleAssociated, ModuleConstant, ModuleFunction,
-    ModuleMacro, ModuleType, Plain, TypeMut, TypeSpecification, UnitType, VariantMut,
+    InternalEnum, InternalEnumMut, ItemMut, ItemsMut, ModuleAssociated, ModuleConstant,
+    ModuleFunction, ModuleMacro, ModuleType, Plain, TypeMut, TypeSpecification, UnitType,
+    VariantMut,
 };
 use crate::runtime::{
-    ConstValue, FromValue, GeneratorState, MacroHandler, MaybeTypeOf, Protocol, Stack, ToValue,
-    TypeCheck, TypeOf, Value, VmResult,
+    ConstValue, FromValue, GeneratorState, MacroHandler, MaybeTypeOf, Protocol, Shared, Stack,
+    ToValue, TypeCheck, TypeOf, Value, VmErrorKind, VmResult,
 };
 use crate::Hash;
 
@@ -61,6 +63,10 @@ pub struct Module {
     pub(crate) internal_enums: Vec<InternalEnum>,
     /// Module level documentation.
     pub(crate) docs: Docs,
+    /// Module level deprecation notice.
+    pub(crate) deprecated: Option<&'static str>,
+    /// If the module is marked as unstable.
+    pub(crate) unstable: bool,
 }
 
 impl Module {
@@ -115,6 +121,8 @@ impl Module {
             internal_enums: Vec::new(),
             constants: Vec::new(),
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         }
     }
 
@@ -122,6 +130,8 @@ impl Module {
     pub fn item_mut(&mut self) -> ItemMut<'_> {
         ItemMut {
             docs: &mut self.docs,
+            deprecated: &mut self.deprecated,
+            unstable: &mut self.unstable,
         }
     }
 
@@ -192,6 +202,8 @@ impl Module {
             type_info,
             spec: None,
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         });
 
         T::install_with(self)?;
@@ -200,6 +212,8 @@ impl Module {
 
         Ok(TypeMut {
             docs: &mut ty.docs,
+            deprecated: &mut ty.deprecated,
+            unstable: &mut ty.unstable,
             spec: &mut ty.spec,
             item: &ty.item,
             _marker: PhantomData,
@@ -222,6 +236,8 @@ impl Module {
 
         Ok(TypeMut {
             docs: &mut ty.docs,
+            deprecated: &mut ty.deprecated,
+            unstable: &mut ty.unstable,
             spec: &mut ty.spec,
             item: &ty.item,
             _marker: PhantomData,
@@ -504,10 +520,16 @@ impl Module {
             item,
             value,
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         });
 
         let c = self.constants.last_mut().unwrap();
-        Ok(ItemMut { docs: &mut c.docs })
+        Ok(ItemMut {
+            docs: &mut c.docs,
+            deprecated: &mut c.deprecated,
+            unstable: &mut c.unstable,
+        })
     }
 
     /// Register a native macro handler through its meta.
@@ -570,12 +592,18 @@ impl Module {
                     item: data.item,
                     handler: data.handler,
                     docs,
+                    deprecated: None,
+                    unstable: false,
                 });
             }
         }
 
         let m = self.macros.last_mut().unwrap();
-        Ok(ItemMut { docs: &mut m.docs })
+        Ok(ItemMut {
+            docs: &mut m.docs,
+            deprecated: &mut m.deprecated,
+            unstable: &mut m.unstable,
+        })
     }
 
     /// Register a native macro handler.
@@ -626,10 +654,16 @@ impl Module {
             item,
             handler,
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         });
 
         let m = self.macros.last_mut().unwrap();
-        Ok(ItemMut { docs: &mut m.docs })
+        Ok(ItemMut {
+            docs: &mut m.docs,
+            deprecated: &mut m.deprecated,
+            unstable: &mut m.unstable,
+        })
     }
 
     /// Register a function handler through its meta.
@@ -792,6 +826,12 @@ impl Module {
     /// If possible, [`Module::function_meta`] should be used since it includes
     /// more useful information about the function.
     ///
+    /// The type the function is associated with doesn't have to be registered
+    /// in this module, which makes it possible to extend types provided by
+    /// other modules. The module which registers the type must then be
+    /// installed into the [`Context`][crate::Context] first, and installing a
+    /// function which conflicts with an existing one is an error.
+    ///
     /// This returns a [`ItemMut`], which is a handle that can be used to associate more metadata
     /// with the inserted item.
     ///
@@ -861,6 +901,49 @@ impl Module {
     ///     .docs(["Download a thing."]);
     /// # Ok::<_, rune::Error>(())
     /// ```
+    ///
+    /// # Stateful functions
+    ///
+    /// Any closure implementing [`Fn`] can be registered, so a function which
+    /// needs to accumulate state across calls can capture that state behind
+    /// interior mutability.
+    ///
+    /// Since a [`Context`][crate::Context] and the [`RuntimeContext`] built
+    /// from it may be shared across threads, registered handlers must be
+    /// `Send + Sync`. Captured state must therefore be protected with a
+    /// thread-safe primitive like [`Mutex`] or an atomic rather than a
+    /// [`RefCell`], and every virtual machine using the same context will
+    /// observe the same state.
+    ///
+    /// [`RuntimeContext`]: crate::runtime::RuntimeContext
+    /// [`Mutex`]: std::sync::Mutex
+    /// [`RefCell`]: core::cell::RefCell
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use rune::{Any, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Logger;
+    ///
+    /// let lines = Arc::new(Mutex::new(Vec::<String>::new()));
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// module.ty::<Logger>()?;
+    ///
+    /// module.associated_function("log", {
+    ///     let lines = lines.clone();
+    ///
+    ///     move |_: &Logger, line: String| {
+    ///         let mut lines = lines.lock().unwrap();
+    ///         lines.push(line);
+    ///         lines.len()
+    ///     }
+    /// })?;
+    /// # Ok::<_, rune::Error>(())
+    /// ```
     pub fn associated_function<N, F, A, K>(
         &mut self,
         name: N,
@@ -879,6 +962,77 @@ impl Module {
         )
     }
 
+    /// Register the same instance function for multiple types.
+    ///
+    /// This is useful for functions which behave the same for several
+    /// concrete types, such as a method which is available on both integers
+    /// and floats. Unlike [`Module::associated_function`] the instance is
+    /// passed in as the first argument of a regular function, typically as a
+    /// [`Value`].
+    ///
+    /// The types are specified as a tuple through the first generic parameter.
+    /// If the function conflicts with an existing function for any of them,
+    /// nothing is registered.
+    ///
+    /// This returns a [`ItemsMut`], which is a handle that can be used to
+    /// associate more metadata with all of the inserted functions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    /// use rune::runtime::Value;
+    ///
+    /// fn is_number(_: Value) -> bool {
+    ///     true
+    /// }
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// module
+    ///     .associated_function_for::<(i64, f64), _, _, _, _>("is_number", is_number)?
+    ///     .docs(["Test if the value is a number."]);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn associated_function_for<T, N, F, A, K>(
+        &mut self,
+        name: N,
+        f: F,
+    ) -> Result<ItemsMut<'_>, ContextError>
+    where
+        T: FunctionContainers,
+        N: ToInstance,
+        F: Function<A, K>,
+        F::Return: MaybeTypeOf,
+        A: FunctionArgs,
+        K: FunctionKind,
+    {
+        let data = AssociatedFunctionData::for_containers(name.to_instance(), &T::containers(), f);
+
+        let mut keys = HashSet::new();
+
+        for data in &data {
+            let key = data.assoc_key();
+
+            if self.names.contains(&Name::Associated(key.clone())) || !keys.insert(key) {
+                return Err(ContextError::conflicting_associated(
+                    &data.name.kind,
+                    data.container_type_info.clone(),
+                ));
+            }
+        }
+
+        let start = self.associated.len();
+
+        for data in data {
+            self.assoc_fn(data, Docs::EMPTY)?;
+        }
+
+        Ok(ItemsMut {
+            associated: &mut self.associated[start..],
+        })
+    }
+
     /// See [`Module::associated_function`].
     #[deprecated = "Use Module::associated_function() instead"]
     #[inline]
@@ -905,10 +1059,109 @@ impl Module {
         self.associated_function(name, f)
     }
 
+    /// Register the type of the instance of the given function as an iterator,
+    /// using the function to advance it.
+    ///
+    /// This installs the [`Protocol::NEXT`] protocol for the type, and the
+    /// [`Protocol::INTO_ITER`] protocol which simply returns the iterator
+    /// itself. Together they allow the type to be iterated over using a `for`
+    /// loop.
+    ///
+    /// This returns a [`ItemMut`] for the installed [`Protocol::NEXT`]
+    /// function, which is a handle that can be used to associate more metadata
+    /// with it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Any, Module};
+    ///
+    /// #[derive(Any)]
+    /// struct Countdown {
+    ///     count: i64,
+    /// }
+    ///
+    /// impl Countdown {
+    ///     fn next(&mut self) -> Option<i64> {
+    ///         if self.count == 0 {
+    ///             return None;
+    ///         }
+    ///
+    ///         self.count -= 1;
+    ///         Some(self.count)
+    ///     }
+    /// }
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// module.ty::<Countdown>()?;
+    /// module.iterator(Countdown::next)?;
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn iterator<F, A>(&mut self, next: F) -> Result<ItemMut<'_>, ContextError>
+    where
+        F: InstanceFunction<A, Plain>,
+        F::Return: MaybeTypeOf,
+        A: FunctionArgs,
+    {
+        // The iterator is already on the stack, so returning it as-is is
+        // a matter of leaving the stack untouched.
+        let into_iter = AssociatedFunctionData {
+            name: Protocol::INTO_ITER.to_instance(),
+            handler: Arc::new(|_, args| {
+                if args != 1 {
+                    return VmResult::err(VmErrorKind::BadArgumentCount {
+                        actual: args,
+                        expected: 1,
+                    });
+                }
+
+                VmResult::Ok(())
+            }),
+            container: F::Instance::type_of(),
+            container_type_info: F::Instance::type_info(),
+            #[cfg(feature = "doc")]
+            is_async: false,
+            args: Some(1),
+            #[cfg(feature = "doc")]
+            return_type: Some(F::Instance::type_of()),
+            #[cfg(feature = "doc")]
+            argument_types: Box::from([Some(F::Instance::type_of())]),
+        };
+
+        self.assoc_fn(into_iter, Docs::EMPTY)?;
+        self.associated_function(Protocol::NEXT, next)
+    }
+
     /// Install a protocol function that interacts with the given field.
     ///
+    /// This can be used to expose computed properties on a type, which are
+    /// accessed like fields through `value.name` using [`Protocol::GET`] and
+    /// assigned through `value.name = ...` using [`Protocol::SET`].
+    ///
     /// This returns a [`ItemMut`], which is a handle that can be used to
     /// associate more metadata with the inserted item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Any, Module};
+    /// use rune::runtime::Protocol;
+    ///
+    /// #[derive(Any)]
+    /// struct Rect {
+    ///     width: i64,
+    ///     height: i64,
+    /// }
+    ///
+    /// let mut m = Module::new();
+    /// m.ty::<Rect>()?;
+    /// m.field_function(Protocol::GET, "area", |r: &Rect| r.width * r.height)?;
+    /// m.field_function(Protocol::SET, "width", |r: &mut Rect, width: i64| {
+    ///     r.width = width;
+    /// })?;
+    /// # Ok::<_, rune::Error>(())
+    /// ```
     pub fn field_function<N, F, A>(
         &mut self,
         protocol: Protocol,
@@ -1031,18 +1284,212 @@ impl Module {
             handler: Arc::new(move |stack, args| f(stack, args)),
             #[cfg(feature = "doc")]
             is_async: false,
-            #[cfg(feature = "doc")]
             args: None,
             #[cfg(feature = "doc")]
             return_type: None,
             #[cfg(feature = "doc")]
             argument_types: Box::from([]),
             docs: Docs::EMPTY,
+            deprecated: None,
+            unstable: false,
         });
 
         let last = self.functions.last_mut().unwrap();
         Ok(ItemMut {
             docs: &mut last.docs,
+            deprecated: &mut last.deprecated,
+            unstable: &mut last.unstable,
+        })
+    }
+
+    /// Register a function which returns a lazily evaluated iterator.
+    ///
+    /// The function is called with the arguments it was passed, and the Rust
+    /// iterator it returns is boxed into a Rune iterator. Values are only
+    /// produced from the iterator as the script advances it, so this can be
+    /// used to expose large or unbounded sequences without collecting them
+    /// first.
+    ///
+    /// This returns a [`ItemMut`], which is a handle that can be used to
+    /// associate more metadata with the inserted item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    /// use rune::runtime::Value;
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// module.function_iter(["countdown"], |args: Vec<Value>| {
+    ///     let from = match args.first() {
+    ///         Some(Value::Integer(from)) => *from,
+    ///         _ => 10,
+    ///     };
+    ///
+    ///     (0..=from).rev()
+    /// })?;
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn function_iter<F, I, N>(&mut self, name: N, f: F) -> Result<ItemMut<'_>, ContextError>
+    where
+        F: 'static + Fn(Vec<Value>) -> I + Send + Sync,
+        I: IntoIterator,
+        I::IntoIter: 'static,
+        I::Item: ToValue,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let item = ItemBuf::with_item(name);
+        let name: Box<str> = item.to_string().into();
+
+        self.raw_fn(&item, move |stack, args| {
+            let args = vm_try!(stack.drain(args)).collect::<Vec<_>>();
+            let iter = f(args).into_iter();
+            let iter = crate::runtime::Iterator::from_named(String::from(&*name).into(), iter);
+            stack.push(Value::Iterator(Shared::new(iter)));
+            VmResult::Ok(())
+        })?;
+
+        let last = self.functions.last_mut().unwrap();
+
+        #[cfg(feature = "doc")]
+        {
+            last.return_type = crate::runtime::Iterator::maybe_type_of();
+        }
+
+        Ok(ItemMut {
+            docs: &mut last.docs,
+            deprecated: &mut last.deprecated,
+            unstable: &mut last.unstable,
+        })
+    }
+
+    /// Register a raw function which receives its arguments directly, and
+    /// returns a value.
+    ///
+    /// Unlike [`Module::raw_fn`], the arguments are popped off the stack
+    /// before the function is called and the returned value is pushed onto
+    /// it afterwards, so the function can't leave the stack unbalanced.
+    ///
+    /// This returns a [`ItemMut`], which is a handle that can be used to
+    /// associate more metadata with the inserted item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    /// use rune::runtime::{Value, VmResult};
+    /// use rune::vm_try;
+    ///
+    /// fn sum(args: &[Value]) -> VmResult<Value> {
+    ///     let mut number = 0;
+    ///
+    ///     for value in args {
+    ///         number += vm_try!(value.clone().into_integer());
+    ///     }
+    ///
+    ///     VmResult::Ok(Value::from(number))
+    /// }
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// let sum = module.raw_value_fn(["sum"], sum)?;
+    /// sum.docs([
+    ///     "Sum all numbers provided to the function."
+    /// ]);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn raw_value_fn<F, N>(&mut self, name: N, f: F) -> Result<ItemMut<'_>, ContextError>
+    where
+        F: 'static + Fn(&[Value]) -> VmResult<Value> + Send + Sync,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        self.raw_fn(name, move |stack, args| {
+            let args = vm_try!(stack.drain(args)).collect::<Vec<_>>();
+            stack.push(vm_try!(f(&args)));
+            VmResult::Ok(())
+        })
+    }
+
+    /// Register an alias for a function which has already been registered in
+    /// this module, so that it can also be called by the given `name`.
+    ///
+    /// The existing function can be identified either by its name or by its
+    /// hash. The documentation of the alias notes which function it is an
+    /// alias of.
+    ///
+    /// This returns a [`ItemMut`], which is a handle that can be used to
+    /// associate more metadata with the inserted item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Module;
+    ///
+    /// fn len(value: &str) -> usize {
+    ///     value.len()
+    /// }
+    ///
+    /// let mut module = Module::default();
+    ///
+    /// module.function(["len"], len)?;
+    /// module.function_alias(["len"], ["length"])?;
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn function_alias<E, N>(
+        &mut self,
+        existing: E,
+        name: N,
+    ) -> Result<ItemMut<'_>, ContextError>
+    where
+        E: ToTypeHash,
+        N: IntoIterator,
+        N::Item: IntoComponent,
+    {
+        let existing = existing.to_type_hash();
+
+        let Some(function) = self
+            .functions
+            .iter()
+            .find(|f| Hash::type_hash(&f.item) == existing)
+        else {
+            return Err(ContextError::MissingFunction { hash: existing });
+        };
+
+        let mut alias = function.clone();
+        alias.item = ItemBuf::with_item(name);
+
+        #[cfg(feature = "doc")]
+        {
+            let note = format!("Alias of `{}`.", function.item);
+            let mut lines = vec![note];
+
+            if !alias.docs.lines().is_empty() {
+                lines.push(String::new());
+                lines.extend(alias.docs.lines().iter().cloned());
+            }
+
+            alias.docs.set_docs(lines);
+        }
+
+        let hash = Hash::type_hash(&alias.item);
+
+        if !self.names.insert(Name::Item(hash)) {
+            return Err(ContextError::ConflictingFunctionName {
+                item: alias.item,
+                hash,
+            });
+        }
+
+        self.functions.push(alias);
+
+        let m = self.functions.last_mut().unwrap();
+        Ok(ItemMut {
+            docs: &mut m.docs,
+            deprecated: &mut m.deprecated,
+            unstable: &mut m.unstable,
         })
     }
 
@@ -1065,17 +1512,22 @@ impl Module {
             handler: data.handler,
             #[cfg(feature = "doc")]
             is_async: data.is_async,
-            #[cfg(feature = "doc")]
             args: data.args,
             #[cfg(feature = "doc")]
             return_type: data.return_type,
             #[cfg(feature = "doc")]
             argument_types: data.argument_types,
             docs,
+            deprecated: None,
+            unstable: false,
         });
 
         let m = self.functions.last_mut().unwrap();
-        Ok(ItemMut { docs: &mut m.docs })
+        Ok(ItemMut {
+            docs: &mut m.docs,
+            deprecated: &mut m.deprecated,
+            unstable: &mut m.unstable,
+        })
     }
 
     /// Install an associated function.
@@ -1085,32 +1537,10 @@ impl Module {
         docs: Docs,
     ) -> Result<ItemMut<'_>, ContextError> {
         if !self.names.insert(Name::Associated(data.assoc_key())) {
-            return Err(match data.name.kind {
-                meta::AssociatedKind::Protocol(protocol) => {
-                    ContextError::ConflictingProtocolFunction {
-                        type_info: data.container_type_info,
-                        name: protocol.name.into(),
-                    }
-                }
-                meta::AssociatedKind::FieldFn(protocol, field) => {
-                    ContextError::ConflictingFieldFunction {
-                        type_info: data.container_type_info,
-                        name: protocol.name.into(),
-                        field: field.into(),
-                    }
-                }
-                meta::AssociatedKind::IndexFn(protocol, index) => {
-                    ContextError::ConflictingIndexFunction {
-                        type_info: data.container_type_info,
-                        name: protocol.name.into(),
-                        index,
-                    }
-                }
-                meta::AssociatedKind::Instance(name) => ContextError::ConflictingInstanceFunction {
-                    type_info: data.container_type_info,
-                    name: name.into(),
-                },
-            });
+            return Err(ContextError::conflicting_associated(
+                &data.name.kind,
+                data.container_type_info,
+            ));
         }
 
         self.associated.push(ModuleAssociated {
@@ -1120,17 +1550,22 @@ impl Module {
             handler: data.handler,
             #[cfg(feature = "doc")]
             is_async: data.is_async,
-            #[cfg(feature = "doc")]
             args: data.args,
             #[cfg(feature = "doc")]
             return_type: data.return_type,
             #[cfg(feature = "doc")]
             argument_types: data.argument_types,
             docs,
+            deprecated: None,
+            unstable: false,
         });
 
         let m = self.associated.last_mut().unwrap();
-        Ok(ItemMut { docs: &mut m.docs })
+        Ok(ItemMut {
+            docs: &mut m.docs,
+            deprecated: &mut m.deprecated,
+            unstable: &mut m.unstable,
+        })
     }
 }
 
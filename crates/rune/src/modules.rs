@@ -27,6 +27,7 @@ pub mod mem;
 pub mod object;
 pub mod ops;
 pub mod option;
+pub mod rc;
 pub mod result;
 pub mod stream;
 pub mod string;
@@ -6,7 +6,7 @@ use crate as rune;
 use crate::compile;
 use crate::macros::{quote, FormatArgs, MacroContext, TokenStream};
 use crate::parse::Parser;
-use crate::runtime::{Panic, Tuple, Value, VmResult};
+use crate::runtime::{Function, Panic, Tuple, Value, VmErrorKind, VmResult};
 use crate::{ContextError, Module};
 
 /// Construct the `std` module.
@@ -22,8 +22,10 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty::<Tuple>()?;
 
     module.function_meta(panic)?;
+    module.function_meta(catch)?;
     module.function_meta(is_readable)?;
     module.function_meta(is_writable)?;
+    module.function_meta(deep_clone)?;
 
     module.macro_meta(stringify_macro)?;
     module.macro_meta(panic_macro)?;
@@ -42,6 +44,35 @@ fn panic(message: &str) -> VmResult<()> {
     VmResult::err(Panic::custom(message.to_owned()))
 }
 
+/// Call the given function `f`, catching any panic it causes.
+///
+/// Returns `Ok` with the return value of `f` if it completed, or `Err` with the
+/// panic message if it panicked.
+///
+/// Only panics are caught, like the ones raised through [panic] or the
+/// [panic!] macro. Any other virtual machine error, like a type mismatch or an
+/// error raised by the host, is propagated as usual.
+///
+/// # Examples
+///
+/// ```rune
+/// let result = std::catch(|| panic("boom"));
+/// assert_eq!(result, Err("boom"));
+///
+/// let result = std::catch(|| 42);
+/// assert_eq!(result, Ok(42));
+/// ```
+#[rune::function]
+fn catch(f: Function) -> VmResult<Result<Value, String>> {
+    match f.call::<_, Value>(()) {
+        VmResult::Ok(value) => VmResult::Ok(Ok(value)),
+        VmResult::Err(error) => match error.kind() {
+            VmErrorKind::Panic { reason } => VmResult::Ok(Err(reason.to_string())),
+            _ => VmResult::Err(error),
+        },
+    }
+}
+
 /// Test if the given `value` is readable.
 #[rune::function]
 fn is_readable(value: Value) -> bool {
@@ -78,6 +109,32 @@ fn is_writable(value: Value) -> bool {
     }
 }
 
+/// Deep clone the given `value`.
+///
+/// Values in Rune are reference counted, so assigning an object or a vector
+/// to a new variable does not copy it. This recursively copies vectors,
+/// tuples, objects, structs and enums so that mutating the clone leaves the
+/// original unchanged.
+///
+/// External types are cloned through the `clone` protocol, and cloning a value
+/// which doesn't support it, like a future or an iterator, results in an
+/// error.
+///
+/// # Examples
+///
+/// ```rune
+/// let a = #{ values: [1, 2] };
+/// let b = std::deep_clone(a);
+/// b.values.push(3);
+///
+/// assert_eq!(a.values, [1, 2]);
+/// assert_eq!(b.values, [1, 2, 3]);
+/// ```
+#[rune::function]
+fn deep_clone(value: Value) -> VmResult<Value> {
+    value.deep_clone()
+}
+
 /// Stringify the given argument, causing it to expand to its underlying token
 /// stream.
 ///
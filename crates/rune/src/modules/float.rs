@@ -10,6 +10,9 @@ fn parse(s: &str) -> Result<f64, ParseFloatError> {
 }
 
 /// Convert a float to a whole number.
+///
+/// Values which are out of range saturate, and `NaN` is converted into `0`.
+/// Use `int::from_float` for a conversion which raises an error instead.
 fn to_integer(value: f64) -> i64 {
     value as i64
 }
@@ -31,5 +34,15 @@ pub fn module() -> Result<Module, ContextError> {
     #[cfg(feature = "std")]
     module.associated_function("powi", f64::powi)?;
     module.associated_function("to_integer", to_integer)?;
+
+    module
+        .constant(["PI"], core::f64::consts::PI)?
+        .docs(["Archimedes' constant (π)."]);
+    module
+        .constant(["TAU"], core::f64::consts::TAU)?
+        .docs(["The full circle constant (τ)."]);
+    module
+        .constant(["E"], core::f64::consts::E)?
+        .docs(["Euler's number (e)."]);
     Ok(module)
 }
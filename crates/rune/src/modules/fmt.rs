@@ -1,6 +1,7 @@
 //! The `std::fmt` module.
 
 use core::fmt::{self, Write};
+use core::num::NonZeroUsize;
 
 use crate::no_std::prelude::*;
 
@@ -8,7 +9,11 @@ use crate as rune;
 use crate::compile;
 use crate::macros::{FormatArgs, MacroContext, TokenStream};
 use crate::parse::Parser;
-use crate::runtime::{Format, Protocol};
+use crate::runtime::format::{Alignment, Flag, Flags, FormatSpec, Type};
+use crate::runtime::{
+    EnvProtocolCaller, Format, Object, Panic, Protocol, Ref, TypeInfo, VmErrorKind, VmResult,
+    OBJECT_TYPE,
+};
 use crate::{ContextError, Module};
 
 /// Construct the `std::fmt` module.
@@ -17,6 +22,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.ty::<fmt::Error>()?;
     module.associated_function(Protocol::STRING_DISPLAY, format_fmt_error)?;
     module.macro_meta(format)?;
+    module.function_meta(format_object)?;
 
     module.ty::<Format>()?;
     Ok(module)
@@ -46,3 +52,172 @@ pub(crate) fn format(
     let expanded = args.expand(ctx)?;
     Ok(expanded.into_token_stream(ctx))
 }
+
+/// Format a string by substituting named holes like `{name}` with the
+/// corresponding fields of the given object.
+///
+/// A hole can be followed by a format specification after a `:`, like
+/// `{name:>5}` or `{name:?}`, and literal braces are escaped by doubling them
+/// as `{{` and `}}`. Referencing a field which is missing from the object is
+/// an error.
+///
+/// # Examples
+///
+/// ```rune
+/// let string = format("Hello {who}!", #{ who: "World" });
+/// assert_eq!(string, "Hello World!");
+///
+/// let string = format("{{{value:?}}}", #{ value: "x" });
+/// assert_eq!(string, "{\"x\"}");
+///
+/// let string = format("[{value:>5}]", #{ value: 42 });
+/// assert_eq!(string, "[   42]");
+/// ```
+#[rune::function(path = format)]
+fn format_object(template: &str, args: Ref<Object>) -> VmResult<String> {
+    let mut out = String::new();
+    let mut buf = String::new();
+    let mut it = template.chars();
+
+    while let Some(c) = it.next() {
+        match c {
+            '{' => {
+                let rest = it.as_str();
+
+                if let Some(rest) = rest.strip_prefix('{') {
+                    out.push('{');
+                    it = rest.chars();
+                    continue;
+                }
+
+                let end = match rest.find('}') {
+                    Some(end) => end,
+                    None => {
+                        return VmResult::err(Panic::custom("unterminated `{` in format string"))
+                    }
+                };
+
+                let (name, spec) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+
+                let Some(spec) = parse_spec(spec) else {
+                    return VmResult::err(Panic::msg(format_args!(
+                        "invalid format specification `{spec}`"
+                    )));
+                };
+
+                let value = match args.get(name) {
+                    Some(value) => value,
+                    None => {
+                        return VmResult::err(VmErrorKind::MissingField {
+                            target: TypeInfo::StaticType(OBJECT_TYPE),
+                            field: name.to_owned(),
+                        });
+                    }
+                };
+
+                buf.clear();
+                vm_try!(spec.format(value, &mut out, &mut buf, EnvProtocolCaller));
+                it = rest[end + 1..].chars();
+            }
+            '}' => {
+                let rest = it.as_str();
+
+                match rest.strip_prefix('}') {
+                    Some(rest) => {
+                        out.push('}');
+                        it = rest.chars();
+                    }
+                    None => return VmResult::err(Panic::custom("unmatched `}` in format string")),
+                }
+            }
+            c => out.push(c),
+        }
+    }
+
+    VmResult::Ok(out)
+}
+
+/// Parse the format specification which follows the `:` in a hole, like the
+/// `>5` in `{value:>5}`.
+fn parse_spec(spec: &str) -> Option<FormatSpec> {
+    fn parse_align(c: char) -> Option<Alignment> {
+        match c {
+            '<' => Some(Alignment::Left),
+            '^' => Some(Alignment::Center),
+            '>' => Some(Alignment::Right),
+            _ => None,
+        }
+    }
+
+    fn parse_number(s: &str) -> (Option<NonZeroUsize>, &str) {
+        let end = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let n = s[..end].parse().ok().and_then(NonZeroUsize::new);
+        (n, &s[end..])
+    }
+
+    let mut fill = ' ';
+    let mut align = Alignment::default();
+    let mut flags = Flags::default();
+    let mut rest = spec;
+
+    let mut chars = rest.chars();
+    let first = chars.next();
+
+    if let (Some(c), Some(a)) = (first, chars.next().and_then(parse_align)) {
+        fill = c;
+        align = a;
+        rest = &rest[c.len_utf8() + 1..];
+    } else if let Some(a) = first.and_then(parse_align) {
+        align = a;
+        rest = &rest[1..];
+    }
+
+    if let Some(r) = rest.strip_prefix('+') {
+        flags.set(Flag::SignPlus);
+        rest = r;
+    } else if let Some(r) = rest.strip_prefix('-') {
+        flags.set(Flag::SignMinus);
+        rest = r;
+    }
+
+    if let Some(r) = rest.strip_prefix('#') {
+        flags.set(Flag::Alternate);
+        rest = r;
+    }
+
+    if let Some(r) = rest.strip_prefix('0') {
+        flags.set(Flag::SignAwareZeroPad);
+        rest = r;
+    }
+
+    let (width, r) = parse_number(rest);
+    rest = r;
+
+    let precision = match rest.strip_prefix('.') {
+        Some(r) => {
+            let (precision, r) = parse_number(r);
+            rest = r;
+            precision
+        }
+        None => None,
+    };
+
+    let format_type = match rest {
+        "" => Type::Display,
+        "?" => Type::Debug,
+        "x" => Type::LowerHex,
+        "X" => Type::UpperHex,
+        "b" => Type::Binary,
+        "p" => Type::Pointer,
+        _ => return None,
+    };
+
+    Some(FormatSpec::new(
+        flags,
+        fill,
+        align,
+        width,
+        precision,
+        format_type,
+    ))
+}
@@ -2,8 +2,11 @@
 
 use core::num::ParseIntError;
 
+use crate::runtime::{VmErrorKind, VmResult};
 use crate::{ContextError, Module};
 
+use crate as rune;
+
 /// Construct the `std::int` module.
 pub fn module() -> Result<Module, ContextError> {
     let mut module = Module::with_crate_item("std", ["int"]);
@@ -12,6 +15,8 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.function(["parse"], parse)?;
     module.associated_function("to_float", to_float)?;
+    module.function_meta(from_float)?;
+    module.function_meta(to_byte)?;
 
     module.associated_function("max", i64::max)?;
     module.associated_function("min", i64::min)?;
@@ -49,4 +54,61 @@ fn to_float(value: i64) -> f64 {
     value as f64
 }
 
+/// Convert a float into an integer, truncating any fractional part towards
+/// zero.
+///
+/// Unlike `float.to_integer()`, which silently saturates values which are out
+/// of range and converts `NaN` into `0`, this raises an error:
+///
+/// * If the value is `NaN`.
+/// * With a numerical overflow if the value is larger than the largest integer,
+///   which includes positive infinity.
+/// * With a numerical underflow if the value is smaller than the smallest
+///   integer, which includes negative infinity.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(int::from_float(42.9), 42);
+/// assert_eq!(int::from_float(-1.5), -1);
+/// ```
+#[rune::function]
+fn from_float(value: f64) -> VmResult<i64> {
+    // NB: -2^63 is exactly representable as a float, while 2^63 is the
+    // smallest float which is out of range.
+    const MIN: f64 = -9_223_372_036_854_775_808.0;
+    const MAX: f64 = 9_223_372_036_854_775_808.0;
+
+    if value.is_nan() {
+        VmResult::err(VmErrorKind::NanConversion)
+    } else if value < MIN {
+        VmResult::err(VmErrorKind::Underflow)
+    } else if value >= MAX {
+        VmResult::err(VmErrorKind::Overflow)
+    } else {
+        VmResult::Ok(value as i64)
+    }
+}
+
+/// Convert an integer into a byte.
+///
+/// This raises a numerical underflow if the value is negative, and a numerical
+/// overflow if it's larger than `255`.
+///
+/// # Examples
+///
+/// ```rune
+/// assert_eq!(65.to_byte(), b'A');
+/// ```
+#[rune::function(instance)]
+fn to_byte(value: i64) -> VmResult<u8> {
+    if value < 0 {
+        VmResult::err(VmErrorKind::Underflow)
+    } else if value > u8::MAX as i64 {
+        VmResult::err(VmErrorKind::Overflow)
+    } else {
+        VmResult::Ok(value as u8)
+    }
+}
+
 crate::__internal_impl_any!(::std::int, ParseIntError);
@@ -32,6 +32,14 @@ pub fn module() -> Result<Module, ContextError> {
 
     module.function_meta(Range::__contains_int__meta)?;
 
+    module
+        .associated_function("contains", Range::contains_int)?
+        .docs([
+            "Test if the range contains the given integer.",
+            "",
+            "This is the same as `contains::<i64>`.",
+        ]);
+
     module
         .associated_function("iter", Range::into_iterator)?
         .docs([
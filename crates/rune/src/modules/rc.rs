@@ -0,0 +1,125 @@
+//! The `std::rc` module.
+
+use core::fmt;
+
+use crate::no_std::prelude::*;
+
+use crate as rune;
+use crate::runtime::{Value, VmErrorKind, VmResult};
+use crate::{Any, ContextError, Module};
+
+/// Construct the `std::rc` module.
+pub fn module() -> Result<Module, ContextError> {
+    let mut module = Module::with_crate_item("std", ["rc"]);
+    module.ty::<Weak>()?;
+    module.function_meta(downgrade)?;
+    module.function_meta(Weak::upgrade)?;
+    Ok(module)
+}
+
+/// A weak reference to a value, constructed through [`downgrade`].
+///
+/// A weak reference doesn't keep the value it refers to alive, which makes it
+/// possible to build cyclic structures like a child holding a reference back to
+/// its parent without leaking them.
+#[derive(Any)]
+#[rune(module = crate, item = ::std::rc)]
+pub struct Weak {
+    upgrade: Box<dyn Fn() -> Option<Value>>,
+}
+
+impl Weak {
+    /// Try to get the value that this weak reference refers to.
+    ///
+    /// This returns `None` if the value has been dropped, which happens once
+    /// there are no strong references to it left.
+    ///
+    /// # Examples
+    ///
+    /// ```rune
+    /// use std::rc::downgrade;
+    ///
+    /// let weak = {
+    ///     let value = #{ name: "parent" };
+    ///     let weak = downgrade(value);
+    ///     assert_eq!(weak.upgrade().map(|v| v.name), Some("parent"));
+    ///     weak
+    /// };
+    ///
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    #[rune::function]
+    fn upgrade(&self) -> Option<Value> {
+        (self.upgrade)()
+    }
+}
+
+impl fmt::Debug for Weak {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Weak").finish_non_exhaustive()
+    }
+}
+
+/// Construct a weak reference to the given value, which doesn't keep the value
+/// alive.
+///
+/// Only values which are reference counted, like objects, vectors and external
+/// types can be referenced weakly. Trying to downgrade other values like
+/// integers raises an error.
+///
+/// # Examples
+///
+/// ```rune
+/// use std::rc::downgrade;
+///
+/// let parent = #{ children: [] };
+/// let child = #{ parent: downgrade(parent) };
+/// parent.children.push(child);
+///
+/// assert!(child.parent.upgrade().is_some());
+/// ```
+#[rune::function]
+fn downgrade(value: Value) -> VmResult<Weak> {
+    macro_rules! downgrade {
+        ($value:expr, $($variant:ident),* $(,)?) => {
+            match $value {
+                $(
+                    Value::$variant(shared) => {
+                        let weak = shared.downgrade();
+                        Box::new(move || Some(Value::$variant(weak.upgrade()?)))
+                    }
+                )*
+                value => {
+                    return VmResult::err(VmErrorKind::WeakNotSupported {
+                        actual: vm_try!(value.type_info()),
+                    });
+                }
+            }
+        };
+    }
+
+    let upgrade: Box<dyn Fn() -> Option<Value>> = downgrade!(
+        value,
+        String,
+        Bytes,
+        Vec,
+        Tuple,
+        Object,
+        Range,
+        Future,
+        Stream,
+        Generator,
+        GeneratorState,
+        Option,
+        Result,
+        UnitStruct,
+        TupleStruct,
+        Struct,
+        Variant,
+        Function,
+        Iterator,
+        Any,
+    );
+
+    VmResult::Ok(Weak { upgrade })
+}
@@ -44,6 +44,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.associated_function("chars", string_chars)?;
     module.associated_function(Protocol::ADD, add)?;
     module.associated_function(Protocol::ADD_ASSIGN, String::push_str)?;
+    module.associated_function(Protocol::MUL, mul)?;
     module.associated_function(Protocol::INDEX_GET, string_index_get)?;
     module.associated_function("get", string_get)?;
 
@@ -126,6 +127,28 @@ fn add(a: &str, b: &str) -> String {
     string
 }
 
+/// The mul operation for strings, which repeats the string `count` times.
+///
+/// The repeated string is allocated up front, so that a repeat count which is
+/// too large results in an error instead of aborting.
+fn mul(a: &str, count: usize) -> VmResult<String> {
+    let Some(size) = a.len().checked_mul(count) else {
+        return VmResult::err(VmErrorKind::Overflow);
+    };
+
+    let mut string = String::new();
+
+    if string.try_reserve_exact(size).is_err() {
+        return VmResult::err(VmErrorKind::AllocationFailed { size });
+    }
+
+    for _ in 0..count {
+        string.push_str(a);
+    }
+
+    VmResult::Ok(string)
+}
+
 fn string_chars(s: &str) -> Iterator {
     let iter = s.chars().collect::<Vec<_>>().into_iter();
     Iterator::from_double_ended("std::str::Chars", iter)
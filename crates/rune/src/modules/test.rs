@@ -72,14 +72,17 @@ pub(crate) fn assert(
         None
     };
 
+    let location = ctx.describe_location();
+
     let output = if let Some(message) = &message {
         let expanded = message.expand(ctx)?;
+        let prefix = ctx.lit(&format!("assertion failed at {location}: "));
 
         quote!(if !(#expr) {
-            panic("assertion failed: " + (#expanded));
+            panic(#prefix + (#expanded));
         })
     } else {
-        let message = format!("assertion failed: {}", ctx.stringify(&expr));
+        let message = format!("assertion failed at {location}: {}", ctx.stringify(&expr));
         let message = ctx.lit(&message);
 
         quote!(if !(#expr) {
@@ -119,8 +122,11 @@ pub(crate) fn assert_eq(
         None
     };
 
+    let location = ctx.describe_location();
+
     let output = if let Some(message) = &message {
         let message = message.expand(ctx)?;
+        let prefix = ctx.lit(&format!("assertion failed at {location} (left == right): "));
 
         quote! {{
             let left = #left;
@@ -130,11 +136,11 @@ pub(crate) fn assert_eq(
                 let message = #message;
                 message += format!("\nleft: {:?}", left);
                 message += format!("\nright: {:?}", right);
-                panic("assertion failed (left == right): " + message);
+                panic(#prefix + message);
             }
         }}
     } else {
-        let message = ctx.lit("assertion failed (left == right):");
+        let message = ctx.lit(&format!("assertion failed at {location} (left == right):"));
 
         quote! {{
             let left = #left;
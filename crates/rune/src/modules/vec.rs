@@ -3,7 +3,7 @@
 use core::cmp;
 
 use crate as rune;
-use crate::runtime::{Function, Protocol, Value, Vec, VmResult};
+use crate::runtime::{Function, Iterator, Protocol, Value, Vec, VmResult};
 use crate::{ContextError, Module};
 
 /// Construct the `std::vec` module.
@@ -15,6 +15,7 @@ pub fn module() -> Result<Module, ContextError> {
     module.function(["Vec", "new"], Vec::new)?;
     module.associated_function("clear", Vec::clear)?;
     module.associated_function("clone", Vec::clone)?;
+    module.function_meta(enumerate)?;
     module.associated_function("extend", Vec::extend)?;
     module.function_meta(get)?;
     module.associated_function("iter", Vec::into_iterator)?;
@@ -41,6 +42,27 @@ fn sort_int(vec: &mut Vec) {
     });
 }
 
+/// Iterate over the vector, yielding each value together with its index.
+///
+/// This is a shorthand for `vec.iter().enumerate()`.
+///
+/// # Examples
+///
+/// ```rune
+/// let values = ["a", "b"];
+/// let out = [];
+///
+/// for (index, value) in values.enumerate() {
+///     out.push(`${index}: ${value}`);
+/// }
+///
+/// assert_eq!(out, ["0: a", "1: b"]);
+/// ```
+#[rune::function(instance, path = Vec::enumerate)]
+fn enumerate(vec: &Vec) -> Iterator {
+    vec.into_iterator().enumerate()
+}
+
 /// Get a value by the specified `index`.
 ///
 /// # Examples
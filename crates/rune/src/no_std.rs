@@ -6,6 +6,11 @@
 pub struct RawEnv {
     pub(crate) context: *const (),
     pub(crate) unit: *const (),
+    pub(crate) ext: *const (),
+    pub(crate) overflow: *const (),
+    pub(crate) cancellation: *const (),
+    #[cfg(feature = "native-hook")]
+    pub(crate) native_hook: *const (),
 }
 
 impl RawEnv {
@@ -14,6 +19,11 @@ impl RawEnv {
         RawEnv {
             context: core::ptr::null(),
             unit: core::ptr::null(),
+            ext: core::ptr::null(),
+            overflow: core::ptr::null(),
+            cancellation: core::ptr::null(),
+            #[cfg(feature = "native-hook")]
+            native_hook: core::ptr::null(),
         }
     }
 }
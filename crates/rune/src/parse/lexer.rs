@@ -90,6 +90,7 @@ impl<'a> Lexer<'a> {
                 source_id: self.source_id,
                 escaped: false,
                 wrapped: false,
+                raw: None,
             })),
             span: docstring_span,
         });
@@ -384,6 +385,58 @@ impl<'a> Lexer<'a> {
                 source_id: self.source_id,
                 escaped,
                 wrapped: true,
+                raw: None,
+            })),
+            span: self.iter.span_to_pos(start),
+        }))
+    }
+
+    /// Consume a raw string literal, like `r#"..."#`, with the leading `r`
+    /// already consumed.
+    ///
+    /// The string is terminated by the first `"` which is followed by as many
+    /// `#` as were used to open it.
+    fn next_raw_str(&mut self, start: usize) -> compile::Result<Option<ast::Token>> {
+        let mut hashes = 0u32;
+
+        while matches!(self.iter.peek(), Some('#')) {
+            self.iter.next();
+            hashes += 1;
+        }
+
+        // NB: checked by `is_raw_str`.
+        self.iter.next();
+
+        loop {
+            let Some(c) = self.iter.next() else {
+                return Err(compile::Error::new(
+                    self.iter.span_to_pos(start),
+                    ParseErrorKind::UnterminatedStrLit,
+                ));
+            };
+
+            if c != '"' {
+                continue;
+            }
+
+            let mut closing = 0;
+
+            while closing < hashes && matches!(self.iter.peek(), Some('#')) {
+                self.iter.next();
+                closing += 1;
+            }
+
+            if closing == hashes {
+                break;
+            }
+        }
+
+        Ok(Some(ast::Token {
+            kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                source_id: self.source_id,
+                escaped: false,
+                wrapped: true,
+                raw: Some(hashes),
             })),
             span: self.iter.span_to_pos(start),
         }))
@@ -466,6 +519,7 @@ impl<'a> Lexer<'a> {
                                 source_id: self.source_id,
                                 escaped: take(&mut escaped),
                                 wrapped: false,
+                                raw: None,
                             })),
                             span,
                         });
@@ -516,6 +570,7 @@ impl<'a> Lexer<'a> {
                                 source_id: self.source_id,
                                 escaped: take(&mut escaped),
                                 wrapped: false,
+                                raw: None,
                             })),
                             span,
                         });
@@ -753,6 +808,9 @@ impl<'a> Lexer<'a> {
                                 ast::Kind::ByteStr,
                             );
                         }
+                        ('r', '"' | '#') if self.iter.is_raw_str() => {
+                            return self.next_raw_str(start);
+                        }
                         _ => (),
                     }
                 }
@@ -907,6 +965,15 @@ impl<'a> SourceIter<'a> {
         self.source.get(self.cursor..)?.chars().next()
     }
 
+    /// Test if the upcoming characters open a raw string, that is any number
+    /// of `#` followed by a `"`.
+    fn is_raw_str(&self) -> bool {
+        match self.source.get(self.cursor..) {
+            Some(rest) => rest.trim_start_matches('#').starts_with('"'),
+            None => false,
+        }
+    }
+
     /// Peek the next next char.
     fn peek2(&self) -> Option<char> {
         let mut it = self.source.get(self.cursor..)?.chars();
@@ -1141,7 +1208,7 @@ mod tests {
             },
             ast::Token {
                 span: span!(10, 19),
-                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText { source_id: SourceId::EMPTY, escaped: false, wrapped: true })),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText { source_id: SourceId::EMPTY, escaped: false, wrapped: true, raw: None })),
             }
         };
     }
@@ -1213,6 +1280,58 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_multi_char_operators() {
+        let operators = [
+            ("&&", ast::Kind::AmpAmp),
+            ("&=", ast::Kind::AmpEq),
+            ("||", ast::Kind::PipePipe),
+            ("|=", ast::Kind::PipeEq),
+            ("^=", ast::Kind::CaretEq),
+            ("%=", ast::Kind::PercEq),
+            ("!=", ast::Kind::BangEq),
+            ("==", ast::Kind::EqEq),
+            ("<=", ast::Kind::LtEq),
+            (">=", ast::Kind::GtEq),
+            ("<<", ast::Kind::LtLt),
+            ("<<=", ast::Kind::LtLtEq),
+            (">>", ast::Kind::GtGt),
+            (">>=", ast::Kind::GtGtEq),
+            ("->", ast::Kind::Arrow),
+            ("=>", ast::Kind::Rocket),
+            ("::", ast::Kind::ColonColon),
+            ("..", ast::Kind::DotDot),
+            ("..=", ast::Kind::DotDotEq),
+        ];
+
+        for (source, kind) in operators {
+            let mut it = Lexer::new(source, SourceId::empty(), false);
+            let token = it.next().unwrap().expect("expected token");
+            assert_eq!(token.kind, kind, "{source}");
+            assert_eq!(token.span, ast::Span::new(0, source.len()), "{source}");
+            assert_eq!(it.next().unwrap(), None, "{source}");
+        }
+    }
+
+    #[test]
+    fn test_maximal_munch() {
+        test_lexer! {
+            "a&&&b||!c<<<d%e",
+            ast::Token { span: span!(0, 1), kind: ast::Kind::Ident(..) },
+            ast::Token { span: span!(1, 3), kind: ast::Kind::AmpAmp },
+            ast::Token { span: span!(3, 4), kind: ast::Kind::Amp },
+            ast::Token { span: span!(4, 5), kind: ast::Kind::Ident(..) },
+            ast::Token { span: span!(5, 7), kind: ast::Kind::PipePipe },
+            ast::Token { span: span!(7, 8), kind: ast::Kind::Bang },
+            ast::Token { span: span!(8, 9), kind: ast::Kind::Ident(..) },
+            ast::Token { span: span!(9, 11), kind: ast::Kind::LtLt },
+            ast::Token { span: span!(11, 12), kind: ast::Kind::Lt },
+            ast::Token { span: span!(12, 13), kind: ast::Kind::Ident(..) },
+            ast::Token { span: span!(13, 14), kind: ast::Kind::Perc },
+            ast::Token { span: span!(14, 15), kind: ast::Kind::Ident(..) },
+        };
+    }
+
     #[test]
     fn test_idents() {
         test_lexer! {
@@ -1278,6 +1397,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(3, 10)
             },
@@ -1306,6 +1426,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(13, 22)
             },
@@ -1351,6 +1472,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(3, 21)
             },
@@ -1383,6 +1505,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(27, 39)
             },
@@ -1473,6 +1596,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(1, 5),
             },
@@ -1493,6 +1617,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: true,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(11, 18),
             },
@@ -1560,6 +1685,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(1, 5),
             },
@@ -1580,6 +1706,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: false,
+                    raw: None,
                 })),
                 span: span!(11, 12),
             },
@@ -1602,6 +1729,43 @@ mod tests {
         };
     }
 
+    #[test]
+    fn test_raw_strings() {
+        test_lexer! {
+            r#"r"C:\foo""#,
+            ast::Token {
+                span: span!(0, 9),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    source_id: SourceId::EMPTY,
+                    escaped: false,
+                    wrapped: true,
+                    raw: Some(0),
+                })),
+            },
+        };
+
+        test_lexer! {
+            r###"r##"a"#b"## r"###,
+            ast::Token {
+                span: span!(0, 11),
+                kind: ast::Kind::Str(ast::StrSource::Text(ast::StrText {
+                    source_id: SourceId::EMPTY,
+                    escaped: false,
+                    wrapped: true,
+                    raw: Some(2),
+                })),
+            },
+            ast::Token {
+                span: span!(11, 12),
+                kind: ast::Kind::Whitespace,
+            },
+            ast::Token {
+                span: span!(12, 13),
+                kind: ast::Kind::Ident(ast::LitSource::Text(SourceId::EMPTY)),
+            },
+        };
+    }
+
     #[test]
     fn test_literals() {
         test_lexer! {
@@ -1612,6 +1776,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: true,
+                    raw: None,
                 })),
             },
         };
@@ -1624,6 +1789,7 @@ mod tests {
                     source_id: SourceId::EMPTY,
                     escaped: false,
                     wrapped: true,
+                    raw: None,
                 })),
             },
         };
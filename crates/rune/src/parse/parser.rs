@@ -2,8 +2,9 @@ use core::fmt;
 use core::ops;
 
 use crate::no_std::collections::VecDeque;
+use crate::no_std::prelude::*;
 
-use crate::ast::{Kind, OptionSpanned, Span, Token};
+use crate::ast::{Delimiter, Kind, OptionSpanned, Span, Token};
 use crate::compile::{self, ParseErrorKind};
 use crate::macros::{TokenStream, TokenStreamIter};
 use crate::parse::{Lexer, Parse, Peek};
@@ -116,6 +117,7 @@ impl<'a> Parser<'a> {
                 error: None,
                 last: None,
                 default_span,
+                delimiters: Vec::new(),
             },
         }
     }
@@ -229,6 +231,9 @@ pub struct Peeker<'a> {
     last: Option<Span>,
     /// The default span to use in case no better one is available.
     default_span: Span,
+    /// Stack of currently open delimiters, used to report unclosed delimiters
+    /// at the position they were opened.
+    delimiters: Vec<(Delimiter, Span)>,
 }
 
 impl<'a> Peeker<'a> {
@@ -291,9 +296,30 @@ impl<'a> Peeker<'a> {
         loop {
             let token = match self.source.next()? {
                 Some(token) => token,
-                None => return Ok(None),
+                None => {
+                    if let Some(&(delim, span)) = self.delimiters.last() {
+                        return Err(compile::Error::new(
+                            span,
+                            ParseErrorKind::UnclosedDelimiter { delim },
+                        ));
+                    }
+
+                    return Ok(None);
+                }
             };
 
+            match token.kind {
+                Kind::Open(delim) if delim != Delimiter::Empty => {
+                    self.delimiters.push((delim, token.span));
+                }
+                Kind::Close(delim) => {
+                    if matches!(self.delimiters.last(), Some(&(open, _)) if open == delim) {
+                        self.delimiters.pop();
+                    }
+                }
+                _ => (),
+            }
+
             match token.kind {
                 Kind::Comment | Kind::Whitespace => {
                     continue;
@@ -0,0 +1,240 @@
+use core::mem::take;
+
+use crate::no_std::prelude::*;
+
+use crate::ast::{Kind, Span, Token};
+use crate::compile::{self, ParseErrorKind};
+use crate::parse::Lexer;
+use crate::SourceId;
+
+/// The kind of a piece of [`Trivia`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TriviaKind {
+    /// A line comment, like `// hello`.
+    Comment,
+    /// A block comment, like `/* hello */`.
+    MultilineComment,
+    /// The given number of blank lines.
+    BlankLines(usize),
+}
+
+/// A comment or a run of blank lines which is not significant to the parser.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct Trivia {
+    /// The span of the trivia.
+    pub span: Span,
+    /// The kind of the trivia.
+    pub kind: TriviaKind,
+}
+
+/// A token together with the trivia surrounding it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct TriviaToken {
+    /// The token.
+    pub token: Token,
+    /// Trivia on the lines preceding the token.
+    pub leading: Vec<Trivia>,
+    /// Trivia following the token on the same line. The last token in the
+    /// input also receives any trivia at the end of the input.
+    pub trailing: Vec<Trivia>,
+}
+
+/// A tokenizer which preserves comments and blank lines instead of skipping
+/// them, so that they can be reproduced by tooling such as the formatter.
+///
+/// # Examples
+///
+/// ```
+/// use rune::ast;
+/// use rune::SourceId;
+/// use rune::parse::{TriviaKind, TriviaLexer};
+///
+/// let mut lexer = TriviaLexer::new("// hello\nfoo", SourceId::empty(), false);
+///
+/// let token = lexer.next()?.expect("expected token");
+/// assert!(matches!(token.token.kind, ast::Kind::Ident(..)));
+/// assert_eq!(token.leading[0].kind, TriviaKind::Comment);
+/// assert!(lexer.next()?.is_none());
+/// # Ok::<_, rune::Error>(())
+/// ```
+#[derive(Debug)]
+pub struct TriviaLexer<'a> {
+    source: &'a str,
+    lexer: Lexer<'a>,
+    /// Next token which is not trivia.
+    peeked: Option<Token>,
+    /// Leading trivia collected for the peeked token.
+    leading: Vec<Trivia>,
+    /// If the lexer is currently at the start of a line.
+    line_start: bool,
+}
+
+impl<'a> TriviaLexer<'a> {
+    /// Construct a new trivia preserving lexer over the given source.
+    ///
+    /// `shebang` indicates if the lexer should try and lex a shebang or not.
+    pub fn new(source: &'a str, source_id: SourceId, shebang: bool) -> Self {
+        Self {
+            source,
+            lexer: Lexer::new(source, source_id, shebang),
+            peeked: None,
+            leading: Vec::new(),
+            line_start: true,
+        }
+    }
+
+    /// Consume the next token from the lexer along with its trivia.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> compile::Result<Option<TriviaToken>> {
+        let token = match self.peeked.take() {
+            Some(token) => token,
+            None => match self.advance(None)? {
+                Some(token) => token,
+                None => return Ok(None),
+            },
+        };
+
+        let leading = take(&mut self.leading);
+        let mut trailing = Vec::new();
+        self.line_start = false;
+
+        self.peeked = self.advance(Some(&mut trailing))?;
+
+        // Trivia at the end of the input is attached to the last token.
+        if self.peeked.is_none() {
+            trailing.append(&mut self.leading);
+        }
+
+        Ok(Some(TriviaToken {
+            token,
+            leading,
+            trailing,
+        }))
+    }
+
+    /// Advance to the next token which is not trivia.
+    ///
+    /// Trivia up until the first newline is collected into `trailing` if it's
+    /// provided, the rest is stored as leading trivia of the next token.
+    fn advance(
+        &mut self,
+        mut trailing: Option<&mut Vec<Trivia>>,
+    ) -> compile::Result<Option<Token>> {
+        while let Some(token) = self.lexer.next()? {
+            let kind = match token.kind {
+                Kind::Whitespace => {
+                    let source = self.source.get(token.span.range()).unwrap_or_default();
+                    let newlines = source.chars().filter(|c| *c == '\n').count();
+
+                    if newlines == 0 {
+                        continue;
+                    }
+
+                    let blank = if self.line_start {
+                        newlines
+                    } else {
+                        newlines - 1
+                    };
+
+                    trailing = None;
+                    self.line_start = true;
+
+                    if blank == 0 {
+                        continue;
+                    }
+
+                    TriviaKind::BlankLines(blank)
+                }
+                Kind::Comment => TriviaKind::Comment,
+                Kind::MultilineComment(true) => TriviaKind::MultilineComment,
+                Kind::MultilineComment(false) => {
+                    return Err(compile::Error::new(
+                        token.span,
+                        ParseErrorKind::ExpectedMultilineCommentTerm,
+                    ));
+                }
+                _ => return Ok(Some(token)),
+            };
+
+            let trivia = Trivia {
+                span: token.span,
+                kind,
+            };
+
+            match &mut trailing {
+                Some(trailing) => trailing.push(trivia),
+                None => self.leading.push(trivia),
+            }
+
+            // Line comments include their terminating newline.
+            if let TriviaKind::Comment = kind {
+                trailing = None;
+                self.line_start = true;
+            } else if let TriviaKind::MultilineComment = kind {
+                self.line_start = false;
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ast::{self, Span};
+    use crate::SourceId;
+
+    use super::{Trivia, TriviaKind, TriviaLexer};
+
+    #[test]
+    fn test_trivia() {
+        let source = "// leading\nfoo /* inline */ // trailing\n\n\nbar\n/* end */";
+        let mut lexer = TriviaLexer::new(source, SourceId::empty(), false);
+
+        let foo = lexer.next().unwrap().expect("expected foo");
+        assert!(matches!(foo.token.kind, ast::Kind::Ident(..)));
+        assert_eq!(foo.token.span, Span::new(11, 14));
+        assert_eq!(
+            foo.leading,
+            [Trivia {
+                span: Span::new(0, 11),
+                kind: TriviaKind::Comment
+            }]
+        );
+        assert_eq!(
+            foo.trailing,
+            [
+                Trivia {
+                    span: Span::new(15, 27),
+                    kind: TriviaKind::MultilineComment
+                },
+                Trivia {
+                    span: Span::new(28, 40),
+                    kind: TriviaKind::Comment
+                },
+            ]
+        );
+
+        let bar = lexer.next().unwrap().expect("expected bar");
+        assert_eq!(bar.token.span, Span::new(42, 45));
+        assert_eq!(
+            bar.leading,
+            [Trivia {
+                span: Span::new(40, 42),
+                kind: TriviaKind::BlankLines(2)
+            }]
+        );
+        assert_eq!(
+            bar.trailing,
+            [Trivia {
+                span: Span::new(46, 55),
+                kind: TriviaKind::MultilineComment
+            }]
+        );
+
+        assert!(lexer.next().unwrap().is_none());
+    }
+}
@@ -10,9 +10,10 @@ use crate::ast::{Span, Spanned};
 use crate::compile::ir;
 use crate::compile::meta;
 use crate::compile::{
-    self, CompileErrorKind, CompileVisitor, ComponentRef, Doc, ImportStep, IntoComponent, IrBudget,
-    IrCompiler, IrInterpreter, Item, ItemBuf, ItemId, ItemMeta, Location, ModId, ModMeta, Names,
-    Pool, Prelude, QueryErrorKind, SourceMeta, UnitBuilder, Visibility, WithSpan,
+    self, attrs, CompileErrorKind, CompileVisitor, ComponentRef, Doc, Docs, ImportStep,
+    IntoComponent, IrBudget, IrCompiler, IrInterpreter, Item, ItemBuf, ItemId, ItemMeta, Location,
+    ModId, ModMeta, Names, Options, Pool, Prelude, QueryErrorKind, SourceMeta, UnitBuilder,
+    Visibility, WithSpan,
 };
 use crate::hir;
 use crate::indexing::{self, Indexed};
@@ -23,9 +24,6 @@ use crate::runtime::Call;
 use crate::shared::{Consts, Gen, Items};
 use crate::{Context, Hash, SourceId, Sources};
 
-/// The permitted number of import recursions when constructing a path.
-const IMPORT_RECURSION_LIMIT: usize = 128;
-
 #[derive(Default)]
 pub(crate) struct QueryInner {
     /// Resolved meta about every single item during a compilation.
@@ -49,6 +47,11 @@ pub(crate) struct QueryInner {
     items: HashMap<NonZeroId, ItemMeta>,
     /// All available names.
     names: Names,
+    /// Items referenced by each item that has been built, in the order they
+    /// were first referenced.
+    dependencies: HashMap<ItemId, Vec<ItemId>>,
+    /// Every `(from, to)` pair in `dependencies`, used to deduplicate them.
+    dependency_edges: HashSet<(ItemId, ItemId)>,
 }
 
 /// Query system of the rune compiler.
@@ -65,6 +68,8 @@ pub(crate) struct Query<'a> {
     pub(crate) unit: &'a mut UnitBuilder,
     /// The prelude in effect.
     prelude: &'a Prelude,
+    /// Compiler options in effect.
+    pub(crate) options: &'a Options,
     /// Cache of constants that have been expanded.
     pub(crate) consts: &'a mut Consts,
     /// Storage associated with the query.
@@ -86,6 +91,7 @@ impl<'a> Query<'a> {
     pub(crate) fn new(
         unit: &'a mut UnitBuilder,
         prelude: &'a Prelude,
+        options: &'a Options,
         consts: &'a mut Consts,
         storage: &'a mut Storage,
         sources: &'a mut Sources,
@@ -97,6 +103,7 @@ impl<'a> Query<'a> {
         Self {
             unit,
             prelude,
+            options,
             consts,
             storage,
             sources,
@@ -112,6 +119,7 @@ impl<'a> Query<'a> {
         Query {
             unit: self.unit,
             prelude: self.prelude,
+            options: self.options,
             consts: self.consts,
             storage: self.storage,
             pool: self.pool,
@@ -539,25 +547,73 @@ impl<'a> Query<'a> {
     /// Remove and queue up unused entries for building.
     ///
     /// Returns boolean indicating if any unused entries were queued up.
-    #[tracing::instrument(skip_all)]
     pub(crate) fn queue_unused_entries(
         &mut self,
+    ) -> compile::Result<bool, (SourceId, compile::Error)> {
+        self.queue_unused_entries_by(|_| true)
+    }
+
+    /// Remove and queue up unused entries for building, but only for entries
+    /// which belong to a source matching the given filter.
+    ///
+    /// Entries belonging to other sources are left indexed, so they are only
+    /// built if something ends up using them.
+    ///
+    /// Returns boolean indicating if any unused entries were queued up.
+    #[tracing::instrument(skip_all)]
+    pub(crate) fn queue_unused_entries_by(
+        &mut self,
+        mut filter: impl FnMut(SourceId) -> bool,
     ) -> compile::Result<bool, (SourceId, compile::Error)> {
         tracing::trace!("queue unused");
 
-        let unused = self
+        let mut unused = Vec::new();
+        let mut imports = Vec::new();
+
+        for e in self
             .inner
             .indexed
             .values()
             .flat_map(|entries| entries.iter())
-            .map(|e| (e.item_meta.location, e.item_meta.item))
-            .collect::<Vec<_>>();
+            .filter(|e| filter(e.item_meta.location.source_id))
+        {
+            match &e.indexed {
+                Indexed::Import(import) => {
+                    // Imports with a visibility are re-exports, which might be
+                    // used from outside of the unit.
+                    let report =
+                        !import.wildcard && matches!(e.item_meta.visibility, Visibility::Inherited);
+
+                    imports.push((e.item_meta.location, e.item_meta.item, report));
+                }
+                _ => {
+                    unused.push((e.item_meta.location, e.item_meta.item));
+                }
+            }
+        }
+
+        // Imports are only queued once every other unused entry has been
+        // built, since building them might end up using the imports.
+        if !unused.is_empty() {
+            for (location, item) in unused {
+                let _ = self
+                    .query_indexed_meta(location.span, item, Used::Unused)
+                    .map_err(|e| (location.source_id, e))?;
+            }
+
+            return Ok(true);
+        }
 
-        if unused.is_empty() {
+        if imports.is_empty() {
             return Ok(false);
         }
 
-        for (location, item) in unused {
+        for (location, item, report) in imports {
+            if report {
+                self.visitor
+                    .visit_unused_import(location, self.pool.item(item));
+            }
+
             let _ = self
                 .query_indexed_meta(location.span, item, Used::Unused)
                 .map_err(|e| (location.source_id, e))?;
@@ -571,6 +627,28 @@ impl<'a> Query<'a> {
         self.inner.meta.get(&(item, hash))
     }
 
+    /// Iterate over all meta which has been resolved so far.
+    pub(crate) fn iter_meta(&self) -> impl Iterator<Item = &meta::Meta> {
+        self.inner.meta.values()
+    }
+
+    /// Record that the item `from` references the item `to`.
+    pub(crate) fn insert_dependency(&mut self, from: ItemId, to: ItemId) {
+        if from == to || !self.inner.dependency_edges.insert((from, to)) {
+            return;
+        }
+
+        self.inner.dependencies.entry(from).or_default().push(to);
+    }
+
+    /// Iterate over all recorded dependencies between items.
+    pub(crate) fn iter_dependencies(&self) -> impl Iterator<Item = (ItemId, &[ItemId])> {
+        self.inner
+            .dependencies
+            .iter()
+            .map(|(item, dependencies)| (*item, dependencies.as_slice()))
+    }
+
     /// Query for the given meta by looking up the reverse of the specified
     /// item.
     #[tracing::instrument(skip(self, span, item), fields(item = ?self.pool.item(item)))]
@@ -846,6 +924,73 @@ impl<'a> Query<'a> {
         self.inner.names.iter_components(iter)
     }
 
+    /// Test if the item at the given path is visible from the given module.
+    ///
+    /// Items which have already been built are checked against their built
+    /// meta. Context items and paths which only exist as a prefix of other
+    /// items are considered visible.
+    pub(crate) fn is_visible_from(&self, from: ModId, item: &Item) -> bool {
+        let Some(item) = self.pool.get_item(item) else {
+            return true;
+        };
+
+        let is_visible = |item_meta: &ItemMeta| {
+            let module = self.pool.module_item(item_meta.module);
+            let (common, _) = self.pool.module_item(from).ancestry(module);
+            item_meta.visibility.is_visible_inside(&common, module)
+        };
+
+        if let Some(entries) = self.inner.indexed.get(&item) {
+            return entries.iter().any(|entry| is_visible(&entry.item_meta));
+        }
+
+        match self.inner.meta.get(&(item, Hash::EMPTY)) {
+            Some(meta) if !meta.context => is_visible(&meta.item_meta),
+            _ => true,
+        }
+    }
+
+    /// Test if the item at the given path is an enum.
+    pub(crate) fn is_enum(&self, item: &Item) -> bool {
+        self.is_kind(
+            item,
+            |indexed| matches!(indexed, Indexed::Enum),
+            |kind| matches!(kind, meta::Kind::Enum { .. }),
+        )
+    }
+
+    /// Test if the item at the given path is an enum variant.
+    pub(crate) fn is_variant(&self, item: &Item) -> bool {
+        self.is_kind(
+            item,
+            |indexed| matches!(indexed, Indexed::Variant(..)),
+            |kind| matches!(kind, meta::Kind::Variant { .. }),
+        )
+    }
+
+    /// Test if the item at the given path is either indexed or built as the
+    /// expected kind of item.
+    fn is_kind(
+        &self,
+        item: &Item,
+        indexed: impl Fn(&Indexed) -> bool,
+        kind: impl Fn(&meta::Kind) -> bool,
+    ) -> bool {
+        let Some(item) = self.pool.get_item(item) else {
+            return false;
+        };
+
+        if let Some(meta) = self.inner.meta.get(&(item, Hash::EMPTY)) {
+            return kind(&meta.kind);
+        }
+
+        let Some(entries) = self.inner.indexed.get(&item) else {
+            return false;
+        };
+
+        entries.iter().any(|entry| indexed(&entry.indexed))
+    }
+
     /// Get the given import by name.
     #[tracing::instrument(skip(self, span, module))]
     pub(crate) fn import(
@@ -863,10 +1008,14 @@ impl<'a> Query<'a> {
         let mut count = 0usize;
 
         'outer: loop {
-            if count > IMPORT_RECURSION_LIMIT {
+            if count > self.options.import_recursion_limit {
                 return Err(compile::Error::new(
                     span,
-                    QueryErrorKind::ImportRecursionLimit { count, path },
+                    QueryErrorKind::ImportRecursionLimit {
+                        count,
+                        limit: self.options.import_recursion_limit,
+                        path,
+                    },
                 ));
             }
 
@@ -956,6 +1105,8 @@ impl<'a> Query<'a> {
             }
         };
 
+        self.insert_dependency(entry.item_meta.item, import.target);
+
         let meta = meta::Meta {
             context: false,
             hash: self.pool.item_type_hash(entry.item_meta.item),
@@ -976,6 +1127,33 @@ impl<'a> Query<'a> {
         entry: indexing::Entry,
         used: Used,
     ) -> compile::Result<meta::Meta> {
+        /// Convert AST generic parameters into meta generic parameters.
+        fn convert_generics(
+            ctx: ResolveContext<'_>,
+            generics: Option<&ast::AngleBracketed<ast::GenericParam, T![,]>>,
+        ) -> compile::Result<Box<[meta::GenericParam]>> {
+            let Some(generics) = generics else {
+                return Ok(Box::from([]));
+            };
+
+            let mut params = Vec::with_capacity(generics.len());
+
+            for (param, _) in generics {
+                let mut bounds = Vec::with_capacity(param.bounds.len());
+
+                for (path, _) in &param.bounds {
+                    bounds.push(path.resolve(ctx)?);
+                }
+
+                params.push(meta::GenericParam {
+                    name: param.name.resolve(ctx)?.into(),
+                    bounds: bounds.into(),
+                });
+            }
+
+            Ok(params.into())
+        }
+
         /// Convert AST fields into meta fields.
         fn convert_fields(
             ctx: ResolveContext<'_>,
@@ -985,14 +1163,41 @@ impl<'a> Query<'a> {
                 ast::Fields::Empty => meta::Fields::Empty,
                 ast::Fields::Unnamed(tuple) => meta::Fields::Unnamed(tuple.len()),
                 ast::Fields::Named(st) => {
-                    let mut fields = HashSet::new();
-
-                    for (ast::Field { name, .. }, _) in st {
-                        let name = name.resolve(ctx)?;
-                        fields.insert(name.into());
+                    let mut fields = Vec::new();
+                    let mut docs = HashMap::new();
+
+                    for (
+                        ast::Field {
+                            attributes, name, ..
+                        },
+                        _,
+                    ) in st
+                    {
+                        let name: Box<str> = name.resolve(ctx)?.into();
+                        let mut attrs = attrs::Attributes::new(attributes);
+                        let field_docs = Doc::collect_from(ctx, &mut attrs)?;
+
+                        if !field_docs.is_empty() {
+                            let mut lines = Vec::with_capacity(field_docs.len());
+
+                            for doc in field_docs {
+                                lines.push(doc.doc_string.resolve(ctx)?);
+                            }
+
+                            let mut field_docs = Docs::EMPTY;
+                            field_docs.set_docs(lines);
+                            docs.insert(name.clone(), field_docs);
+                        }
+
+                        if !fields.contains(&name) {
+                            fields.push(name);
+                        }
                     }
 
-                    meta::Fields::Named(meta::FieldsNamed { fields })
+                    meta::Fields::Named(meta::FieldsNamed {
+                        fields: fields.into(),
+                        docs,
+                    })
                 }
             })
         }
@@ -1030,8 +1235,11 @@ impl<'a> Query<'a> {
                     signature: meta::Signature {
                         #[cfg(feature = "doc")]
                         is_async: f.ast.async_token.is_some(),
-                        #[cfg(feature = "doc")]
                         args: Some(f.ast.args.len()),
+                        generics: convert_generics(
+                            resolve_context!(self),
+                            f.ast.generics.as_ref(),
+                        )?,
                         #[cfg(feature = "doc")]
                         return_type: None,
                         #[cfg(feature = "doc")]
@@ -1056,8 +1264,11 @@ impl<'a> Query<'a> {
                     signature: meta::Signature {
                         #[cfg(feature = "doc")]
                         is_async: f.ast.async_token.is_some(),
-                        #[cfg(feature = "doc")]
                         args: Some(f.ast.args.len()),
+                        generics: convert_generics(
+                            resolve_context!(self),
+                            f.ast.generics.as_ref(),
+                        )?,
                         #[cfg(feature = "doc")]
                         return_type: None,
                         #[cfg(feature = "doc")]
@@ -1104,7 +1315,7 @@ impl<'a> Query<'a> {
             }
             Indexed::Const(c) => {
                 let mut const_compiler = IrInterpreter {
-                    budget: IrBudget::new(1_000_000),
+                    budget: IrBudget::new(self.options.const_eval_budget),
                     scopes: Default::default(),
                     module: c.module,
                     item: item_meta.item,
@@ -1157,6 +1368,8 @@ impl<'a> Query<'a> {
                     });
                 }
 
+                self.insert_dependency(item_meta.item, import.entry.target);
+
                 meta::Kind::Import(import.entry)
             }
             Indexed::Module => meta::Kind::Module,
@@ -1248,34 +1461,41 @@ impl<'a> Query<'a> {
                 locations.push((oth.item_meta.location, oth.item().to_owned()));
             }
 
-            return Err(compile::Error::new(
-                span,
-                QueryErrorKind::AmbiguousItem {
-                    item: self.pool.item(cur.item_meta.item).to_owned(),
-                    locations: locations
-                        .into_iter()
-                        .map(|(loc, item)| (loc, self.pool.item(item).to_owned()))
-                        .collect(),
-                },
-            ));
+            return Err(self.ambiguous_item(span, cur.item_meta.item, locations));
         }
 
         if let Indexed::Import(indexing::Import { wildcard: true, .. }) = &cur.indexed {
-            return Err(compile::Error::new(
-                span,
-                QueryErrorKind::AmbiguousItem {
-                    item: self.pool.item(cur.item_meta.item).to_owned(),
-                    locations: locations
-                        .into_iter()
-                        .map(|(loc, item)| (loc, self.pool.item(item).to_owned()))
-                        .collect(),
-                },
-            ));
+            return Err(self.ambiguous_item(span, cur.item_meta.item, locations));
         }
 
         Ok(Some(cur))
     }
 
+    /// Construct an ambiguous item error.
+    ///
+    /// Locations are sorted by where they are declared and deduplicated, so
+    /// that the error is reported the same way regardless of indexing order.
+    fn ambiguous_item(
+        &self,
+        span: Span,
+        item: ItemId,
+        mut locations: Vec<(Location, ItemId)>,
+    ) -> compile::Error {
+        locations.sort_by_key(|(loc, _)| (loc.source_id, loc.span.start, loc.span.end));
+        locations.dedup_by_key(|(loc, _)| (loc.source_id, loc.span));
+
+        compile::Error::new(
+            span,
+            QueryErrorKind::AmbiguousItem {
+                item: self.pool.item(item).to_owned(),
+                locations: locations
+                    .into_iter()
+                    .map(|(loc, item)| (loc, self.pool.item(item).to_owned()))
+                    .collect(),
+            },
+        )
+    }
+
     /// Walk the names to find the first one that is contained in the unit.
     #[tracing::instrument(skip_all, fields(module = ?self.pool.module_item(module), base = ?self.pool.item(base)))]
     fn convert_initial_path(
@@ -1339,14 +1559,8 @@ impl<'a> Query<'a> {
         visibility: Visibility,
         chain: &mut Vec<ImportStep>,
     ) -> compile::Result<()> {
-        fn into_chain(chain: Vec<ImportStep>) -> Vec<Location> {
-            chain.into_iter().map(|c| c.location).collect()
-        }
-
-        let (common, tree) = self
-            .pool
-            .module_item(from)
-            .ancestry(self.pool.module_item(module));
+        let from_module = self.pool.module_item(from).to_owned();
+        let (common, tree) = from_module.ancestry(self.pool.module_item(module));
         let mut current_module = common.clone();
 
         // Check each module from the common ancestrly to the module.
@@ -1363,29 +1577,35 @@ impl<'a> Query<'a> {
                 )
             })?;
 
-            if !m.visibility.is_visible(&common, &current_module) {
+            if !m.visibility.is_visible_from_crate(&from_module, &current_module)
+                || !m.visibility.is_visible(&common, &current_module)
+            {
                 return Err(compile::Error::new(
                     span,
                     QueryErrorKind::NotVisibleMod {
-                        chain: into_chain(take(chain)),
+                        chain: take(chain),
                         location: m.location,
                         visibility: m.visibility,
                         item: current_module,
-                        from: self.pool.module_item(from).to_owned(),
+                        from: from_module,
                     },
                 ));
             }
         }
 
-        if !visibility.is_visible_inside(&common, self.pool.module_item(module)) {
+        let to_module = self.pool.module_item(module);
+
+        if !visibility.is_visible_from_crate(&from_module, to_module)
+            || !visibility.is_visible_inside(&common, to_module)
+        {
             return Err(compile::Error::new(
                 span,
                 QueryErrorKind::NotVisible {
-                    chain: into_chain(take(chain)),
+                    chain: take(chain),
                     location,
                     visibility,
                     item: self.pool.item(item).to_owned(),
-                    from: self.pool.module_item(from).to_owned(),
+                    from: from_module,
                 },
             ));
         }
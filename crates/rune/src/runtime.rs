@@ -23,6 +23,9 @@ pub use self::bytes::Bytes;
 mod call;
 pub use self::call::Call;
 
+mod cancellation;
+pub use self::cancellation::CancellationToken;
+
 mod const_value;
 pub use self::const_value::ConstValue;
 
@@ -30,6 +33,10 @@ pub mod debug;
 pub use self::debug::{DebugInfo, DebugInst};
 
 mod env;
+pub use self::env::ext;
+
+mod extensions;
+pub(crate) use self::extensions::Extensions;
 
 pub mod format;
 pub use self::format::{Format, FormatSpec};
@@ -72,9 +79,19 @@ mod label;
 pub use self::label::DebugLabel;
 pub(crate) use self::label::Label;
 
+#[cfg(feature = "native-hook")]
+mod native_hook;
+#[cfg(feature = "native-hook")]
+pub(crate) use self::native_hook::NativeHook;
+#[cfg(feature = "native-hook")]
+pub use self::native_hook::{NativeCall, NativeCallHook};
+
 mod object;
 pub use self::object::Object;
 
+mod overflow;
+pub use self::overflow::Overflow;
+
 mod panic;
 pub(crate) use self::panic::{BoxedPanic, Panic};
 
@@ -98,7 +115,7 @@ mod select;
 pub(crate) use self::select::Select;
 
 mod shared;
-pub use self::shared::{Mut, RawMut, RawRef, Ref, Shared, SharedPointerGuard};
+pub use self::shared::{Mut, RawMut, RawRef, Ref, Shared, SharedPointerGuard, Weak};
 
 mod stack;
 pub use self::stack::{Stack, StackError};
@@ -151,6 +168,9 @@ pub use self::vm::{CallFrame, Vm};
 mod vm_call;
 pub(crate) use self::vm_call::VmCall;
 
+mod vm_call_builder;
+pub use self::vm_call_builder::VmCallBuilder;
+
 mod vm_error;
 pub(crate) use self::vm_error::VmErrorKind;
 pub use self::vm_error::{try_result, TryFromResult, VmError, VmIntegerRepr, VmResult};
@@ -0,0 +1,34 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+
+use crate::no_std::sync::Arc;
+
+/// A token which can be used by the host to cooperatively cancel the
+/// execution of a virtual machine.
+///
+/// The token is cheap to clone, and all clones refer to the same cancellation
+/// state. A virtual machine which has been given a token through
+/// [`Vm::with_cancellation`][crate::runtime::Vm::with_cancellation] checks it
+/// at loop back-edges and await points, and aborts with an error for which
+/// [`VmError::is_cancelled`][crate::runtime::VmError::is_cancelled] returns
+/// `true` once it has been signaled.
+#[derive(Debug, Default, Clone)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    /// Construct a new token which has not been cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Signal cancellation to every virtual machine using this token.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Test if cancellation has been signaled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
+}
@@ -1,6 +1,7 @@
 //! Debug information for units.
 
 use core::fmt;
+use core::ops::Range;
 
 use crate::no_std::collections::HashMap;
 use crate::no_std::prelude::*;
@@ -22,6 +23,10 @@ pub struct DebugInfo {
     pub functions: HashMap<Hash, DebugSignature>,
     /// Reverse lookup of a function.
     pub functions_rev: HashMap<usize, Hash>,
+    /// The range of instruction pointers occupied by each function, sorted by
+    /// the start of the range.
+    #[serde(default)]
+    pub function_ranges: Vec<(Range<usize>, Hash)>,
 }
 
 impl DebugInfo {
@@ -36,6 +41,52 @@ impl DebugInfo {
         let signature = self.functions.get(&hash)?;
         Some((hash, signature))
     }
+
+    /// Get the range of instruction pointers occupied by the function with
+    /// the given hash.
+    pub fn function_range(&self, hash: Hash) -> Option<Range<usize>> {
+        let (range, _) = self.function_ranges.iter().find(|(_, h)| *h == hash)?;
+        Some(range.clone())
+    }
+
+    /// Record the range of instruction pointers occupied by a function,
+    /// keeping the ranges sorted.
+    pub(crate) fn insert_function_range(&mut self, hash: Hash, range: Range<usize>) {
+        let index = self
+            .function_ranges
+            .partition_point(|(r, _)| r.start <= range.start);
+        self.function_ranges.insert(index, (range, hash));
+    }
+
+    /// Get the function whose body contains the given instruction pointer.
+    ///
+    /// Unlike [`DebugInfo::function_at`], this doesn't require `ip` to be the
+    /// entry point of the function.
+    pub fn function_containing(&self, ip: usize) -> Option<(Hash, &DebugSignature)> {
+        let index = self
+            .function_ranges
+            .partition_point(|(range, _)| range.start <= ip);
+        let (range, hash) = self.function_ranges.get(index.checked_sub(1)?)?;
+
+        if !range.contains(&ip) {
+            return None;
+        }
+
+        let signature = self.functions.get(hash)?;
+        Some((*hash, signature))
+    }
+
+    /// Iterate over the source map of the function with the given hash,
+    /// producing every instruction pointer in the function in order together
+    /// with its debug information.
+    pub fn function_source_map(
+        &self,
+        hash: Hash,
+    ) -> impl Iterator<Item = (usize, &DebugInst)> + '_ {
+        self.function_range(hash)
+            .unwrap_or_default()
+            .filter_map(move |ip| Some((ip, self.instructions.get(&ip)?)))
+    }
 }
 
 /// Debug information for every instruction.
@@ -10,9 +10,15 @@
 #[cfg_attr(feature = "std", path = "env/std.rs")]
 mod no_std;
 
+use core::any::Any;
+
 use crate::no_std::sync::Arc;
 
-use crate::runtime::{RuntimeContext, Unit, VmErrorKind, VmResult};
+#[cfg(feature = "native-hook")]
+use crate::runtime::NativeHook;
+use crate::runtime::{
+    CancellationToken, Extensions, Overflow, RuntimeContext, Unit, VmErrorKind, VmResult,
+};
 
 /// Call the given closure with access to the checked environment.
 pub(crate) fn with<F, T>(c: F) -> VmResult<T>
@@ -20,7 +26,7 @@ where
     F: FnOnce(&Arc<RuntimeContext>, &Arc<Unit>) -> VmResult<T>,
 {
     let env = self::no_std::rune_env_get();
-    let Env { context, unit } = env;
+    let Env { context, unit, .. } = env;
 
     if context.is_null() || unit.is_null() {
         return VmResult::err(VmErrorKind::MissingInterfaceEnvironment);
@@ -32,18 +38,111 @@ where
     c(unsafe { &*context }, unsafe { &*unit })
 }
 
+/// Get the host extension of type `T` from the virtual machine which is
+/// currently executing.
+///
+/// This is intended to be used by native functions to access per-call host
+/// context, which has been set up through [`Vm::insert_ext`]. Returns `None` if
+/// no such value has been inserted or if this is called outside of a virtual
+/// machine.
+///
+/// [`Vm::insert_ext`]: crate::runtime::Vm::insert_ext
+///
+/// # Examples
+///
+/// ```
+/// use rune::runtime;
+///
+/// struct RequestId(u64);
+///
+/// fn request_id() -> Option<u64> {
+///     Some(runtime::ext::<RequestId>()?.0)
+/// }
+///
+/// assert_eq!(request_id(), None);
+/// ```
+pub fn ext<T>() -> Option<Arc<T>>
+where
+    T: Any + Send + Sync,
+{
+    let Env { ext, .. } = self::no_std::rune_env_get();
+
+    if ext.is_null() {
+        return None;
+    }
+
+    // Safety: extensions can only be registered through [Guard], which makes
+    // sure that they are live for the duration of the registration.
+    unsafe { &*ext }.get_shared::<T>()
+}
+
+/// Settings of the virtual machine which is currently executing.
+pub(crate) struct Inherited<'a> {
+    pub(crate) ext: Option<&'a Extensions>,
+    pub(crate) overflow: Option<Overflow>,
+    pub(crate) cancellation: Option<&'a CancellationToken>,
+    #[cfg(feature = "native-hook")]
+    pub(crate) native_hook: Option<&'a NativeHook>,
+}
+
+/// Call the given closure with the settings of the virtual machine which is
+/// currently executing, so that they can be inherited by virtual machines
+/// constructed from native code.
+pub(crate) fn with_inherited<F, T>(c: F) -> T
+where
+    F: FnOnce(Inherited<'_>) -> T,
+{
+    let env = self::no_std::rune_env_get();
+
+    // Safety: settings can only be registered through [Guard], which makes
+    // sure that they are live for the duration of the registration.
+    unsafe {
+        c(Inherited {
+            ext: env.ext.as_ref(),
+            overflow: env.overflow.as_ref().copied(),
+            cancellation: env.cancellation.as_ref().and_then(Option::as_ref),
+            #[cfg(feature = "native-hook")]
+            native_hook: env.native_hook.as_ref().and_then(Option::as_ref),
+        })
+    }
+}
+
 pub(crate) struct Guard {
     old: Env,
 }
 
 impl Guard {
-    /// Construct a new environment guard with the given context and unit.
+    /// Construct a new environment guard with the given context, unit,
+    /// extensions and settings. If `ext` is empty, the extensions of the
+    /// current environment are inherited.
     ///
     /// # Safety
     ///
     /// The returned guard must be dropped before the pointed to elements are.
-    pub(crate) fn new(context: *const Arc<RuntimeContext>, unit: *const Arc<Unit>) -> Guard {
-        let old = self::no_std::rune_env_replace(Env { context, unit });
+    pub(crate) fn new(
+        context: *const Arc<RuntimeContext>,
+        unit: *const Arc<Unit>,
+        ext: &Extensions,
+        overflow: *const Overflow,
+        cancellation: *const Option<CancellationToken>,
+        #[cfg(feature = "native-hook")] native_hook: *const Option<NativeHook>,
+    ) -> Guard {
+        let ext = if ext.is_empty() {
+            self::no_std::rune_env_get().ext
+        } else {
+            ext as *const _
+        };
+
+        let old = self::no_std::rune_env_replace(Env {
+            context,
+            unit,
+            ext,
+            overflow,
+            cancellation,
+            #[cfg(feature = "native-hook")]
+            native_hook,
+        });
+
         Guard { old }
     }
 }
@@ -58,6 +157,11 @@ impl Drop for Guard {
 struct Env {
     context: *const Arc<RuntimeContext>,
     unit: *const Arc<Unit>,
+    ext: *const Extensions,
+    overflow: *const Overflow,
+    cancellation: *const Option<CancellationToken>,
+    #[cfg(feature = "native-hook")]
+    native_hook: *const Option<NativeHook>,
 }
 
 impl Env {
@@ -66,6 +170,11 @@ impl Env {
         Self {
             context: core::ptr::null(),
             unit: core::ptr::null(),
+            ext: core::ptr::null(),
+            overflow: core::ptr::null(),
+            cancellation: core::ptr::null(),
+            #[cfg(feature = "native-hook")]
+            native_hook: core::ptr::null(),
         }
     }
 }
@@ -32,6 +32,11 @@ unsafe fn from_env(env: Env) -> RawEnv {
     RawEnv {
         context: env.context as *const _,
         unit: env.unit as *const _,
+        ext: env.ext as *const _,
+        overflow: env.overflow as *const _,
+        cancellation: env.cancellation as *const _,
+        #[cfg(feature = "native-hook")]
+        native_hook: env.native_hook as *const _,
     }
 }
 
@@ -39,5 +44,10 @@ unsafe fn from_raw_env(env: RawEnv) -> Env {
     Env {
         context: env.context as *const _,
         unit: env.unit as *const _,
+        ext: env.ext as *const _,
+        overflow: env.overflow as *const _,
+        cancellation: env.cancellation as *const _,
+        #[cfg(feature = "native-hook")]
+        native_hook: env.native_hook as *const _,
     }
 }
@@ -0,0 +1,77 @@
+use core::any::{Any, TypeId};
+use core::fmt;
+
+use crate::no_std::prelude::*;
+use crate::no_std::sync::Arc;
+
+/// Typed host values associated with a virtual machine, keyed by their type.
+///
+/// See [`Vm::insert_ext`][crate::runtime::Vm::insert_ext].
+#[derive(Default, Clone)]
+pub(crate) struct Extensions {
+    values: Vec<(TypeId, Arc<dyn Any + Send + Sync>)>,
+}
+
+impl Extensions {
+    /// Construct a new empty collection of extensions.
+    pub(crate) const fn new() -> Self {
+        Self { values: Vec::new() }
+    }
+
+    /// Test if there are no extensions.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Insert a value, replacing any existing value of the same type.
+    pub(crate) fn insert<T>(&mut self, value: T)
+    where
+        T: Any + Send + Sync,
+    {
+        let value = Arc::new(value);
+
+        for (id, existing) in &mut self.values {
+            if *id == TypeId::of::<T>() {
+                *existing = value;
+                return;
+            }
+        }
+
+        self.values.push((TypeId::of::<T>(), value));
+    }
+
+    /// Get a reference to the value of the given type.
+    pub(crate) fn get<T>(&self) -> Option<&T>
+    where
+        T: Any + Send + Sync,
+    {
+        self.find::<T>()?.downcast_ref()
+    }
+
+    /// Get a shared handle to the value of the given type.
+    pub(crate) fn get_shared<T>(&self) -> Option<Arc<T>>
+    where
+        T: Any + Send + Sync,
+    {
+        self.find::<T>()?.clone().downcast().ok()
+    }
+
+    fn find<T>(&self) -> Option<&Arc<dyn Any + Send + Sync>>
+    where
+        T: Any,
+    {
+        let (_, value) = self
+            .values
+            .iter()
+            .find(|(id, _)| *id == TypeId::of::<T>())?;
+        Some(value)
+    }
+}
+
+impl fmt::Debug for Extensions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list()
+            .entries(self.values.iter().map(|(id, _)| id))
+            .finish()
+    }
+}
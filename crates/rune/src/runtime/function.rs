@@ -466,7 +466,7 @@ where
                 let arg_count = args.count();
                 let mut stack = Stack::with_capacity(arg_count);
                 vm_try!(args.into_stack(&mut stack));
-                vm_try!((handler.handler)(&mut stack, arg_count));
+                vm_try!(handler.call(&mut stack, arg_count));
                 vm_try!(stack.pop())
             }
             Inner::FnOffset(fn_offset) => vm_try!(fn_offset.call(args, ())),
@@ -529,7 +529,7 @@ where
     pub(crate) fn call_with_vm(&self, vm: &mut Vm, args: usize) -> VmResult<Option<VmHalt>> {
         let reason = match &self.inner {
             Inner::FnHandler(handler) => {
-                vm_try!((handler.handler)(vm.stack_mut(), args));
+                vm_try!(vm.call_native(&*handler.handler, handler.hash, args));
                 None
             }
             Inner::FnOffset(fn_offset) => {
@@ -778,6 +778,34 @@ struct FnHandler {
     hash: Hash,
 }
 
+impl FnHandler {
+    /// Call the handler outside of a virtual machine, notifying the native call
+    /// hook of the virtual machine which is currently executing, if any.
+    fn call(&self, stack: &mut Stack, args: usize) -> VmResult<()> {
+        #[cfg(feature = "native-hook")]
+        {
+            crate::runtime::env::with_inherited(|inherited| {
+                let guard = inherited
+                    .native_hook
+                    .map(|hook| hook.enter(self.hash, args));
+
+                let result = (self.handler)(stack, args);
+
+                if let Some(guard) = guard {
+                    guard.exit();
+                }
+
+                result
+            })
+        }
+
+        #[cfg(not(feature = "native-hook"))]
+        {
+            (self.handler)(stack, args)
+        }
+    }
+}
+
 impl fmt::Debug for FnHandler {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "FnHandler")
@@ -809,6 +837,7 @@ impl FnOffset {
         vm_try!(check_args(args.count(), self.args));
 
         let mut vm = Vm::new(self.context.clone(), self.unit.clone());
+        vm.inherit_env();
 
         vm.set_ip(self.offset);
         vm_try!(args.into_stack(vm.stack_mut()));
@@ -838,9 +867,10 @@ impl FnOffset {
 
         let mut new_stack = vm_try!(vm.stack_mut().drain(args)).collect::<Stack>();
         vm_try!(extra.into_stack(&mut new_stack));
-        let mut vm = Vm::with_stack(self.context.clone(), self.unit.clone(), new_stack);
-        vm.set_ip(self.offset);
-        VmResult::Ok(Some(VmCall::new(self.call, vm)))
+        let mut new_vm = Vm::with_stack(self.context.clone(), self.unit.clone(), new_stack);
+        new_vm.inherit(vm);
+        new_vm.set_ip(self.offset);
+        VmResult::Ok(Some(VmCall::new(self.call, new_vm)))
     }
 }
 
@@ -3,6 +3,7 @@ use core::fmt;
 use core::iter;
 use core::ops;
 
+use crate::no_std::borrow::Cow;
 use crate::no_std::prelude::*;
 use crate::no_std::vec;
 
@@ -88,6 +89,15 @@ impl Iterator {
     ///
     /// The name is only intended to identify the iterator in case of errors.
     pub fn from<T>(name: &'static str, iter: T) -> Self
+    where
+        T: IteratorTrait,
+    {
+        Self::from_named(Cow::Borrowed(name), iter)
+    }
+
+    /// Construct a new owning iterator with a name which isn't known
+    /// statically.
+    pub(crate) fn from_named<T>(name: Cow<'static, str>, iter: T) -> Self
     where
         T: IteratorTrait,
     {
@@ -105,7 +115,10 @@ impl Iterator {
         T: DoubleEndedIteratorTrait,
     {
         Self {
-            iter: IterRepr::DoubleEndedIterator(Box::new(IteratorObj { name, iter })),
+            iter: IterRepr::DoubleEndedIterator(Box::new(IteratorObj {
+                name: Cow::Borrowed(name),
+                iter,
+            })),
         }
     }
 
@@ -739,7 +752,7 @@ struct IteratorObj<T>
 where
     T: ?Sized,
 {
-    name: &'static str,
+    name: Cow<'static, str>,
     iter: T,
 }
 
@@ -9,11 +9,19 @@ use crate::no_std::vec;
 use serde::{de, ser};
 
 use crate::runtime::{
-    Bytes, FromValue, FullTypeOf, MaybeTypeOf, Object, Shared, StaticString, ToValue, Tuple,
-    TypeInfo, Value, Variant, VariantData, VariantRtti, Vec, VmErrorKind, VmResult,
+    Bytes, EnvProtocolCaller, FromValue, FullTypeOf, MaybeTypeOf, Object, Protocol, ProtocolCaller,
+    Shared, StaticString, ToValue, Tuple, TypeInfo, Value, Variant, VariantData, VariantRtti, Vec,
+    VmErrorKind, VmResult,
 };
+use crate::Hash;
 
 /// A key that can be used as an anonymous object key.
+///
+/// Keys are hashed and compared structurally. External types are identified
+/// by their type and the hash produced by the [`Protocol::HASH`] protocol.
+/// Values which have no key representation, like floats, functions and
+/// external types which don't implement the protocol, cause
+/// [`VmErrorKind::KeyNotSupported`] to be raised.
 #[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum Key {
     /// A constant unit.
@@ -38,6 +46,8 @@ pub enum Key {
     Option(Option<Box<Key>>),
     /// A variant.
     Variant(VariantKey),
+    /// An external type which implements [`Protocol::HASH`].
+    Any(AnyKey),
 }
 
 impl Key {
@@ -94,6 +104,7 @@ impl Key {
                     data,
                 })
             }
+            Value::Any(..) => Self::Any(vm_try!(AnyKey::from_value(value))),
             value => {
                 return VmResult::err(VmErrorKind::KeyNotSupported {
                     actual: vm_try!(value.type_info()),
@@ -164,6 +175,7 @@ impl Key {
                     data,
                 }))
             }
+            Self::Any(any) => any.value,
         };
 
         fn tuple_into_value(data: Box<[Key]>) -> Tuple {
@@ -209,6 +221,7 @@ impl Key {
             Self::Tuple(..) => TypeInfo::StaticType(crate::runtime::TUPLE_TYPE),
             Self::Option(..) => TypeInfo::StaticType(crate::runtime::OPTION_TYPE),
             Self::Variant(variant) => TypeInfo::Variant(variant.rtti.clone()),
+            Self::Any(any) => any.type_info.clone(),
         }
     }
 }
@@ -227,6 +240,7 @@ impl fmt::Debug for Key {
             Key::Tuple(tuple) => write!(f, "{:?}", tuple),
             Key::Option(opt) => write!(f, "{:?}", opt),
             Key::Variant(variant) => write!(f, "{:?}", variant),
+            Key::Any(any) => write!(f, "{:?}", any),
         }
     }
 }
@@ -298,6 +312,7 @@ impl ser::Serialize for Key {
             }
             Self::Option(option) => <Option<Box<Key>>>::serialize(option, serializer),
             Self::Variant(..) => Err(ser::Error::custom("cannot serialize variants")),
+            Self::Any(..) => Err(ser::Error::custom("cannot serialize external types")),
         }
     }
 }
@@ -571,3 +586,76 @@ pub enum VariantKeyData {
     /// An struct variant with a specific type hash.
     Struct(Box<[(Box<str>, Key)]>),
 }
+
+/// An external value which has been converted into a key through the
+/// [`Protocol::HASH`] protocol.
+///
+/// The hash is resolved when the key is constructed, and the key is compared,
+/// hashed and ordered by the type and hash of the value alone. Values of the
+/// same type which produce the same hash are therefore the same key.
+#[derive(Clone)]
+pub struct AnyKey {
+    type_hash: Hash,
+    type_info: TypeInfo,
+    hash: i64,
+    value: Value,
+}
+
+impl AnyKey {
+    fn from_value(value: &Value) -> VmResult<Self> {
+        let type_hash = vm_try!(value.type_hash());
+
+        let supported = vm_try!(crate::runtime::env::with(|context, unit| {
+            let hash = Hash::associated_function(type_hash, Protocol::HASH);
+            VmResult::Ok(context.function(hash).is_some() || unit.function(hash).is_some())
+        }));
+
+        if !supported {
+            return VmResult::err(VmErrorKind::KeyNotSupported {
+                actual: vm_try!(value.type_info()),
+            });
+        }
+
+        let hash = vm_try!(EnvProtocolCaller.call_protocol_fn(Protocol::HASH, value.clone(), ()));
+
+        VmResult::Ok(Self {
+            type_hash,
+            type_info: vm_try!(value.type_info()),
+            hash: vm_try!(hash.into_integer()),
+            value: value.clone(),
+        })
+    }
+}
+
+impl cmp::PartialEq for AnyKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_hash == other.type_hash && self.hash == other.hash
+    }
+}
+
+impl cmp::Eq for AnyKey {}
+
+impl hash::Hash for AnyKey {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.type_hash.hash(state);
+        self.hash.hash(state);
+    }
+}
+
+impl cmp::PartialOrd for AnyKey {
+    fn partial_cmp(&self, other: &Self) -> Option<cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl cmp::Ord for AnyKey {
+    fn cmp(&self, other: &Self) -> cmp::Ordering {
+        (self.type_hash, self.hash).cmp(&(other.type_hash, other.hash))
+    }
+}
+
+impl fmt::Debug for AnyKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.value)
+    }
+}
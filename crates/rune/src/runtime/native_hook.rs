@@ -0,0 +1,88 @@
+use core::fmt;
+use core::time::Duration;
+
+use std::time::Instant;
+
+use crate::no_std::sync::Arc;
+use crate::Hash;
+
+/// Information on a single native function call, as passed to a
+/// [`NativeCallHook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct NativeCall {
+    /// The hash of the native function being called.
+    pub hash: Hash,
+    /// The number of arguments passed to the function, including the instance
+    /// for instance functions.
+    pub args: usize,
+}
+
+/// A hook which is notified on entry to and exit from every native function
+/// called by a virtual machine.
+///
+/// Unlike the instruction [budget][crate::runtime::budget] this is
+/// coarse-grained, so it's suitable for logging and measuring how much time is
+/// spent in host code.
+///
+/// Hooks are installed with
+/// [`Vm::with_native_hook`][crate::runtime::Vm::with_native_hook], and are only
+/// available when the `native-hook` feature is enabled.
+pub trait NativeCallHook: Send + Sync {
+    /// Called right before the native function is invoked.
+    fn enter(&self, call: NativeCall) {
+        let _ = call;
+    }
+
+    /// Called once the native function has returned, regardless of whether it
+    /// errored or not. `elapsed` is the time spent inside of the function.
+    fn exit(&self, call: NativeCall, elapsed: Duration) {
+        let _ = (call, elapsed);
+    }
+}
+
+/// A native call hook installed in a virtual machine.
+#[derive(Clone)]
+pub(crate) struct NativeHook {
+    hook: Arc<dyn NativeCallHook>,
+}
+
+impl NativeHook {
+    pub(crate) fn new(hook: Arc<dyn NativeCallHook>) -> Self {
+        Self { hook }
+    }
+
+    /// Notify the hook that a native function is being entered.
+    #[inline]
+    pub(crate) fn enter(&self, hash: Hash, args: usize) -> NativeCallGuard<'_> {
+        let call = NativeCall { hash, args };
+        self.hook.enter(call);
+
+        NativeCallGuard {
+            hook: &*self.hook,
+            call,
+            start: Instant::now(),
+        }
+    }
+}
+
+impl fmt::Debug for NativeHook {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NativeHook").finish_non_exhaustive()
+    }
+}
+
+/// Guard for an ongoing native call.
+pub(crate) struct NativeCallGuard<'a> {
+    hook: &'a dyn NativeCallHook,
+    call: NativeCall,
+    start: Instant,
+}
+
+impl NativeCallGuard<'_> {
+    /// Notify the hook that the native function has returned.
+    #[inline]
+    pub(crate) fn exit(self) {
+        self.hook.exit(self.call, self.start.elapsed());
+    }
+}
@@ -10,6 +10,7 @@ use crate::no_std::prelude::*;
 use crate as rune;
 use crate::compile::{ItemBuf, Named};
 use crate::module::InstallWith;
+use crate::runtime::value::DeepClone;
 use crate::runtime::{
     FromValue, Iterator, Mut, RawMut, RawRef, RawStr, Ref, ToValue, UnsafeFromValue, Value, Vm,
     VmResult,
@@ -264,6 +265,17 @@ impl Object {
         map_ptr_eq(vm, &a.inner, &b.inner)
     }
 
+    /// Deep clone the object, deep cloning each of its values.
+    pub(crate) fn deep_clone(&self, cx: &mut DeepClone) -> VmResult<Self> {
+        let mut object = Self::with_capacity(self.len());
+
+        for (key, value) in self.iter() {
+            object.insert(key.clone(), vm_try!(value.deep_clone_with(cx)));
+        }
+
+        VmResult::Ok(object)
+    }
+
     /// Debug implementation for a struct. This assumes that all fields
     /// corresponds to identifiers.
     pub(crate) fn debug_struct<'a>(&'a self, item: &'a ItemBuf) -> DebugStruct<'a> {
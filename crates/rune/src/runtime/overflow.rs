@@ -0,0 +1,44 @@
+/// How integer arithmetic which overflows is handled by a virtual machine.
+///
+/// This applies to `+`, `-` and `*` (and their assign variants) on integers.
+/// It is configured through [`Vm::set_overflow`][crate::runtime::Vm::set_overflow].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Overflow {
+    /// Raise a virtual machine error when an operation overflows.
+    #[default]
+    Error,
+    /// Wrap around at the boundary of the integer type.
+    Wrap,
+    /// Saturate at the boundary of the integer type.
+    Saturate,
+}
+
+impl Overflow {
+    /// Integer addition under this overflow behavior.
+    pub(crate) fn add(self) -> fn(i64, i64) -> Option<i64> {
+        match self {
+            Overflow::Error => i64::checked_add,
+            Overflow::Wrap => |a, b| Some(a.wrapping_add(b)),
+            Overflow::Saturate => |a, b| Some(a.saturating_add(b)),
+        }
+    }
+
+    /// Integer subtraction under this overflow behavior.
+    pub(crate) fn sub(self) -> fn(i64, i64) -> Option<i64> {
+        match self {
+            Overflow::Error => i64::checked_sub,
+            Overflow::Wrap => |a, b| Some(a.wrapping_sub(b)),
+            Overflow::Saturate => |a, b| Some(a.saturating_sub(b)),
+        }
+    }
+
+    /// Integer multiplication under this overflow behavior.
+    pub(crate) fn mul(self) -> fn(i64, i64) -> Option<i64> {
+        match self {
+            Overflow::Error => i64::checked_mul,
+            Overflow::Wrap => |a, b| Some(a.wrapping_mul(b)),
+            Overflow::Saturate => |a, b| Some(a.saturating_mul(b)),
+        }
+    }
+}
@@ -26,6 +26,7 @@ impl<T> Shared<T> {
         let inner = Box::leak(Box::new(SharedBox {
             access: Access::new(false),
             count: Cell::new(1),
+            weak: Cell::new(1),
             data: data.into(),
         }));
 
@@ -40,6 +41,32 @@ impl<T> Shared<T> {
         SharedDebug { shared: self }
     }
 
+    /// Construct a [Weak] reference to the shared value, which doesn't keep
+    /// the value alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Shared;
+    ///
+    /// let a = Shared::new(42);
+    /// let weak = a.downgrade();
+    ///
+    /// assert_eq!(*weak.upgrade().unwrap().borrow_ref().unwrap(), 42);
+    ///
+    /// drop(a);
+    /// assert!(weak.upgrade().is_none());
+    /// ```
+    pub fn downgrade(&self) -> Weak<T> {
+        // Safety: by virtue of holding onto a shared we can safely access
+        // `inner` because it must outlive any `Shared` instances.
+        unsafe {
+            SharedBox::inc_weak(self.inner.as_ptr());
+        }
+
+        Weak { inner: self.inner }
+    }
+
     /// Test if the value is sharable.
     ///
     /// # Examples
@@ -443,6 +470,7 @@ impl Shared<AnyObj> {
         let inner = ptr::NonNull::from(Box::leak(Box::new(SharedBox {
             access: Access::new(true),
             count: Cell::new(2),
+            weak: Cell::new(1),
             data: any.into(),
         })));
 
@@ -730,6 +758,7 @@ where
 
             debug.field("access", &inner.access);
             debug.field("count", &inner.count.get());
+            debug.field("weak", &inner.weak.get());
 
             if !inner.access.is_shared() {
                 debug.field("data", &any::type_name::<T>());
@@ -742,6 +771,62 @@ where
     }
 }
 
+/// A weak reference to a [Shared] value, constructed through
+/// [Shared::downgrade].
+///
+/// A weak reference doesn't keep the value alive, which makes it useful to
+/// break reference cycles. Once every [Shared] reference to the value has been
+/// dropped the value itself is dropped, and [Weak::upgrade] returns `None`
+/// from then on. The allocation backing the value is only freed once all weak
+/// references have been dropped as well.
+pub struct Weak<T: ?Sized> {
+    inner: ptr::NonNull<SharedBox<T>>,
+}
+
+impl<T: ?Sized> Weak<T> {
+    /// Try to upgrade the weak reference into a [Shared] value, returning
+    /// `None` if the value has already been dropped.
+    pub fn upgrade(&self) -> Option<Shared<T>> {
+        // Safety: the allocation of the shared box is kept alive for as long
+        // as there are weak references to it.
+        unsafe {
+            let inner = self.inner.as_ref();
+
+            if inner.count.get() == 0 {
+                return None;
+            }
+
+            SharedBox::inc(self.inner.as_ptr());
+        }
+
+        Some(Shared { inner: self.inner })
+    }
+}
+
+impl<T: ?Sized> Clone for Weak<T> {
+    fn clone(&self) -> Self {
+        unsafe {
+            SharedBox::inc_weak(self.inner.as_ptr());
+        }
+
+        Self { inner: self.inner }
+    }
+}
+
+impl<T: ?Sized> Drop for Weak<T> {
+    fn drop(&mut self) {
+        unsafe {
+            SharedBox::dec_weak(self.inner.as_ptr());
+        }
+    }
+}
+
+impl<T: ?Sized> fmt::Debug for Weak<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "(Weak)")
+    }
+}
+
 /// The boxed internals of [Shared].
 #[repr(C)]
 struct SharedBox<T: ?Sized> {
@@ -749,6 +834,9 @@ struct SharedBox<T: ?Sized> {
     access: Access,
     /// The number of strong references to the shared data.
     count: Cell<usize>,
+    /// The number of weak references to the shared data, plus one which is
+    /// collectively held by all strong references.
+    weak: Cell<usize>,
     /// The value being held. Guarded by the `access` field to determine if it
     /// can be access shared or exclusively.
     data: UnsafeCell<T>,
@@ -786,26 +874,63 @@ impl<T: ?Sized> SharedBox<T> {
             return false;
         }
 
-        let this = Box::from_raw(this);
-
-        if this.access.is_taken() {
-            // NB: This prevents the inner `T` from being dropped in case it
-            // has already been taken (as indicated by `is_taken`).
-            //
-            // If it has been taken, the shared box contains invalid memory.
-            drop(transmute::<_, Box<SharedBox<ManuallyDrop<T>>>>(this));
-        } else {
+        if !(*this).access.is_taken() {
             // NB: At the point of the final drop, no on else should be using
             // this.
             debug_assert!(
-                this.access.is_exclusive(),
+                (*this).access.is_exclusive(),
                 "expected exclusive, but was: {:?}",
-                this.access
+                (*this).access
             );
+
+            // NB: This is skipped in case the data has already been taken (as
+            // indicated by `is_taken`), in which case the shared box contains
+            // invalid memory.
+            ptr::drop_in_place((*this).data.get());
         }
 
+        // Release the weak reference collectively held by strong references,
+        // which frees the box unless there are weak references left.
+        Self::dec_weak(this);
         true
     }
+
+    /// Increment the weak reference count of the inner value.
+    unsafe fn inc_weak(this: *const Self) {
+        let weak = (*this).weak.get();
+
+        if weak == 0 || weak == usize::max_value() {
+            crate::no_std::abort();
+        }
+
+        (*this).weak.set(weak + 1);
+    }
+
+    /// Decrement the weak reference count in inner, and free the box once it
+    /// has reached zero.
+    ///
+    /// # Safety
+    ///
+    /// ProtocolCaller needs to ensure that `this` is a valid pointer.
+    unsafe fn dec_weak(this: *mut Self) {
+        let weak = (*this).weak.get();
+
+        if weak == 0 {
+            crate::no_std::abort();
+        }
+
+        let weak = weak - 1;
+        (*this).weak.set(weak);
+
+        if weak != 0 {
+            return;
+        }
+
+        // NB: The data has already been dropped or taken at this point, so
+        // only the box itself is freed.
+        let this = Box::from_raw(this);
+        drop(transmute::<_, Box<SharedBox<ManuallyDrop<T>>>>(this));
+    }
 }
 
 type DropFn = unsafe fn(*const ());
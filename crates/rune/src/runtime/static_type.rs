@@ -139,6 +139,25 @@ impl_static_type!(impl<T> vec::Vec<T> => VEC_TYPE);
 impl_static_type!([rt::Value] => VEC_TYPE);
 impl_static_type!(impl<T> rt::VecTuple<T> => VEC_TYPE);
 
+impl<T, const N: usize> rt::TypeOf for [T; N] {
+    #[inline]
+    fn type_hash() -> Hash {
+        VEC_TYPE.hash
+    }
+
+    #[inline]
+    fn type_info() -> TypeInfo {
+        TypeInfo::StaticType(VEC_TYPE)
+    }
+}
+
+impl<T, const N: usize> rt::MaybeTypeOf for [T; N] {
+    #[inline]
+    fn maybe_type_of() -> Option<rt::FullTypeOf> {
+        Some(<Self as rt::TypeOf>::type_of())
+    }
+}
+
 /// The specialized type information for an anonymous tuple type.
 pub static TUPLE_TYPE: &StaticType = &StaticType {
     name: RawStr::from_str("Tuple"),
@@ -6,6 +6,7 @@ use crate::no_std::prelude::*;
 
 use crate::compile::Named;
 use crate::module::InstallWith;
+use crate::runtime::value::DeepClone;
 use crate::runtime::{
     ConstValue, FromValue, Mut, RawStr, Ref, ToValue, Value, Vm, VmErrorKind, VmResult, TUPLE_TYPE,
 };
@@ -77,6 +78,17 @@ impl Tuple {
 
         VmResult::Ok(true)
     }
+
+    /// Deep clone the tuple, deep cloning each of its values.
+    pub(crate) fn deep_clone(&self, cx: &mut DeepClone) -> VmResult<Self> {
+        let mut values = Vec::with_capacity(self.len());
+
+        for value in self.iter() {
+            values.push(vm_try!(value.deep_clone_with(cx)));
+        }
+
+        VmResult::Ok(Self::from(values))
+    }
 }
 
 impl fmt::Debug for Tuple {
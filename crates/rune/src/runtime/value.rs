@@ -302,6 +302,33 @@ pub enum Value {
     Any(Shared<AnyObj>),
 }
 
+/// The containers which are currently being deep cloned, used to detect values
+/// which contain themselves.
+#[derive(Default)]
+pub(crate) struct DeepClone {
+    parents: vec::Vec<*const ()>,
+}
+
+impl DeepClone {
+    /// Deep clone the contents of the given container using `f`, erroring if
+    /// the container is already being cloned.
+    pub(crate) fn container<T, O, F>(&mut self, container: &T, f: F) -> VmResult<O>
+    where
+        F: FnOnce(&T, &mut Self) -> VmResult<O>,
+    {
+        let ptr = container as *const T as *const ();
+
+        if self.parents.contains(&ptr) {
+            return err(VmErrorKind::DeepCloneCycle);
+        }
+
+        self.parents.push(ptr);
+        let result = f(container, self);
+        self.parents.pop();
+        result
+    }
+}
+
 impl Value {
     /// Format the value using the [Protocol::STRING_DISPLAY] protocol.
     ///
@@ -657,6 +684,167 @@ impl Value {
         })
     }
 
+    /// Deep clone the value, giving it value semantics.
+    ///
+    /// Containers such as vectors, tuples, objects and structs are recursively
+    /// copied, so that mutating the clone leaves the original unchanged.
+    /// External types are cloned using the [Protocol::CLONE] protocol, and
+    /// cloning any value which doesn't support it results in an error. The
+    /// same goes for values which cannot be cloned, like futures, generators
+    /// and iterators.
+    ///
+    /// Functions are immutable, so they are shared rather than copied.
+    ///
+    /// You must use [Vm::with] to specify which virtual machine this function
+    /// is called inside.
+    pub fn deep_clone(&self) -> VmResult<Self> {
+        self.deep_clone_with(&mut DeepClone::default())
+    }
+
+    /// Deep clone the value, keeping track of the containers which are
+    /// currently being cloned in `cx`.
+    pub(crate) fn deep_clone_with(&self, cx: &mut DeepClone) -> VmResult<Self> {
+        VmResult::Ok(match self {
+            Self::Unit => Self::Unit,
+            Self::Bool(value) => Self::Bool(*value),
+            Self::Byte(value) => Self::Byte(*value),
+            Self::Char(value) => Self::Char(*value),
+            Self::Integer(value) => Self::Integer(*value),
+            Self::Float(value) => Self::Float(*value),
+            Self::Type(value) => Self::Type(*value),
+            Self::StaticString(value) => Self::StaticString(value.clone()),
+            Self::String(value) => Self::String(Shared::new(vm_try!(value.borrow_ref()).clone())),
+            Self::Bytes(value) => Self::Bytes(Shared::new(vm_try!(value.borrow_ref()).clone())),
+            Self::Vec(value) => {
+                let vec = vm_try!(value.borrow_ref());
+                let vec = vm_try!(cx.container(&*vec, Vec::deep_clone));
+                Self::Vec(Shared::new(vec))
+            }
+            Self::Tuple(value) => {
+                let tuple = vm_try!(value.borrow_ref());
+                let tuple = vm_try!(cx.container(&*tuple, Tuple::deep_clone));
+                Self::Tuple(Shared::new(tuple))
+            }
+            Self::Object(value) => {
+                let object = vm_try!(value.borrow_ref());
+                let object = vm_try!(cx.container(&*object, Object::deep_clone));
+                Self::Object(Shared::new(object))
+            }
+            Self::Range(value) => {
+                let range = vm_try!(value.borrow_ref());
+
+                let range = vm_try!(cx.container(&*range, |range, cx| {
+                    let start = match &range.start {
+                        Some(start) => Some(vm_try!(start.deep_clone_with(cx))),
+                        None => None,
+                    };
+
+                    let end = match &range.end {
+                        Some(end) => Some(vm_try!(end.deep_clone_with(cx))),
+                        None => None,
+                    };
+
+                    VmResult::Ok(Range::new(start, end, range.limits))
+                }));
+
+                Self::Range(Shared::new(range))
+            }
+            Self::GeneratorState(value) => {
+                let state = vm_try!(value.borrow_ref());
+
+                let state = vm_try!(cx.container(&*state, |state, cx| {
+                    VmResult::Ok(match state {
+                        GeneratorState::Yielded(value) => {
+                            GeneratorState::Yielded(vm_try!(value.deep_clone_with(cx)))
+                        }
+                        GeneratorState::Complete(value) => {
+                            GeneratorState::Complete(vm_try!(value.deep_clone_with(cx)))
+                        }
+                    })
+                }));
+
+                Self::GeneratorState(Shared::new(state))
+            }
+            Self::Option(value) => {
+                let option = vm_try!(value.borrow_ref());
+
+                let option = vm_try!(cx.container(&*option, |option, cx| {
+                    VmResult::Ok(match option {
+                        Some(value) => Some(vm_try!(value.deep_clone_with(cx))),
+                        None => None,
+                    })
+                }));
+
+                Self::Option(Shared::new(option))
+            }
+            Self::Result(value) => {
+                let result = vm_try!(value.borrow_ref());
+
+                let result = vm_try!(cx.container(&*result, |result, cx| {
+                    VmResult::Ok(match result {
+                        Ok(value) => Ok(vm_try!(value.deep_clone_with(cx))),
+                        Err(value) => Err(vm_try!(value.deep_clone_with(cx))),
+                    })
+                }));
+
+                Self::Result(Shared::new(result))
+            }
+            Self::UnitStruct(value) => {
+                let value = vm_try!(value.borrow_ref());
+
+                Self::UnitStruct(Shared::new(UnitStruct {
+                    rtti: value.rtti.clone(),
+                }))
+            }
+            Self::TupleStruct(value) => {
+                let value = vm_try!(value.borrow_ref());
+
+                Self::TupleStruct(Shared::new(TupleStruct {
+                    rtti: value.rtti.clone(),
+                    data: vm_try!(cx.container(&value.data, Tuple::deep_clone)),
+                }))
+            }
+            Self::Struct(value) => {
+                let value = vm_try!(value.borrow_ref());
+
+                Self::Struct(Shared::new(Struct {
+                    rtti: value.rtti.clone(),
+                    data: vm_try!(cx.container(&value.data, Object::deep_clone)),
+                }))
+            }
+            Self::Variant(value) => {
+                let variant = vm_try!(value.borrow_ref());
+                let variant = vm_try!(cx.container(&*variant, Variant::deep_clone));
+                Self::Variant(Shared::new(variant))
+            }
+            Self::Function(value) => Self::Function(value.clone()),
+            Self::Format(value) => Self::Format(Box::new(Format {
+                value: vm_try!(value.value.deep_clone_with(cx)),
+                spec: value.spec,
+            })),
+            Self::Any(..) => {
+                let hash = Hash::associated_function(vm_try!(self.type_hash()), Protocol::CLONE);
+
+                let supported = vm_try!(crate::runtime::env::with(|context, unit| {
+                    VmResult::Ok(context.function(hash).is_some() || unit.function(hash).is_some())
+                }));
+
+                if !supported {
+                    return err(VmErrorKind::UnsupportedDeepClone {
+                        actual: vm_try!(self.type_info()),
+                    });
+                }
+
+                vm_try!(EnvProtocolCaller.call_protocol_fn(Protocol::CLONE, self.clone(), ()))
+            }
+            Self::Future(..) | Self::Stream(..) | Self::Generator(..) | Self::Iterator(..) => {
+                return err(VmErrorKind::UnsupportedDeepClone {
+                    actual: vm_try!(self.type_info()),
+                });
+            }
+        })
+    }
+
     /// Try to coerce value into a unit.
     #[inline]
     pub fn into_unit(self) -> VmResult<()> {
@@ -1,6 +1,7 @@
 use core::fmt;
 
 use crate::no_std::sync::Arc;
+use crate::runtime::value::DeepClone;
 use crate::runtime::{Object, Tuple, TypeInfo, VariantRtti, Vm, VmResult};
 
 /// The variant of a type.
@@ -72,6 +73,20 @@ impl Variant {
             _ => VmResult::Ok(false),
         }
     }
+
+    /// Deep clone the variant, deep cloning any data associated with it.
+    pub(crate) fn deep_clone(&self, cx: &mut DeepClone) -> VmResult<Self> {
+        let data = match &self.data {
+            VariantData::Unit => VariantData::Unit,
+            VariantData::Tuple(tuple) => VariantData::Tuple(vm_try!(tuple.deep_clone(cx))),
+            VariantData::Struct(object) => VariantData::Struct(vm_try!(object.deep_clone(cx))),
+        };
+
+        VmResult::Ok(Self {
+            rtti: self.rtti.clone(),
+            data,
+        })
+    }
 }
 
 /// The data of the variant.
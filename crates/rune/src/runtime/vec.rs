@@ -8,6 +8,7 @@ use crate::no_std::vec;
 
 use crate::compile::Named;
 use crate::module::InstallWith;
+use crate::runtime::value::DeepClone;
 use crate::runtime::{
     FromValue, Iterator, Mut, RawMut, RawRef, RawStr, Ref, Shared, ToValue, UnsafeFromValue, Value,
     Vm, VmErrorKind, VmResult,
@@ -183,6 +184,17 @@ impl Vec {
 
         VmResult::Ok(true)
     }
+
+    /// Deep clone the vector, deep cloning each of its values.
+    pub(crate) fn deep_clone(&self, cx: &mut DeepClone) -> VmResult<Self> {
+        let mut vec = Self::with_capacity(self.len());
+
+        for value in self.iter() {
+            vec.push(vm_try!(value.deep_clone_with(cx)));
+        }
+
+        VmResult::Ok(vec)
+    }
 }
 
 impl Named for Vec {
@@ -349,3 +361,35 @@ where
         VmResult::Ok(Value::from(Shared::new(Vec::from(vec))))
     }
 }
+
+/// Slices are copied into a dynamic vector.
+impl<T> ToValue for &[T]
+where
+    T: ToValue + Clone,
+{
+    fn to_value(self) -> VmResult<Value> {
+        let mut vec = vec::Vec::with_capacity(self.len());
+
+        for value in self {
+            vec.push(vm_try!(value.clone().to_value()));
+        }
+
+        VmResult::Ok(Value::from(Shared::new(Vec::from(vec))))
+    }
+}
+
+/// Fixed arrays are converted into a dynamic vector.
+impl<T, const N: usize> ToValue for [T; N]
+where
+    T: ToValue,
+{
+    fn to_value(self) -> VmResult<Value> {
+        let mut vec = vec::Vec::with_capacity(N);
+
+        for value in self {
+            vec.push(vm_try!(value.to_value()));
+        }
+
+        VmResult::Ok(Value::from(Shared::new(Vec::from(vec))))
+    }
+}
@@ -1,3 +1,4 @@
+use core::any::Any;
 use core::fmt;
 use core::mem;
 use core::ops;
@@ -11,13 +12,15 @@ use crate::runtime::budget;
 use crate::runtime::future::SelectFuture;
 use crate::runtime::unit::{UnitFn, UnitStorage};
 use crate::runtime::{
-    Args, Awaited, BorrowMut, Bytes, Call, Format, FormatSpec, FromValue, Function, Future,
-    Generator, GuardedArgs, Inst, InstAddress, InstAssignOp, InstOp, InstRangeLimits, InstTarget,
-    InstValue, InstVariant, Object, Panic, Protocol, Range, RangeLimits, RuntimeContext, Select,
-    Shared, Stack, Stream, Struct, Tuple, Type, TypeCheck, Unit, UnitStruct, Value, Variant,
-    VariantData, Vec, VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr, VmResult,
-    VmSendExecution,
+    Args, Awaited, BorrowMut, Bytes, Call, CancellationToken, Extensions, Format, FormatSpec,
+    FromValue, Function, FunctionHandler, Future, Generator, GuardedArgs, Inst, InstAddress,
+    InstAssignOp, InstOp, InstRangeLimits, InstTarget, InstValue, InstVariant, Object, Overflow,
+    Panic, Protocol, Range, RangeLimits, RuntimeContext, Select, Shared, Stack, Stream, Struct,
+    Tuple, Type, TypeCheck, Unit, UnitStruct, Value, Variant, VariantData, Vec, VmCallBuilder,
+    VmError, VmErrorKind, VmExecution, VmHalt, VmIntegerRepr, VmResult, VmSendExecution,
 };
+#[cfg(feature = "native-hook")]
+use crate::runtime::{NativeCallHook, NativeHook};
 
 /// Small helper function to build errors.
 fn err<T, E>(error: E) -> VmResult<T>
@@ -84,6 +87,30 @@ macro_rules! target_value {
     }};
 }
 
+/// Call a native function handler, notifying the native call hook if one has
+/// been installed.
+macro_rules! call_native {
+    ($vm:ident, $handler:expr, $hash:expr, $args:expr) => {{
+        #[cfg(feature = "native-hook")]
+        let guard = $vm
+            .native_hook
+            .as_ref()
+            .map(|hook| hook.enter($hash, $args));
+
+        #[cfg(not(feature = "native-hook"))]
+        let _ = $hash;
+
+        let result = $handler(&mut $vm.stack, $args);
+
+        #[cfg(feature = "native-hook")]
+        if let Some(guard) = guard {
+            guard.exit();
+        }
+
+        result
+    }};
+}
+
 /// A stack which references variables indirectly from a slab.
 #[derive(Debug, Clone)]
 pub struct Vm {
@@ -97,6 +124,15 @@ pub struct Vm {
     stack: Stack,
     /// Frames relative to the stack.
     call_frames: vec::Vec<CallFrame>,
+    /// Host extensions accessible to native functions.
+    ext: Extensions,
+    /// How integer overflow is handled.
+    overflow: Overflow,
+    /// Token used to cooperatively cancel execution.
+    cancellation: Option<CancellationToken>,
+    /// Hook notified around native function calls.
+    #[cfg(feature = "native-hook")]
+    native_hook: Option<NativeHook>,
 }
 
 impl Vm {
@@ -113,6 +149,11 @@ impl Vm {
             ip: 0,
             stack,
             call_frames: vec::Vec::new(),
+            ext: Extensions::new(),
+            overflow: Overflow::Error,
+            cancellation: None,
+            #[cfg(feature = "native-hook")]
+            native_hook: None,
         }
     }
 
@@ -152,6 +193,18 @@ impl Vm {
         &mut self.stack
     }
 
+    /// Call a native function handler on the stack of this virtual machine,
+    /// notifying the native call hook if one has been installed.
+    #[inline]
+    pub(crate) fn call_native(
+        &mut self,
+        handler: &FunctionHandler,
+        hash: Hash,
+        args: usize,
+    ) -> VmResult<()> {
+        call_native!(self, handler, hash, args)
+    }
+
     /// Access the context related to the virtual machine.
     #[inline]
     pub fn context(&self) -> &Arc<RuntimeContext> {
@@ -170,6 +223,259 @@ impl Vm {
         self.ip
     }
 
+    /// Insert a host extension value into the virtual machine, replacing any
+    /// existing value of the same type.
+    ///
+    /// Extensions can be accessed by native functions during a call through
+    /// [`runtime::ext`][crate::runtime::ext], which makes it possible to pass
+    /// per-call host context without relying on global state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Context, Module, Vm};
+    /// use rune::runtime;
+    /// use std::sync::Arc;
+    ///
+    /// struct User(String);
+    ///
+    /// let mut m = Module::new();
+    /// m.function(["user"], || runtime::ext::<User>().map(|u| u.0.clone()))?;
+    ///
+    /// let mut context = Context::new();
+    /// context.install(m)?;
+    /// let runtime = Arc::new(context.runtime());
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         pub fn main() {
+    ///             user()
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    /// let mut vm = Vm::new(runtime, Arc::new(unit));
+    ///
+    /// vm.insert_ext(User(String::from("alice")));
+    /// assert_eq!(vm.ext::<User>().map(|u| u.0.as_str()), Some("alice"));
+    ///
+    /// let user: Option<String> = rune::from_value(vm.call(["main"], ())?)?;
+    /// assert_eq!(user.as_deref(), Some("alice"));
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn insert_ext<T>(&mut self, value: T)
+    where
+        T: Any + Send + Sync,
+    {
+        self.ext.insert(value);
+    }
+
+    /// Get a reference to the host extension value of type `T`, if one has
+    /// been inserted with [`Vm::insert_ext`].
+    pub fn ext<T>(&self) -> Option<&T>
+    where
+        T: Any + Send + Sync,
+    {
+        self.ext.get()
+    }
+
+    /// Copy the host extensions and settings of `other` into this virtual
+    /// machine.
+    pub(crate) fn inherit(&mut self, other: &Vm) {
+        self.ext = other.ext.clone();
+        self.overflow = other.overflow;
+        self.cancellation = other.cancellation.clone();
+        #[cfg(feature = "native-hook")]
+        {
+            self.native_hook = other.native_hook.clone();
+        }
+    }
+
+    /// Copy the host extensions and settings of the virtual machine which is
+    /// currently executing into this one, if this is called from a native
+    /// function.
+    pub(crate) fn inherit_env(&mut self) {
+        crate::runtime::env::with_inherited(|inherited| {
+            if let Some(ext) = inherited.ext {
+                self.ext = ext.clone();
+            }
+
+            if let Some(overflow) = inherited.overflow {
+                self.overflow = overflow;
+            }
+
+            self.cancellation = inherited.cancellation.cloned();
+
+            #[cfg(feature = "native-hook")]
+            {
+                self.native_hook = inherited.native_hook.cloned();
+            }
+        })
+    }
+
+    /// Set up the environment so that native functions can access the context,
+    /// unit, extensions and settings of this virtual machine.
+    fn env_guard(&self) -> crate::runtime::env::Guard {
+        crate::runtime::env::Guard::new(
+            &self.context,
+            &self.unit,
+            &self.ext,
+            &self.overflow,
+            &self.cancellation,
+            #[cfg(feature = "native-hook")]
+            &self.native_hook,
+        )
+    }
+
+    /// Set how integer overflow in `+`, `-` and `*` is handled.
+    ///
+    /// By default overflow raises an error.
+    ///
+    /// This is inherited by generators, streams, async functions and closures
+    /// called from this virtual machine, including functions called from
+    /// native code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::Overflow;
+    /// use rune::{Context, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let runtime = Arc::new(context.runtime());
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         pub fn main(n) {
+    ///             n + 1
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    /// let mut vm = Vm::new(runtime, Arc::new(unit));
+    ///
+    /// assert!(vm.call(["main"], (i64::MAX,)).is_err());
+    ///
+    /// vm.set_overflow(Overflow::Wrap);
+    /// let n: i64 = rune::from_value(vm.call(["main"], (i64::MAX,))?)?;
+    /// assert_eq!(n, i64::MIN);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn set_overflow(&mut self, overflow: Overflow) {
+        self.overflow = overflow;
+    }
+
+    /// Get how integer overflow is handled.
+    pub fn overflow(&self) -> Overflow {
+        self.overflow
+    }
+
+    /// Make execution of this virtual machine cancellable through the given
+    /// token.
+    ///
+    /// The token is checked at loop back-edges and await points, and once it
+    /// has been signaled execution aborts with an error for which
+    /// [`VmError::is_cancelled`] returns `true`.
+    /// Unlike the [instruction budget][crate::runtime::budget], this allows the
+    /// host to stop execution at any time, for example from another thread.
+    ///
+    /// This is inherited in the same way as [`Vm::set_overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::runtime::CancellationToken;
+    /// use rune::{Context, Vm};
+    /// use std::sync::Arc;
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let runtime = Arc::new(context.runtime());
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         pub fn main() {
+    ///             loop {}
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    ///
+    /// let token = CancellationToken::new();
+    /// let mut vm = Vm::new(runtime, Arc::new(unit)).with_cancellation(token.clone());
+    ///
+    /// token.cancel();
+    /// let error = vm.call(["main"], ()).unwrap_err();
+    /// assert!(error.is_cancelled());
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn with_cancellation(mut self, token: CancellationToken) -> Self {
+        self.cancellation = Some(token);
+        self
+    }
+
+    /// Install a hook which is notified on entry to and exit from every native
+    /// function called by this virtual machine.
+    ///
+    /// This is inherited in the same way as [`Vm::set_overflow`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::sync::atomic::{AtomicUsize, Ordering};
+    /// use std::sync::Arc;
+    ///
+    /// use rune::runtime::{NativeCall, NativeCallHook};
+    /// use rune::{Context, Vm};
+    ///
+    /// #[derive(Default)]
+    /// struct Counter(AtomicUsize);
+    ///
+    /// impl NativeCallHook for Counter {
+    ///     fn enter(&self, _: NativeCall) {
+    ///         self.0.fetch_add(1, Ordering::SeqCst);
+    ///     }
+    /// }
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let runtime = Arc::new(context.runtime());
+    ///
+    /// let mut sources = rune::sources! {
+    ///     entry => {
+    ///         pub fn main() {
+    ///             std::i64::max(1, 2)
+    ///         }
+    ///     }
+    /// };
+    ///
+    /// let unit = rune::prepare(&mut sources).with_context(&context).build()?;
+    ///
+    /// let counter = Arc::new(Counter::default());
+    /// let mut vm = Vm::new(runtime, Arc::new(unit)).with_native_hook(counter.clone());
+    ///
+    /// vm.call(["main"], ())?;
+    /// assert_eq!(counter.0.load(Ordering::SeqCst), 1);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    #[cfg(feature = "native-hook")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "native-hook")))]
+    pub fn with_native_hook(mut self, hook: Arc<dyn NativeCallHook>) -> Self {
+        self.native_hook = Some(NativeHook::new(hook));
+        self
+    }
+
+    /// Error if execution has been cancelled.
+    #[inline]
+    fn check_cancelled(&self) -> VmResult<()> {
+        match &self.cancellation {
+            Some(token) if token.is_cancelled() => VmResult::err(VmErrorKind::Cancelled),
+            _ => VmResult::Ok(()),
+        }
+    }
+
     /// Reset this virtual machine, freeing all memory used.
     pub fn clear(&mut self) {
         self.ip = 0;
@@ -323,6 +629,41 @@ impl Vm {
     /// This is accomplished by preventing values escaping from being
     /// non-exclusively sent with the execution or escaping the execution. We
     /// only support encoding arguments which themselves are `Send`.
+    ///
+    /// Note that the [`Value`] produced by the execution is *not* [`Send`].
+    /// Any value which is backed by a [`Shared`] container, such as strings,
+    /// vectors, objects, tuples, functions or external types, uses
+    /// non-atomic reference counting. The output of the execution should
+    /// therefore be converted into a `Send` type, like through
+    /// [`FromValue`][crate::FromValue], before it leaves the task that
+    /// completed it.
+    ///
+    /// # Examples
+    ///
+    /// ```,no_run
+    /// use rune::{Context, Unit, Vm};
+    /// use std::sync::Arc;
+    /// use std::thread;
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let context = Arc::new(context.runtime());
+    ///
+    /// // Normally the unit would be created by compiling some source,
+    /// // and since this one is empty it won't do anything.
+    /// let unit = Arc::new(Unit::default());
+    ///
+    /// let vm = Vm::new(context, unit);
+    /// let execution = vm.send_execute(["main"], (1i64,))?;
+    ///
+    /// let output = thread::spawn(move || {
+    ///     let output = futures_executor::block_on(execution.async_complete());
+    ///     rune::from_value::<i64>(output.into_result()?)
+    /// });
+    ///
+    /// let output = output.join().expect("thread panicked")?;
+    /// println!("output: {}", output);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
     pub fn send_execute<A, N>(mut self, name: N, args: A) -> Result<VmSendExecution, VmError>
     where
         N: ToTypeHash,
@@ -377,6 +718,41 @@ impl Vm {
         Result::Ok(value)
     }
 
+    /// Construct a call to the function identified by the given name, where
+    /// the arguments are pushed one at a time.
+    ///
+    /// This is useful when the number of arguments isn't known statically.
+    /// The number of arguments is checked against the function before it is
+    /// executed.
+    ///
+    /// # Examples
+    ///
+    /// ```,no_run
+    /// use rune::{Context, Unit};
+    /// use std::sync::Arc;
+    ///
+    /// let context = Context::with_default_modules()?;
+    /// let context = Arc::new(context.runtime());
+    ///
+    /// // Normally the unit would be created by compiling some source,
+    /// // and since this one is empty it won't do anything.
+    /// let unit = Arc::new(Unit::default());
+    ///
+    /// let mut vm = rune::Vm::new(context, unit);
+    ///
+    /// let output = vm.call_builder(["main"]).arg(1i64).arg(2i64).invoke()?;
+    /// let output: i64 = rune::from_value(output)?;
+    ///
+    /// println!("output: {}", output);
+    /// # Ok::<_, rune::Error>(())
+    /// ```
+    pub fn call_builder<N>(&mut self, name: N) -> VmCallBuilder<'_, N>
+    where
+        N: ToTypeHash,
+    {
+        VmCallBuilder::new(self, name)
+    }
+
     /// Call the given function immediately asynchronously, returning the
     /// produced value.
     ///
@@ -493,7 +869,7 @@ impl Vm {
         }
 
         if let Some(handler) = self.context.function(hash) {
-            vm_try!(handler(&mut self.stack, full_count));
+            vm_try!(call_native!(self, handler, hash, full_count));
             return VmResult::Ok(CallResult::Ok(()));
         }
 
@@ -524,7 +900,7 @@ impl Vm {
         vm_try!(args.into_stack(&mut self.stack));
 
         if let Some(handler) = self.context.function(hash) {
-            vm_try!(handler(&mut self.stack, full_count));
+            vm_try!(call_native!(self, handler, hash, full_count));
             return VmResult::Ok(CallResult::Ok(()));
         }
 
@@ -554,7 +930,7 @@ impl Vm {
         vm_try!(args.into_stack(&mut self.stack));
 
         if let Some(handler) = self.context.function(hash) {
-            vm_try!(handler(&mut self.stack, full_count));
+            vm_try!(call_native!(self, handler, hash, full_count));
             return VmResult::Ok(CallResult::Ok(()));
         }
 
@@ -578,6 +954,10 @@ impl Vm {
         let out = match (lhs, rhs) {
             (Value::Integer(lhs), Value::Integer(rhs)) => int_op(lhs, rhs),
             (Value::Float(lhs), Value::Float(rhs)) => float_op(lhs, rhs),
+            (Value::Byte(lhs), Value::Byte(rhs)) => int_op(lhs.into(), rhs.into()),
+            (Value::Char(lhs), Value::Char(rhs)) => {
+                int_op(u32::from(lhs).into(), u32::from(rhs).into())
+            }
             (lhs, rhs) => {
                 return err(VmErrorKind::UnsupportedBinaryOperation {
                     op,
@@ -1081,6 +1461,7 @@ impl Vm {
     fn call_generator_fn(&mut self, offset: usize, args: usize) -> Result<(), VmErrorKind> {
         let stack = self.stack.drain(args)?.collect::<Stack>();
         let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
+        vm.inherit(self);
         vm.ip = offset;
         self.stack.push(Generator::new(vm));
         Ok(())
@@ -1090,6 +1471,7 @@ impl Vm {
     fn call_stream_fn(&mut self, offset: usize, args: usize) -> Result<(), VmErrorKind> {
         let stack = self.stack.drain(args)?.collect::<Stack>();
         let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
+        vm.inherit(self);
         vm.ip = offset;
         self.stack.push(Stream::new(vm));
         Ok(())
@@ -1099,6 +1481,7 @@ impl Vm {
     fn call_async_fn(&mut self, offset: usize, args: usize) -> Result<(), VmErrorKind> {
         let stack = self.stack.drain(args)?.collect::<Stack>();
         let mut vm = Self::with_stack(self.context.clone(), self.unit.clone(), stack);
+        vm.inherit(self);
         vm.ip = offset;
         let mut execution = vm.into_execution();
         self.stack
@@ -1504,7 +1887,14 @@ impl Vm {
     /// Perform a jump operation.
     #[cfg_attr(feature = "bench", inline(never))]
     fn op_jump(&mut self, jump: usize) -> VmResult<()> {
-        self.ip = vm_try!(self.unit.translate(jump));
+        let ip = vm_try!(self.unit.translate(jump));
+
+        // NB: jumping backwards is a loop back-edge.
+        if ip < self.ip {
+            vm_try!(self.check_cancelled());
+        }
+
+        self.ip = ip;
         VmResult::Ok(())
     }
 
@@ -1636,7 +2026,7 @@ impl Vm {
                 vm_try!(self.internal_num(
                     Protocol::ADD,
                     || VmErrorKind::Overflow,
-                    i64::checked_add,
+                    self.overflow.add(),
                     ops::Add::add,
                     lhs,
                     rhs,
@@ -1646,7 +2036,7 @@ impl Vm {
                 vm_try!(self.internal_num(
                     Protocol::SUB,
                     || VmErrorKind::Underflow,
-                    i64::checked_sub,
+                    self.overflow.sub(),
                     ops::Sub::sub,
                     lhs,
                     rhs,
@@ -1656,7 +2046,7 @@ impl Vm {
                 vm_try!(self.internal_num(
                     Protocol::MUL,
                     || VmErrorKind::Overflow,
-                    i64::checked_mul,
+                    self.overflow.mul(),
                     ops::Mul::mul,
                     lhs,
                     rhs,
@@ -1775,7 +2165,7 @@ impl Vm {
                     target,
                     Protocol::ADD_ASSIGN,
                     || VmErrorKind::Overflow,
-                    i64::checked_add,
+                    self.overflow.add(),
                     ops::Add::add,
                 ));
             }
@@ -1784,7 +2174,7 @@ impl Vm {
                     target,
                     Protocol::SUB_ASSIGN,
                     || VmErrorKind::Underflow,
-                    i64::checked_sub,
+                    self.overflow.sub(),
                     ops::Sub::sub,
                 ));
             }
@@ -1793,7 +2183,7 @@ impl Vm {
                     target,
                     Protocol::MUL_ASSIGN,
                     || VmErrorKind::Overflow,
-                    i64::checked_mul,
+                    self.overflow.mul(),
                     ops::Mul::mul,
                 ));
             }
@@ -2742,7 +3132,7 @@ impl Vm {
                     .function(hash)
                     .ok_or(VmErrorKind::MissingFunction { hash }));
 
-                vm_try!(handler(&mut self.stack, args));
+                vm_try!(call_native!(self, handler, hash, args));
             }
         }
 
@@ -2769,7 +3159,7 @@ impl Vm {
         }
 
         if let Some(handler) = self.context.function(hash) {
-            vm_try!(handler(&mut self.stack, args));
+            vm_try!(call_native!(self, handler, hash, args));
             return VmResult::Ok(());
         }
 
@@ -2861,7 +3251,7 @@ impl Vm {
     where
         F: FnOnce() -> T,
     {
-        let _guard = crate::runtime::env::Guard::new(&self.context, &self.unit);
+        let _guard = self.env_guard();
         f()
     }
 
@@ -2869,7 +3259,7 @@ impl Vm {
     pub(crate) fn run(&mut self) -> VmResult<VmHalt> {
         // NB: set up environment so that native function can access context and
         // unit.
-        let _guard = crate::runtime::env::Guard::new(&self.context, &self.unit);
+        let _guard = self.env_guard();
 
         loop {
             if !budget::take() {
@@ -2946,11 +3336,14 @@ impl Vm {
                     }
                 }
                 Inst::Await => {
+                    vm_try!(self.check_cancelled());
                     let future = vm_try!(self.op_await());
                     // NB: the future itself will advance the virtual machine.
                     return VmResult::Ok(VmHalt::Awaited(Awaited::Future(future)));
                 }
                 Inst::Select { len } => {
+                    vm_try!(self.check_cancelled());
+
                     if let Some(select) = vm_try!(self.op_select(len)) {
                         // NB: the future itself will advance the virtual machine.
                         return VmResult::Ok(VmHalt::Awaited(Awaited::Select(select)));
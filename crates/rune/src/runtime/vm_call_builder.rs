@@ -0,0 +1,87 @@
+use crate::hash::ToTypeHash;
+use crate::no_std::prelude::*;
+use crate::runtime::{ToValue, Value, Vm, VmError};
+
+/// A builder for a call into a function in a virtual machine, where arguments
+/// are provided one at a time.
+///
+/// See [`Vm::call_builder`].
+pub struct VmCallBuilder<'a, N> {
+    vm: &'a mut Vm,
+    name: N,
+    args: Vec<Value>,
+    error: Option<VmError>,
+}
+
+impl<'a, N> VmCallBuilder<'a, N>
+where
+    N: ToTypeHash,
+{
+    pub(crate) fn new(vm: &'a mut Vm, name: N) -> Self {
+        Self {
+            vm,
+            name,
+            args: Vec::new(),
+            error: None,
+        }
+    }
+
+    /// Push an argument to the call.
+    ///
+    /// If the argument fails to convert into a [`Value`], the error is
+    /// reported when the call is invoked.
+    pub fn arg<T>(mut self, value: T) -> Self
+    where
+        T: ToValue,
+    {
+        if self.error.is_none() {
+            match value.to_value().into_result() {
+                Ok(value) => self.args.push(value),
+                Err(error) => self.error = Some(error),
+            }
+        }
+
+        self
+    }
+
+    /// The number of arguments pushed so far.
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    /// Test if no arguments have been pushed.
+    pub fn is_empty(&self) -> bool {
+        self.args.is_empty()
+    }
+
+    /// Invoke the function with the arguments pushed so far.
+    ///
+    /// This errors before anything is executed if the number of arguments
+    /// doesn't match the number of arguments the function expects.
+    pub fn invoke(self) -> Result<Value, VmError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        self.vm
+            .execute(self.name, self.args)?
+            .complete()
+            .into_result()
+    }
+
+    /// Invoke the function asynchronously with the arguments pushed so far.
+    ///
+    /// This errors before anything is executed if the number of arguments
+    /// doesn't match the number of arguments the function expects.
+    pub async fn async_invoke(self) -> Result<Value, VmError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+
+        self.vm
+            .execute(self.name, self.args)?
+            .async_complete()
+            .await
+            .into_result()
+    }
+}
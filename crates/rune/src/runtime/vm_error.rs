@@ -11,8 +11,8 @@ use crate::compile::ItemBuf;
 use crate::hash::Hash;
 use crate::runtime::unit::{BadInstruction, BadJump};
 use crate::runtime::{
-    AccessError, BoxedPanic, CallFrame, ExecutionState, FullTypeOf, Key, MaybeTypeOf, Panic,
-    StackError, TypeInfo, TypeOf, Unit, Value, Vm, VmHaltInfo,
+    AccessError, BoxedPanic, CallFrame, ExecutionState, FullTypeOf, MaybeTypeOf, Panic, StackError,
+    TypeInfo, TypeOf, Unit, Value, Vm, VmHaltInfo,
 };
 
 /// Trait used to convert result types to [`VmResult`].
@@ -125,6 +125,9 @@ pub struct VmError {
     pub(crate) inner: Box<VmErrorInner>,
 }
 
+#[cfg(test)]
+static_assertions::assert_impl_all!(VmError: Send, Sync);
+
 impl VmError {
     /// Construct an error containing a panic.
     pub fn panic<D>(message: D) -> Self
@@ -144,6 +147,11 @@ impl VmError {
         &self.inner.chain
     }
 
+    /// Get the kind of the error.
+    pub(crate) fn kind(&self) -> &VmErrorKind {
+        &self.inner.error.kind
+    }
+
     /// Construct an expectation error. The actual type received is `actual`,
     /// but we expected `E`.
     pub fn expected<E>(actual: TypeInfo) -> Self
@@ -166,6 +174,12 @@ impl VmError {
         self.inner.stacktrace.first()
     }
 
+    /// Test if the error was raised because execution was cancelled through a
+    /// [`CancellationToken`][crate::runtime::CancellationToken].
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.kind(), VmErrorKind::Cancelled)
+    }
+
     #[cfg(test)]
     pub(crate) fn into_kind(self) -> VmErrorKind {
         self.inner.error.kind
@@ -252,6 +266,27 @@ impl<T> VmResult<T> {
         }
     }
 
+    /// Attach the name of the native function being called to an argument
+    /// conversion error raised directly by it.
+    #[inline]
+    pub(crate) fn with_function(self, function: &str) -> Self {
+        match self {
+            Self::Ok(ok) => Self::Ok(ok),
+            Self::Err(mut err) => {
+                if let Some(at) = err.inner.chain.last_mut() {
+                    if let VmErrorKind::BadArgument { arg } = at.kind {
+                        at.kind = VmErrorKind::BadFunctionArgument {
+                            function: function.to_owned(),
+                            arg,
+                        };
+                    }
+                }
+
+                Self::Err(err)
+            }
+        }
+    }
+
     /// Add auxilliary errors if appropriate.
     #[inline]
     pub(crate) fn with_error<E, O>(self, error: E) -> Self
@@ -419,12 +454,18 @@ pub(crate) enum VmErrorKind {
     Halted { halt: VmHaltInfo },
     #[error("Failed to format argument")]
     FormatError,
+    #[error("Execution was cancelled")]
+    Cancelled,
     #[error("Numerical overflow")]
     Overflow,
     #[error("Numerical underflow")]
     Underflow,
     #[error("Division by zero")]
     DivideByZero,
+    #[error("Failed to allocate `{size}` bytes")]
+    AllocationFailed { size: usize },
+    #[error("Cannot convert `NaN` into an integer")]
+    NanConversion,
     #[error("Missing entry `{item}` with hash `{hash}`")]
     MissingEntry { item: ItemBuf, hash: Hash },
     #[error("Missing entry with hash `{hash}`")]
@@ -463,6 +504,8 @@ pub(crate) enum VmErrorKind {
     },
     #[error("Bad argument at #{arg}")]
     BadArgument { arg: usize },
+    #[error("Converting argument #{arg} of `{function}`")]
+    BadFunctionArgument { function: String, arg: usize },
     #[error("The index set operation `{target}[{index}] = {value}` is not supported")]
     UnsupportedIndexSet {
         target: TypeInfo,
@@ -493,8 +536,8 @@ pub(crate) enum VmErrorKind {
         target: TypeInfo,
         index: VmIntegerRepr,
     },
-    #[error("Type `{target}` missing index `{index:?}`")]
-    MissingIndexKey { target: TypeInfo, index: Key },
+    #[error("Type `{target}` missing index `{index}`")]
+    MissingIndexKey { target: TypeInfo, index: String },
     #[error("Index out of bounds, the length is `{length}` but the index is `{index}`")]
     OutOfRange {
         index: VmIntegerRepr,
@@ -504,6 +547,10 @@ pub(crate) enum VmErrorKind {
     UnsupportedTryOperand { actual: TypeInfo },
     #[error("Type `{actual}` is not supported as iter-next operand")]
     UnsupportedIterNextOperand { actual: TypeInfo },
+    #[error("Type `{actual}` does not support deep cloning")]
+    UnsupportedDeepClone { actual: TypeInfo },
+    #[error("Cannot deep clone a value which contains itself")]
+    DeepCloneCycle,
     #[error("Expected type `{expected}`, but found `{actual}`")]
     Expected {
         expected: TypeInfo,
@@ -529,6 +576,8 @@ pub(crate) enum VmErrorKind {
     ConstNotSupported { actual: TypeInfo },
     #[error("Type `{actual}` can't be converted to a hash key")]
     KeyNotSupported { actual: TypeInfo },
+    #[error("Type `{actual}` can't be referenced weakly")]
+    WeakNotSupported { actual: TypeInfo },
     #[error("Missing interface environment")]
     MissingInterfaceEnvironment,
     #[error("Unsupported range")]
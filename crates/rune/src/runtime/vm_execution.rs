@@ -420,7 +420,8 @@ impl VmExecution<&mut Vm> {
     /// Convert the current execution into one which owns its virtual machine.
     pub fn into_owned(self) -> VmExecution<Vm> {
         let stack = take(self.head.stack_mut());
-        let head = Vm::with_stack(self.head.context().clone(), self.head.unit().clone(), stack);
+        let mut head = Vm::with_stack(self.head.context().clone(), self.head.unit().clone(), stack);
+        head.inherit(self.head);
 
         VmExecution {
             head,
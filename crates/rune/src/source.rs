@@ -22,7 +22,8 @@ pub struct Source {
     source: Box<str>,
     /// The path the source was loaded from.
     path: Option<Box<Path>>,
-    /// The starting byte indices in the source code.
+    /// The starting byte indices of each line in the source code, computed
+    /// once on construction so that resolving positions never rescans it.
     line_starts: Box<[usize]>,
 }
 
@@ -81,6 +82,38 @@ impl Source {
         })
     }
 
+    /// Read and load a source with the given name from a reader, such as
+    /// standard input or a network stream.
+    ///
+    /// The whole reader is buffered, and its content must be valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Source;
+    ///
+    /// let source = Source::from_reader("stdin", &b"pub fn main() { 42 }"[..])?;
+    /// assert_eq!(source.name(), "stdin");
+    /// assert_eq!(source.path(), None);
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    #[cfg(feature = "std")]
+    pub fn from_reader<R>(name: impl AsRef<str>, mut reader: R) -> io::Result<Self>
+    where
+        R: std::io::Read,
+    {
+        let mut source = String::new();
+        reader.read_to_string(&mut source)?;
+        let line_starts = line_starts(&source).collect::<Box<[_]>>();
+
+        Ok(Self {
+            name: SourceName::Name(name.as_ref().into()),
+            source: source.into(),
+            path: None,
+            line_starts,
+        })
+    }
+
     /// Construct a new source with the given content and path.
     ///
     /// # Examples
@@ -169,6 +202,40 @@ impl Source {
         (line, col)
     }
 
+    /// Convert the given offset to a line and a visual column, where tab
+    /// characters advance the column to the next multiple of `tab_width`.
+    ///
+    /// A `tab_width` of `1` (or `0`) counts every character as a single column,
+    /// which is the same as [`Source::pos_to_utf8_linecol`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::Source;
+    ///
+    /// let source = Source::new("test", "fn main() {\n\tlet x = 42;\n}");
+    /// // The byte offset of `42`.
+    /// let offset = 21;
+    ///
+    /// assert_eq!(source.pos_to_visual_linecol(offset, 1), (1, 9));
+    /// assert_eq!(source.pos_to_visual_linecol(offset, 4), (1, 12));
+    /// assert_eq!(source.pos_to_visual_linecol(offset, 8), (1, 16));
+    /// ```
+    pub fn pos_to_visual_linecol(&self, offset: usize, tab_width: usize) -> (usize, usize) {
+        let (line, offset, rest) = self.position(offset);
+        let tab_width = tab_width.max(1);
+
+        let col =
+            rest.char_indices()
+                .take_while(|&(n, _)| n < offset)
+                .fold(0, |col, (_, c)| match c {
+                    '\t' => (col / tab_width + 1) * tab_width,
+                    _ => col + 1,
+                });
+
+        (line, col)
+    }
+
     /// Get the line index for the given byte.
     #[cfg(feature = "emit")]
     pub(crate) fn line_index(&self, byte_index: usize) -> usize {
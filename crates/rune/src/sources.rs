@@ -1,11 +1,13 @@
 use core::fmt;
 use core::num;
 
+use crate::no_std::collections::HashMap;
 use crate::no_std::path::Path;
 use crate::no_std::prelude::*;
 
 use crate::ast::Span;
 use crate::source::Source;
+use crate::Hash;
 #[cfg(feature = "codespan-reporting")]
 use codespan_reporting::files;
 
@@ -35,6 +37,10 @@ macro_rules! sources {
 pub struct Sources {
     /// Sources associated.
     sources: Vec<Source>,
+    /// Content hashes of sources, indexed by source id.
+    content_hashes: Vec<Hash>,
+    /// The first source inserted with a given content hash.
+    by_content_hash: HashMap<Hash, SourceId>,
 }
 
 impl Sources {
@@ -42,6 +48,8 @@ impl Sources {
     pub fn new() -> Self {
         Self {
             sources: Vec::new(),
+            content_hashes: Vec::new(),
+            by_content_hash: HashMap::new(),
         }
     }
 
@@ -60,10 +68,60 @@ impl Sources {
     pub fn insert(&mut self, source: Source) -> SourceId {
         let id =
             SourceId::try_from(self.sources.len()).expect("could not build a source identifier");
+        let hash = content_hash(&source);
+        self.by_content_hash.entry(hash).or_insert(id);
+        self.content_hashes.push(hash);
         self.sources.push(source);
         id
     }
 
+    /// Insert a source unless a source with identical content has already
+    /// been inserted, in which case the [`SourceId`] of the existing source is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Sources, Source};
+    ///
+    /// let mut sources = Sources::new();
+    /// let id = sources.insert_unique(Source::new("a.rn", "pub fn main() { 10 }"));
+    /// let id2 = sources.insert_unique(Source::new("./a.rn", "pub fn main() { 10 }"));
+    /// let id3 = sources.insert_unique(Source::new("b.rn", "pub fn main() { 20 }"));
+    /// assert_eq!(id, id2);
+    /// assert_ne!(id, id3);
+    /// ```
+    pub fn insert_unique(&mut self, source: Source) -> SourceId {
+        if let Some(&id) = self.by_content_hash.get(&content_hash(&source)) {
+            if self.sources[id.into_index()].as_str() == source.as_str() {
+                return id;
+            }
+        }
+
+        self.insert(source)
+    }
+
+    /// Get the hash of the content of the source matching the given source id.
+    ///
+    /// The hash only depends on the content of the source, and not on its name
+    /// or path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rune::{Sources, Source};
+    ///
+    /// let mut sources = Sources::new();
+    /// let a = sources.insert(Source::new("a.rn", "pub fn main() { 10 }"));
+    /// let b = sources.insert(Source::new("b.rn", "pub fn main() { 10 }"));
+    ///
+    /// assert!(sources.content_hash(a).is_some());
+    /// assert_eq!(sources.content_hash(a), sources.content_hash(b));
+    /// ```
+    pub fn content_hash(&self, id: SourceId) -> Option<Hash> {
+        self.content_hashes.get(id.into_index()).copied()
+    }
+
     /// Get the source matching the given source id.
     ///
     /// # Examples
@@ -114,6 +172,11 @@ impl Sources {
     }
 }
 
+/// Calculate the content hash of a source.
+fn content_hash(source: &Source) -> Hash {
+    Hash::static_bytes(source.as_str().as_bytes())
+}
+
 #[cfg(feature = "codespan-reporting")]
 impl<'a> files::Files<'a> for Sources {
     type FileId = SourceId;
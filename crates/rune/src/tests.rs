@@ -35,7 +35,9 @@ use thiserror::Error;
 
 use crate::compile::{IntoComponent, ItemBuf};
 use crate::runtime::{Args, VmError, VmResult};
-use crate::{termcolor, BuildError, Context, Diagnostics, FromValue, Source, Sources, Unit, Vm};
+use crate::{
+    termcolor, BuildError, Context, Diagnostics, FromValue, Options, Source, Sources, Unit, Vm,
+};
 
 /// An error that can be raised during testing.
 #[derive(Debug, Error)]
@@ -51,6 +53,17 @@ pub enum RunError {
 /// Compile the given source into a unit and collection of warnings.
 #[doc(hidden)]
 pub fn compile_helper(source: &str, diagnostics: &mut Diagnostics) -> Result<Unit, BuildError> {
+    compile_helper_with_options(source, &Options::default(), diagnostics)
+}
+
+/// Compile the given source with the given options into a unit and
+/// collection of warnings.
+#[doc(hidden)]
+pub fn compile_helper_with_options(
+    source: &str,
+    options: &Options,
+    diagnostics: &mut Diagnostics,
+) -> Result<Unit, BuildError> {
     let context = crate::Context::with_default_modules().expect("setting up default modules");
 
     let mut sources = Sources::new();
@@ -58,6 +71,7 @@ pub fn compile_helper(source: &str, diagnostics: &mut Diagnostics) -> Result<Uni
 
     let unit = crate::prepare(&mut sources)
         .with_context(&context)
+        .with_options(options)
         .with_diagnostics(diagnostics)
         .build()?;
 
@@ -238,8 +252,13 @@ macro_rules! assert_parse {
 /// Assert that the given rune program raises a query error.
 macro_rules! assert_errors {
     ($source:expr, $span:ident, $($pat:pat => $cond:expr),+ $(,)?) => {{
+        assert_errors!(&$crate::Options::default() => $source, $span, $($pat => $cond),+)
+    }};
+
+    // Variant which compiles the program with the given options.
+    ($options:expr => $source:expr, $span:ident, $($pat:pat => $cond:expr),+ $(,)?) => {{
         let mut diagnostics = Default::default();
-        let _ = $crate::tests::compile_helper($source, &mut diagnostics).unwrap_err();
+        let _ = $crate::tests::compile_helper_with_options($source, $options, &mut diagnostics).unwrap_err();
 
         let mut it = diagnostics.into_diagnostics().into_iter();
 
@@ -280,6 +299,11 @@ macro_rules! assert_compile_error {
     ($source:expr, $span:ident, $pat:pat => $cond:expr) => {{
         assert_errors!($source, $span, $pat => $cond)
     }};
+
+    // Variant which compiles the program with the given options.
+    ($options:expr => $source:expr, $span:ident, $pat:pat => $cond:expr) => {{
+        assert_errors!($options => $source, $span, $pat => $cond)
+    }};
 }
 
 /// Assert that the given rune program parses, but raises the specified set of
@@ -356,13 +380,22 @@ mod compiler_general;
 mod compiler_literals;
 mod compiler_paths;
 mod compiler_patterns;
+mod compiler_prelude;
 mod compiler_use;
 mod compiler_visibility;
 mod compiler_warnings;
+mod context_snapshot;
 mod core_macros;
 mod custom_macros;
 mod destructuring;
+#[cfg(feature = "doc")]
+mod doc_examples;
+#[cfg(feature = "doc")]
+mod doc_links;
+#[cfg(feature = "doc")]
+mod doc_module_contents;
 mod external_ops;
+mod file_modules;
 mod for_loop;
 mod generics;
 mod getter_setter;
@@ -372,6 +405,8 @@ mod match_external;
 mod moved;
 mod patterns;
 mod reference_error;
+mod source_map;
+mod sources;
 mod stmt_reordering;
 mod test_attribute;
 mod test_continue;
@@ -381,6 +416,7 @@ mod test_iter;
 mod test_option;
 mod test_quote;
 mod test_range;
+mod test_rc;
 mod test_result;
 mod type_name_native;
 mod type_name_rune;
@@ -390,9 +426,15 @@ mod vm_arithmetic;
 mod vm_assign_exprs;
 mod vm_async_block;
 mod vm_blocks;
+mod vm_call_builder;
+mod vm_cancellation;
+mod vm_catch;
 mod vm_closures;
 mod vm_const_exprs;
+mod vm_deep_clone;
 mod vm_early_termination;
+mod vm_ext;
+mod vm_format;
 mod vm_function;
 mod vm_general;
 mod vm_generators;
@@ -400,10 +442,14 @@ mod vm_is;
 mod vm_lazy_and_or;
 mod vm_literals;
 mod vm_match;
+#[cfg(feature = "native-hook")]
+mod vm_native_hook;
 mod vm_not_used;
 mod vm_option;
+mod vm_overflow;
 mod vm_pat;
 mod vm_result;
+mod vm_send_execute;
 mod vm_streams;
 mod vm_test_external_fn_ptr;
 mod vm_test_from_value_derive;
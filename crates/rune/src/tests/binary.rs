@@ -7,11 +7,18 @@ use ParseErrorKind::*;
 fn test_binary_exprs() {
     assert_errors! {
         r#"pub fn main() { 0 < 10 >= 10 }"#,
-        span, ParseError(PrecedenceGroupRequired) => {
+        span, ParseError(ChainedComparison { .. }) => {
             assert_eq!(span, span!(16, 22));
         }
     };
 
+    assert_errors! {
+        r#"pub fn main() { 0 is i64 is bool }"#,
+        span, ParseError(PrecedenceGroupRequired) => {
+            assert_eq!(span, span!(16, 24));
+        }
+    };
+
     // Test solving precedence with groups.
     assert_parse!(r#"pub fn main() { (0 < 10) >= 10 }"#);
     assert_parse!(r#"pub fn main() { 0 < (10 >= 10) }"#);
@@ -20,6 +27,27 @@ fn test_binary_exprs() {
     assert_parse!(r#"pub fn main() { false || return }"#);
 }
 
+#[test]
+fn test_chained_comparison() {
+    assert_errors! {
+        r#"pub fn main() { let a = 1; let b = 2; let c = 3; a < b < c }"#,
+        span, ParseError(error) => {
+            assert_eq!(span, span!(49, 54));
+            assert_eq!(
+                error.to_string(),
+                "Comparison operators cannot be chained; consider `(a < b) && (b < c)`"
+            );
+        }
+    };
+
+    assert_errors! {
+        r#"pub fn main() { 1 == 1 == true }"#,
+        span, ParseError(ChainedComparison { .. }) => {
+            assert_eq!(span, span!(16, 22));
+        }
+    };
+}
+
 #[test]
 fn test_basic_operator_precedence() {
     let result: bool = rune! {
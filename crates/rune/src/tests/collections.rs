@@ -1,5 +1,22 @@
 prelude!();
 
+use std::sync::Arc;
+
+#[derive(Any, Debug, Clone)]
+struct Point {
+    x: i64,
+    y: i64,
+}
+
+impl Point {
+    fn hash(&self) -> i64 {
+        (self.x << 32) | self.y
+    }
+}
+
+#[derive(Any, Debug)]
+struct Handle;
+
 #[test]
 fn test_hash_map_tile() {
     let _: () = rune! {
@@ -41,3 +58,124 @@ fn test_hash_set_tuple() {
         }
     };
 }
+
+#[test]
+fn test_hash_map_value_keys() {
+    let out: (i64, i64, Option<i64>, i64) = rune! {
+        pub fn main() {
+            use std::collections::HashMap;
+
+            let m = HashMap::new();
+
+            m.insert(1, 10);
+            m.insert((1, 2), 20);
+            m.insert((1, (2, "three")), 30);
+            m.insert(Some([1, 2]), 40);
+
+            // Replaces the existing entry since the keys are equal.
+            m.insert((1, 2), 21);
+
+            (m[(1, 2)], m.len(), m.get((1, (2, "three"))), m[Some([1, 2])])
+        }
+    };
+
+    assert_eq!(out, (21, 4, Some(30), 40));
+}
+
+#[test]
+fn test_hash_set_value_keys() {
+    let out: (bool, bool, bool, i64) = rune! {
+        pub fn main() {
+            use std::collections::HashSet;
+
+            let s = HashSet::new();
+            s.insert(1);
+            s.insert((1, 2));
+            s.insert((1, 2));
+
+            (s.contains(1), s.contains((1, 2)), s.contains((2, 1)), s.len())
+        }
+    };
+
+    assert_eq!(out, (true, true, false, 2));
+}
+
+#[test]
+fn test_unhashable_keys() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            let m = std::collections::HashMap::new();
+            m.insert(1.5, 1);
+        }
+        "#,
+        VmErrorKind::KeyNotSupported { actual } => {
+            assert_eq!(actual.to_string(), "float");
+        }
+    );
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            let s = std::collections::HashSet::new();
+            s.insert((1, || 2));
+        }
+        "#,
+        VmErrorKind::KeyNotSupported { .. } => {}
+    );
+}
+
+#[test]
+fn test_hash_map_external_keys() -> Result<()> {
+    let mut module = Module::new();
+    module.ty::<Point>()?;
+    module.associated_function(Protocol::HASH, Point::hash)?;
+
+    let a = Point { x: 1, y: 2 };
+    let b = Point { x: 1, y: 2 };
+    let c = Point { x: 2, y: 1 };
+
+    let out: (i64, i64, Option<i64>, bool) = rune_n! {
+        module,
+        (a, b, c),
+        (i64, i64, Option<i64>, bool) =>
+        pub fn main(a, b, c) {
+            use std::collections::{HashMap, HashSet};
+
+            let m = HashMap::new();
+            m.insert(a, 1);
+            // Replaces the existing entry since the points are equal.
+            m.insert(b, 2);
+
+            let s = HashSet::new();
+            s.insert(a);
+
+            (m.len(), m[a], m.get(c), s.contains(c))
+        }
+    };
+
+    assert_eq!(out, (1, 2, None, false));
+
+    let mut module = Module::new();
+    module.ty::<Handle>()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let mut sources = crate::tests::sources(
+        "pub fn main(handle) { let m = std::collections::HashMap::new(); m.insert(handle, 1); }",
+    );
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let error = vm.call(["main"], (Handle,)).unwrap_err();
+
+    match error.into_kind() {
+        VmErrorKind::KeyNotSupported { actual } => {
+            assert_eq!(actual.to_string(), "Handle");
+        }
+        kind => panic!("unexpected error {kind:?}"),
+    }
+
+    Ok(())
+}
@@ -197,3 +197,90 @@ fn harvest_docs() {
     result.unwrap();
     vis.assert();
 }
+
+#[cfg(feature = "doc")]
+#[test]
+fn field_docs_in_meta() {
+    use crate::compile::meta;
+
+    #[derive(Default)]
+    struct FieldVisitor {
+        collected: BTreeMap<String, Vec<String>>,
+    }
+
+    impl compile::CompileVisitor for FieldVisitor {
+        fn visit_meta(&mut self, _: Location, meta: compile::MetaRef<'_>) {
+            let fields = match meta.kind {
+                meta::Kind::Struct {
+                    fields: meta::Fields::Named(fields),
+                    ..
+                }
+                | meta::Kind::Variant {
+                    fields: meta::Fields::Named(fields),
+                    ..
+                } => fields,
+                _ => return,
+            };
+
+            for field in fields.fields.iter() {
+                let lines = fields
+                    .field_docs(field)
+                    .map(|docs| docs.lines().to_vec())
+                    .unwrap_or_default();
+
+                self.collected
+                    .insert(format!("{}.{field}", meta.item), lines);
+            }
+        }
+    }
+
+    let mut sources = crate::tests::sources(
+        r#"
+        struct Struct {
+            /// Struct field A.
+            a,
+            b,
+        }
+
+        enum Enum {
+            B {
+                /// Variant field A.
+                /// Second line!
+                a,
+            },
+        }
+
+        pub fn main() {
+            (Struct { a: 1, b: 2 }, Enum::B { a: 3 })
+        }
+        "#,
+    );
+
+    let context = Context::default();
+    let mut diagnostics = Diagnostics::new();
+    let mut vis = FieldVisitor::default();
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .with_visitor(&mut vis)
+        .build()
+        .unwrap();
+
+    let expected = BTreeMap::from([
+        (
+            "Enum::B.a".to_string(),
+            vec![
+                " Variant field A.\n".to_string(),
+                " Second line!\n".to_string(),
+            ],
+        ),
+        (
+            "Struct.a".to_string(),
+            vec![" Struct field A.\n".to_string()],
+        ),
+        ("Struct.b".to_string(), vec![]),
+    ]);
+
+    assert_eq!(vis.collected, expected);
+}
@@ -18,3 +18,91 @@ fn test_fn_const_async() {
         }
     };
 }
+
+#[test]
+fn test_fn_argument_count() {
+    assert_compile_error! {
+        r#"fn foo(a, b) { a + b } pub fn main() { foo(1, 2, 3) }"#,
+        span, BadArgumentCount { expected, actual, declared, .. } => {
+            assert_eq!(span, span!(39, 51));
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 3);
+            assert_eq!(declared.map(|d| d.span), Some(span!(0, 22)));
+        }
+    };
+
+    assert_compile_error! {
+        r#"struct Foo; impl Foo { fn bar(self, a) { a } } pub fn main() { Foo::bar(Foo) }"#,
+        span, BadArgumentCount { expected, actual, .. } => {
+            assert_eq!(span, span!(63, 76));
+            assert_eq!(expected, 2);
+            assert_eq!(actual, 1);
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { std::string::String::new(1) }"#,
+        span, BadArgumentCount { expected, actual, declared, .. } => {
+            assert_eq!(span, span!(16, 43));
+            assert_eq!(expected, 0);
+            assert_eq!(actual, 1);
+            assert!(declared.is_none());
+        }
+    };
+}
+
+#[test]
+fn test_fn_dependencies() -> Result<()> {
+    use compile::ItemBuf;
+
+    let mut sources = sources! {
+        entry => {
+            mod a {
+                pub fn first() { super::LIMIT }
+            }
+
+            use a::first;
+
+            const LIMIT = BASE + 5;
+            const BASE = double(2);
+
+            const fn double(n) { n * FACTOR }
+
+            const FACTOR = 2;
+
+            fn second() { 2 }
+
+            fn unrelated() { 3 }
+
+            pub fn main() {
+                first() + second()
+            }
+        }
+    };
+
+    let mut graph = compile::DependencyGraph::new();
+
+    prepare(&mut sources)
+        .with_dependency_graph(&mut graph)
+        .build()?;
+
+    let main = ItemBuf::with_item(["main"]);
+    let first = ItemBuf::with_item(["a", "first"]);
+    let second = ItemBuf::with_item(["second"]);
+    let limit = ItemBuf::with_item(["LIMIT"]);
+    let base = ItemBuf::with_item(["BASE"]);
+    let double = ItemBuf::with_item(["double"]);
+    let import = ItemBuf::with_item(["first"]);
+    let factor = ItemBuf::with_item(["FACTOR"]);
+
+    assert_eq!(graph.dependencies(&main), [first.clone(), second]);
+    assert_eq!(graph.dependencies(&first), [limit.clone()]);
+    assert_eq!(graph.dependencies(&import), [first]);
+    assert_eq!(graph.dependencies(&limit), [base.clone()]);
+    assert_eq!(graph.dependencies(&base), [double.clone()]);
+    assert_eq!(graph.dependencies(&double), [factor]);
+
+    let unrelated = ItemBuf::with_item(["unrelated"]);
+    assert!(graph.dependencies(&unrelated).is_empty());
+    Ok(())
+}
@@ -87,3 +87,20 @@ fn test_bad_struct_declaration() {
         }
     };
 }
+
+#[test]
+fn test_unclosed_delimiter() {
+    assert_errors! {
+        r#"pub fn main() { let a = [1, 2]; a"#,
+        span, ParseError(ParseErrorKind::UnclosedDelimiter { .. }) => {
+            assert_eq!(span, span!(14, 15));
+        }
+    };
+
+    assert_errors! {
+        r#"pub fn main() { if true { (1"#,
+        span, ParseError(ParseErrorKind::UnclosedDelimiter { .. }) => {
+            assert_eq!(span, span!(26, 27));
+        }
+    };
+}
@@ -4,6 +4,9 @@ use CompileErrorKind::{ParseError, ResolveError};
 use ParseErrorKind::*;
 use ResolveErrorKind::*;
 
+use crate::ast::LitKind;
+use crate::compile::{CompileVisitor, Location};
+
 #[test]
 fn test_number_literals() {
     assert_parse!(r#"pub fn main() { -9223372036854775808 }"#);
@@ -21,6 +24,34 @@ fn test_number_literals() {
         }
     };
 
+    assert_compile_error! {
+        r#"pub fn main() { 1__0 }"#,
+        span, ResolveError(BadNumberLiteral { .. }) => {
+            assert_eq!(span, span!(16, 20));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { 100_ }"#,
+        span, ResolveError(BadNumberLiteral { .. }) => {
+            assert_eq!(span, span!(16, 20));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { 0x_ff }"#,
+        span, ResolveError(BadNumberLiteral { .. }) => {
+            assert_eq!(span, span!(16, 21));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { 42_.5 }"#,
+        span, ResolveError(BadNumberLiteral { .. }) => {
+            assert_eq!(span, span!(16, 21));
+        }
+    };
+
     assert_compile_error! {
         r#"pub fn main() { -9223372036854775809 }"#,
         span, ParseError(BadNumberOutOfBounds { .. }) => {
@@ -43,3 +74,154 @@ fn test_number_literals() {
         }
     };
 }
+
+#[derive(Default)]
+struct LiteralVisitor {
+    strings: Vec<ast::Span>,
+    numbers: usize,
+}
+
+impl CompileVisitor for LiteralVisitor {
+    fn visit_literal(&mut self, location: Location, kind: LitKind) {
+        match kind {
+            LitKind::Str => self.strings.push(location.span),
+            LitKind::Number => self.numbers += 1,
+            _ => {}
+        }
+    }
+}
+
+#[test]
+fn test_char_literals() {
+    assert_parse!(r#"pub fn main() { '\'' }"#);
+    assert_parse!(r#"pub fn main() { '\\' }"#);
+    assert_parse!(r#"pub fn main() { '\"' }"#);
+
+    assert_compile_error! {
+        r#"pub fn main() { 'ab' }"#,
+        span, ResolveError(BadCharLiteral { .. }) => {
+            assert_eq!(span, span!(16, 20));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { '\q' }"#,
+        span, ResolveError(BadEscapeSequence { .. }) => {
+            assert_eq!(span, span!(17, 20));
+        }
+    };
+}
+
+#[test]
+fn test_string_escapes() {
+    assert_compile_error! {
+        r#"pub fn main() { "\u1F600" }"#,
+        span, ResolveError(BadUnicodeEscape { .. }) => {
+            assert_eq!(span, span!(17, 20));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { "\u{zz}" }"#,
+        span, ResolveError(BadUnicodeEscape { .. }) => {
+            assert_eq!(span, span!(17, 21));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { "\u{110000}" }"#,
+        span, ResolveError(BadUnicodeEscape { .. }) => {
+            assert_eq!(span, span!(17, 27));
+        }
+    };
+
+    assert_compile_error! {
+        r#"pub fn main() { "\q" }"#,
+        span, ResolveError(BadEscapeSequence { .. }) => {
+            assert_eq!(span, span!(17, 19));
+        }
+    };
+}
+
+#[test]
+fn test_visit_string_literals() {
+    let source = r#"
+    const SECRET = "hunter2";
+
+    fn check(value) {
+        match value {
+            "admin" => 1,
+            _ => -2,
+        }
+    }
+
+    pub fn main() {
+        let name = "guest";
+        "unused";
+        `hello ${name}`;
+        check(name) + check(SECRET)
+    }
+    "#;
+
+    let mut sources = crate::tests::sources(source);
+    let context = Context::default();
+    let mut diagnostics = Diagnostics::new();
+    let mut visitor = LiteralVisitor::default();
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .with_visitor(&mut visitor)
+        .build()
+        .unwrap();
+
+    let mut strings = visitor
+        .strings
+        .iter()
+        .map(|span| &source[span.range()])
+        .collect::<Vec<_>>();
+
+    strings.sort();
+
+    assert_eq!(
+        strings,
+        [
+            "\"admin\"",
+            "\"guest\"",
+            "\"hunter2\"",
+            "\"unused\"",
+            "hello "
+        ]
+    );
+    assert_eq!(visitor.numbers, 2);
+}
+
+#[test]
+fn test_object_literal_keys() {
+    let out: i64 = rune! {
+        pub fn main() {
+            let object = #{a: 1, "b": 2,};
+            object.a + object.b
+        }
+    };
+    assert_eq!(out, 3);
+
+    assert_compile_error! {
+        r#"pub fn main() { #{"a": 1, "a": 2} }"#,
+        span, CompileErrorKind::DuplicateObjectKey { existing, object } => {
+            assert_eq!(existing, span!(18, 24));
+            assert_eq!(object, span!(26, 32));
+            assert_eq!(span, object);
+        }
+    };
+
+    // Identifier and string keys with the same text conflict.
+    assert_compile_error! {
+        r#"pub fn main() { #{a: 1, "a": 2} }"#,
+        span, CompileErrorKind::DuplicateObjectKey { existing, object } => {
+            assert_eq!(existing, span!(18, 22));
+            assert_eq!(object, span!(24, 30));
+            assert_eq!(span, object);
+        }
+    };
+}
@@ -81,3 +81,17 @@ fn test_import_conflict() {
         }
     };
 }
+
+#[test]
+fn test_import_conflict_locations() {
+    assert_errors! {
+        r#"use std::{option, option, option};"#,
+        span, QueryError(AmbiguousItem { locations, .. }) => {
+            assert_eq!(span, span!(10, 16));
+            assert_eq!(locations.len(), 3);
+
+            let spans = locations.iter().map(|(loc, _)| loc.span).collect::<Vec<_>>();
+            assert!(spans.windows(2).all(|w| w[0].start < w[1].start));
+        }
+    };
+}
@@ -32,3 +32,13 @@ fn illegal_pattern_in_match() {
         }
     };
 }
+
+#[test]
+fn mismatched_range_pattern_bounds() {
+    assert_compile_error! {
+        r#"pub fn main() { match 'a' { 'a'..=10 => {} } }"#,
+        span, UnsupportedPatternExpr => {
+            assert_eq!(span, span!(34, 36));
+        }
+    };
+}
@@ -0,0 +1,68 @@
+prelude!();
+
+use crate::compile::Prelude;
+
+/// Dump the instructions of a unit.
+fn dump(unit: &crate::Unit) -> Vec<String> {
+    unit.iter_instructions()
+        .map(|(n, inst)| format!("{n}: {inst:?}"))
+        .collect()
+}
+
+#[test]
+fn test_shared_prelude() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let prelude = Prelude::with_context(&context);
+
+    let source = r#"
+    pub fn main() {
+        let a = Some(Vec::new());
+        let b = Ok(format!("hello {}", 42));
+        assert_eq!(a, Some([]));
+        (a, b)
+    }
+    "#;
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", source));
+    let cold = prepare(&mut sources).with_context(&context).build()?;
+
+    for _ in 0..4 {
+        let mut sources = Sources::new();
+        sources.insert(Source::new("main", source));
+
+        let warm = prepare(&mut sources)
+            .with_context(&context)
+            .with_prelude(&prelude)
+            .build()?;
+
+        assert_eq!(dump(&cold), dump(&warm));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_empty_prelude() {
+    let context = Context::with_default_modules().unwrap();
+    let prelude = Prelude::new();
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main() {
+                Some(42)
+            }
+        }
+    };
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = prepare(&mut sources)
+        .with_context(&context)
+        .with_prelude(&prelude)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    assert!(result.is_err());
+    assert!(diagnostics.has_error());
+}
@@ -123,3 +123,209 @@ fn test_reexport_fn() {
 
     assert_eq!(result, 8);
 }
+
+#[test]
+fn test_reexport_wildcard() {
+    let result: (i64, bool, i64, bool) = rune! {
+        mod a {
+            pub mod b {
+                pub fn out(n) { n + A }
+                pub const A = 1;
+                pub struct Foo;
+                pub enum Bar { Baz }
+            }
+
+            pub use self::b::*;
+        }
+
+        mod c { pub use crate::a::*; }
+        mod d { pub use std::option::*; }
+
+        pub fn main() {
+            (a::out(2) + c::out(4), a::Foo is a::b::Foo, c::A, d::Option::None is Option)
+        }
+    };
+
+    assert_eq!(result, (8, true, 1, true));
+
+    assert_compile_error! {
+        r#"
+        mod a {
+            pub mod b { fn secret() { 1 } }
+            pub use self::b::*;
+        }
+
+        pub fn main() {
+            a::secret()
+        }
+        "#,
+        span, CompileErrorKind::MissingItem { item } => {
+            assert_eq!(span, span!(140, 149));
+            assert_eq!(item.to_string(), "a::secret");
+        }
+    };
+}
+
+#[test]
+fn test_reexport_wildcard_private() {
+    let result: i64 = rune! {
+        mod a {
+            pub fn shown() { 1 }
+            fn hidden() { 2 }
+        }
+
+        mod b {
+            pub use crate::a::*;
+            pub fn hidden() { 3 }
+        }
+
+        pub fn main() {
+            b::shown() + b::hidden()
+        }
+    };
+
+    assert_eq!(result, 4);
+
+    assert_compile_error! {
+        r#"
+        mod a { fn hidden() { 1 } }
+        mod b { pub use crate::a::*; }
+
+        pub fn main() {
+            b::hidden()
+        }
+        "#,
+        span, CompileErrorKind::MissingItem { item } => {
+            assert_eq!(span, span!(113, 122));
+            assert_eq!(item.to_string(), "b::hidden");
+        }
+    };
+}
+
+#[test]
+fn test_import_variant() {
+    let result: (bool, i64, bool) = rune! {
+        use std::option::Option::Some as Just;
+
+        enum Shape { Circle(r), Square(s) }
+
+        use Shape::Circle;
+
+        pub fn main() {
+            let circle = Circle(2);
+            let Circle(r) = circle;
+            (circle is Shape, r, Just(1) is Option)
+        }
+    };
+
+    assert_eq!(result, (true, 2, true));
+
+    let result: Option<i64> = rune! {
+        use std::option::Option::Some;
+
+        pub fn main() {
+            Some(1)
+        }
+    };
+
+    assert_eq!(result, Some(1));
+
+    assert_compile_error! {
+        r#"
+        enum Shape { Circle(r) }
+        use Shape;
+
+        pub fn main() {
+            Circle(2)
+        }
+        "#,
+        span, CompileErrorKind::MissingItem { item } => {
+            assert_eq!(span, span!(90, 96));
+            assert_eq!(item.to_string(), "Circle");
+        }
+    };
+}
+
+#[test]
+fn test_import_recursion_limit() -> Result<()> {
+    const SOURCE: &str = r#"
+    mod a0 { pub fn f() { 42 } }
+    mod a1 { pub use super::a0::f; }
+    mod a2 { pub use super::a1::f; }
+    mod a3 { pub use super::a2::f; }
+    mod a4 { pub use super::a3::f; }
+
+    pub fn main() {
+        a4::f()
+    }
+    "#;
+
+    let context = Context::with_default_modules()?;
+
+    let out: i64 = run(&context, SOURCE, ["main"], ())?;
+    assert_eq!(out, 42);
+
+    let mut options = crate::Options::default();
+    options.import_recursion_limit(2);
+
+    assert_compile_error! {
+        &options => SOURCE,
+        _span, kind @ QueryError(ImportRecursionLimit { count, limit, .. }) => {
+            assert_eq!(kind.to_string(), "Import recursion limit of 2 reached (3)");
+            assert_eq!(count, 3);
+            assert_eq!(limit, 2);
+        }
+    };
+
+    Ok(())
+}
+
+#[test]
+fn test_unused_imports() -> Result<()> {
+    #[derive(Default)]
+    struct UnusedImports {
+        unused: Vec<String>,
+    }
+
+    impl compile::CompileVisitor for UnusedImports {
+        fn visit_unused_import(&mut self, _: Location, item: &Item) {
+            self.unused.push(item.to_string());
+        }
+    }
+
+    let mut sources = crate::tests::sources(
+        r#"
+        mod a { pub fn f() { 1 } pub fn g() { 2 } pub fn h() { 3 } pub fn i() { 4 } }
+        mod b { pub fn k() { 4 } }
+        use a::f;
+        use a::g;
+        use a::i;
+        use a::h as renamed;
+        use b::*;
+        pub use a::g as exported;
+        pub(crate) use a::h as crate_exported;
+
+        pub fn main() {
+            f()
+        }
+
+        fn helper() {
+            i()
+        }
+        "#,
+    );
+
+    let context = Context::with_default_modules()?;
+    let mut diagnostics = Diagnostics::new();
+    let mut visitor = UnusedImports::default();
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .with_visitor(&mut visitor)
+        .build()?;
+
+    visitor.unused.sort();
+    assert_eq!(visitor.unused, ["g", "renamed"]);
+    Ok(())
+}
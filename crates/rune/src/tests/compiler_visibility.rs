@@ -3,6 +3,8 @@ prelude!();
 use CompileErrorKind::QueryError;
 use QueryErrorKind::*;
 
+use std::sync::Arc;
+
 #[test]
 fn test_working_visibility() {
     let output: i64 = rune! {
@@ -153,3 +155,195 @@ fn test_access_super() {
 
     assert_eq!(value, 1);
 }
+
+#[test]
+fn test_reexport_chain_diagnostics() -> Result<()> {
+    let mut sources = sources! {
+        entry => {
+            mod a { struct Foo; }
+            mod b { pub use crate::a::Foo; }
+            mod c { pub use crate::b::Foo; }
+
+            pub fn main() { c::Foo }
+        }
+    };
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = prepare(&mut sources)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    assert!(result.is_err());
+
+    let mut buffer = crate::termcolor::Buffer::no_color();
+    diagnostics.emit(&mut buffer, &sources)?;
+    let output = String::from_utf8(buffer.into_inner())?;
+
+    assert!(
+        output.contains("Step #1: `b::Foo` imported here"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Step #2: `a::Foo` re-exported here"),
+        "{output}"
+    );
+    assert!(
+        output.contains("Import chain: `b::Foo` -> `a::Foo`"),
+        "{output}"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_pub_in_path() {
+    let value: i64 = rune! {
+        mod a {
+            pub mod b {
+                pub mod c {
+                    pub(in a) fn f() { 1 }
+                    pub(in crate::a::b) fn g() { 2 }
+                    pub(in super::super) fn h() { 3 }
+                }
+
+                pub fn g() { c::g() }
+            }
+
+            pub fn test() { b::c::f() + b::g() + b::c::h() }
+        }
+
+        pub fn main() {
+            a::test()
+        }
+    };
+
+    assert_eq!(value, 6);
+
+    assert_compile_error! {
+        r#"
+        mod a { pub mod b { pub(in a) fn f() { 1 } } }
+        pub fn main() { a::b::f() }
+        "#,
+        span, QueryError(NotVisible { visibility, .. }) => {
+            assert_eq!(visibility.to_string(), "pub(in super)");
+            assert_eq!(span, span!(80, 87));
+        }
+    };
+
+    assert_compile_error! {
+        r#"
+        mod a { pub mod b { pub(in crate::a) fn f() { 1 } } pub fn g() { b::f() } }
+        mod c { pub fn g() { crate::a::b::f() } }
+        pub fn main() { c::g() }
+        "#,
+        span, QueryError(NotVisible { .. }) => {
+            assert_eq!(span, span!(114, 128));
+        }
+    };
+
+    assert_compile_error! {
+        r#"
+        mod a { pub(in b) fn f() { 1 } }
+        mod b {}
+        "#,
+        span, CompileErrorKind::VisibilityRestrictionNotAncestor { restriction, module } => {
+            assert_eq!(restriction.to_string(), "b");
+            assert_eq!(module.to_string(), "a");
+            assert_eq!(span, span!(24, 25));
+        }
+    };
+}
+
+#[test]
+fn test_pub_crate_not_exported() -> Result<()> {
+    let mut sources = sources! {
+        entry => {
+            mod a {
+                pub(crate) fn hidden() { 1 }
+                pub(crate) fn unused() { 3 }
+                pub fn visible() { 2 }
+            }
+
+            pub fn main() {
+                a::hidden() + a::visible()
+            }
+        }
+    };
+
+    let unit = prepare(&mut sources).build()?;
+    let mut vm = Vm::without_runtime(Arc::new(unit));
+
+    let value: i64 = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(value, 3);
+
+    let value: i64 = from_value(vm.call(["a", "visible"], ())?)?;
+    assert_eq!(value, 2);
+
+    // Crate visible items are not exported, so they're only available if
+    // they're used inside of the crate.
+    let error = vm.call(["a", "unused"], ()).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::MissingEntry { .. }));
+    Ok(())
+}
+
+#[test]
+fn test_compiled_items_visibility() -> Result<()> {
+    let mut sources = sources! {
+        entry => {
+            mod a {
+                pub struct Foo;
+                pub(crate) fn hidden() { 1 }
+                pub fn visible() { 2 }
+            }
+
+            mod b {
+                pub fn unexported() { 3 }
+            }
+
+            pub mod c {
+                pub fn exported() { 4 }
+            }
+
+            fn private() { 5 }
+
+            pub fn main() {
+                a::hidden() + a::visible() + private()
+            }
+        }
+    };
+
+    let mut items = compile::CompiledItems::new();
+    prepare(&mut sources)
+        .with_compiled_items(&mut items)
+        .build()?;
+
+    let public = items
+        .iter()
+        .filter(|i| i.is_public && matches!(i.kind, compile::meta::Kind::Function { .. }))
+        .map(|i| i.item.to_string())
+        .collect::<Vec<_>>();
+
+    assert_eq!(public, ["c::exported", "main"]);
+
+    let foo = items
+        .iter()
+        .find(|i| i.item.to_string() == "a::Foo")
+        .expect("missing a::Foo");
+
+    assert!(matches!(foo.visibility, compile::Visibility::Public));
+    assert!(!foo.is_public);
+    Ok(())
+}
+
+#[test]
+fn test_pub_crate_dependent_crate() {
+    use compile::{ItemBuf, Visibility};
+
+    let module = ItemBuf::with_item(["a"]);
+    let same = ItemBuf::with_item(["b"]);
+    let dependent = ItemBuf::with_crate_item("dependent", ["b"]);
+
+    assert!(Visibility::Crate.is_visible_from_crate(&same, &module));
+    assert!(!Visibility::Crate.is_visible_from_crate(&dependent, &module));
+    assert!(Visibility::Public.is_visible_from_crate(&dependent, &module));
+}
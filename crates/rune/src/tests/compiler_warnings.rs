@@ -1,6 +1,6 @@
 prelude!();
 
-use diagnostics::WarningDiagnosticKind::*;
+use diagnostics::WarningDiagnosticKind::{self, *};
 
 #[test]
 fn test_let_pattern_might_panic() {
@@ -31,3 +31,183 @@ fn test_remove_variant_parens() {
         }
     };
 }
+
+#[test]
+fn test_used_deprecated() -> Result<()> {
+    fn old_add(a: i64, b: i64) -> i64 {
+        a + b
+    }
+
+    let mut m = Module::new();
+    m.function(["old_add"], old_add)?
+        .deprecated("Use the `+` operator instead");
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main() { old_add(1, 2) + old_add(3, 4) }
+        }
+    };
+
+    let mut diagnostics = Diagnostics::new();
+
+    let _ = prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build()?;
+
+    let warnings = diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .map(|d| match d {
+            diagnostics::Diagnostic::Warning(warning) => warning.into_kind(),
+            d => panic!("unexpected diagnostic {d:?}"),
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(warnings.len(), 2);
+
+    for warning in warnings {
+        let UsedDeprecated { message, .. } = warning else {
+            panic!("expected deprecation warning but got {warning:?}");
+        };
+
+        assert_eq!(message, "Use the `+` operator instead");
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_used_deprecated_type() -> Result<()> {
+    #[derive(Any)]
+    struct Legacy;
+
+    let mut m = Module::new();
+    m.ty::<Legacy>()?.deprecated("Use `Modern` instead");
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main(value) { value is Legacy }
+        }
+    };
+
+    let mut diagnostics = Diagnostics::new();
+
+    let _ = prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build()?;
+
+    let diagnostics = diagnostics.into_diagnostics();
+
+    let [diagnostics::Diagnostic::Warning(warning)] = &diagnostics[..] else {
+        panic!("expected a single warning");
+    };
+
+    let UsedDeprecated { message, .. } = warning.into_kind() else {
+        panic!("expected deprecation warning but got {warning:?}");
+    };
+
+    assert_eq!(message, "Use `Modern` instead");
+    Ok(())
+}
+
+/// Build the given source with the given options, collecting the warnings
+/// which match the given filter.
+fn filter_warnings<T>(
+    source: &str,
+    options: &crate::Options,
+    filter: impl FnMut(WarningDiagnosticKind) -> Option<T>,
+) -> Result<Vec<T>> {
+    let context = Context::with_default_modules()?;
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("main", source));
+
+    let mut diagnostics = Diagnostics::new();
+
+    let _ = prepare(&mut sources)
+        .with_context(&context)
+        .with_options(options)
+        .with_diagnostics(&mut diagnostics)
+        .build()?;
+
+    Ok(diagnostics
+        .into_diagnostics()
+        .into_iter()
+        .filter_map(|d| match d {
+            diagnostics::Diagnostic::Warning(warning) => Some(warning.into_kind()),
+            _ => None,
+        })
+        .filter_map(filter)
+        .collect())
+}
+
+#[test]
+fn test_shadowed_local() -> Result<()> {
+    let shadowed = |kind| match kind {
+        ShadowedLocal { span, shadowed, .. } => Some((span, shadowed)),
+        _ => None,
+    };
+
+    let mut options = crate::Options::default();
+    options.warn_shadowing(true);
+
+    let source = r#"pub fn main() { let x = 1; let x = x + 1; { let x = 3; } x }"#;
+
+    assert_eq!(
+        filter_warnings(source, &options, shadowed)?,
+        [
+            (span!(31, 32), span!(20, 21)),
+            (span!(48, 49), span!(31, 32))
+        ]
+    );
+
+    // Deliberate shadowing is idiomatic, so the warning is opt-in.
+    assert_eq!(
+        filter_warnings(source, &crate::Options::default(), shadowed)?,
+        []
+    );
+
+    let source = r#"pub fn main() { let x = 1; let y = 2; let (a, b) = (x, y); a + b }"#;
+    assert_eq!(filter_warnings(source, &options, shadowed)?, []);
+    Ok(())
+}
+
+#[test]
+fn test_unreachable() -> Result<()> {
+    let unreachable = |kind| match kind {
+        Unreachable { span, cause, .. } => Some((span, cause)),
+        _ => None,
+    };
+
+    let mut options = crate::Options::default();
+    options.warn_unreachable(true);
+
+    let source = r#"pub fn main() { return 1; let x = 2; x }"#;
+
+    assert_eq!(
+        filter_warnings(source, &options, unreachable)?,
+        [(span!(26, 38), span!(16, 24))]
+    );
+
+    assert_eq!(
+        filter_warnings(source, &crate::Options::default(), unreachable)?,
+        []
+    );
+
+    let source = r#"pub fn main() { loop { break; 1 } for x in 0..2 { continue; } }"#;
+
+    assert_eq!(
+        filter_warnings(source, &options, unreachable)?,
+        [(span!(30, 31), span!(23, 28))]
+    );
+
+    Ok(())
+}
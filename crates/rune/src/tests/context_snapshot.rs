@@ -0,0 +1,34 @@
+prelude!();
+
+use crate::compile::ApiSnapshot;
+
+fn snapshot(m: Module) -> Result<ApiSnapshot> {
+    let mut context = Context::new();
+    context.install(m)?;
+    Ok(context.snapshot())
+}
+
+#[test]
+fn test_snapshot_diff() -> Result<()> {
+    let mut m = Module::with_crate("api");
+    m.function(["keep"], || 1i64)?;
+    m.function(["remove"], || 2i64)?;
+    let old = snapshot(m)?;
+
+    let mut m = Module::with_crate("api");
+    m.function(["keep"], || 1i64)?;
+    let new = snapshot(m)?;
+
+    let diff = old.diff(&new);
+    assert_eq!(diff.removed, ["::api::remove"]);
+    assert!(diff.added.is_empty());
+    assert!(diff.changed.is_empty());
+    assert!(diff.is_breaking());
+
+    let diff = new.diff(&old);
+    assert_eq!(diff.added, ["::api::remove"]);
+    assert!(!diff.is_breaking());
+
+    assert!(old.diff(&old).is_empty());
+    Ok(())
+}
@@ -21,6 +21,36 @@ fn test_asserts() {
     );
 }
 
+#[test]
+fn test_failing_asserts() {
+    assert_vm_error!(
+        "pub fn main() {\n    assert_eq!(1, 2);\n}",
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed at main:2:5 (left == right):\nleft: 1\nright: 2"
+            );
+        }
+    );
+
+    assert_vm_error!(
+        "pub fn main() {\n    assert_eq!(1, 2, \"numbers differ\");\n}",
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(
+                reason.to_string(),
+                "assertion failed at main:2:5 (left == right): numbers differ\nleft: 1\nright: 2"
+            );
+        }
+    );
+
+    assert_vm_error!(
+        "pub fn main() {\n    let value = 1;\n    assert!(value == 2);\n}",
+        VmErrorKind::Panic { reason } => {
+            assert_eq!(reason.to_string(), "assertion failed at main:3:5: value == 2");
+        }
+    );
+}
+
 #[test]
 fn test_stringify() {
     let out: String = rune!(
@@ -50,3 +50,74 @@ fn test_parse_in_macro() -> Result<()> {
     assert_eq!(output, (42, 42));
     Ok(())
 }
+
+#[test]
+fn test_macro_item_path() -> Result<()> {
+    let mut m = Module::default();
+
+    m.macro_(["item_path"], |ctx, _| {
+        let item = ctx.item().to_string();
+        let lit = ctx.lit(item);
+        Ok(quote!(#lit).into_token_stream(ctx))
+    })?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut sources = sources! {
+        entry => {
+            mod foo {
+                pub fn bar() {
+                    crate::item_path!()
+                }
+            }
+
+            pub fn main() {
+                (item_path!(), foo::bar())
+            }
+        }
+    };
+
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let output = vm.call(["main"], ())?;
+    let output: (String, String) = from_value(output)?;
+
+    assert_eq!(
+        output,
+        (String::from("main::$0"), String::from("foo::bar::$0"))
+    );
+    Ok(())
+}
+
+#[test]
+fn test_path_qualified_macro() -> Result<()> {
+    let mut m = Module::with_crate_item("mymod", ["inner"]);
+
+    m.macro_(["double"], |ctx, stream| {
+        let mut p = Parser::from_token_stream(stream, ctx.stream_span());
+        let expr = p.parse_all::<ast::Expr>()?;
+        Ok(quote!((#expr) * 2).into_token_stream(ctx))
+    })?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main() {
+                (mymod::inner::double!(1 + 2), ::mymod::inner::double!(10))
+            }
+        }
+    };
+
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+    let output = vm.call(["main"], ())?;
+    let output: (i64, i64) = from_value(output)?;
+
+    assert_eq!(output, (6, 20));
+    Ok(())
+}
@@ -0,0 +1,63 @@
+prelude!();
+
+use crate::compile::ItemBuf;
+use crate::doc::Context as DocContext;
+
+/// Add two numbers.
+///
+/// ```rune
+/// let n = add(1, 2);
+/// assert_eq!(n, 3);
+/// ```
+///
+/// Some text in between.
+///
+/// ```rune,should_panic
+/// add(1, "two");
+/// ```
+///
+/// ```
+/// add(0, 0)
+/// ```
+///
+/// ```text
+/// not code
+/// ```
+#[rune::function]
+fn add(a: i64, b: i64) -> i64 {
+    a + b
+}
+
+#[test]
+fn test_doc_examples() -> Result<()> {
+    let mut m = Module::with_crate("a");
+    m.function_meta(add)?;
+
+    let mut context = Context::new();
+    context.install(m)?;
+
+    let doc = DocContext::new(&context, &[]);
+    let meta = doc.meta(&ItemBuf::with_crate_item("a", ["add"]));
+    assert_eq!(meta.len(), 1);
+
+    let examples = meta[0]
+        .examples()
+        .into_iter()
+        .map(|e| (e.language, e.code))
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        examples,
+        [
+            (
+                String::from("rune"),
+                String::from("let n = add(1, 2);\nassert_eq!(n, 3);\n")
+            ),
+            (String::from("rune"), String::from("add(1, \"two\");\n")),
+            (String::from("rune"), String::from("add(0, 0)\n")),
+            (String::from("text"), String::from("not code\n")),
+        ]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,59 @@
+prelude!();
+
+use std::fs;
+use std::process;
+
+use crate::doc::Visitor;
+
+#[derive(Any)]
+#[rune(item = ::a)]
+struct Foo;
+
+/// Construct a foo.
+#[rune::function]
+fn make() -> Foo {
+    Foo
+}
+
+#[test]
+fn test_cross_crate_links() -> Result<()> {
+    let mut a = Module::with_crate("a");
+    a.ty::<Foo>()?;
+
+    let mut b = Module::with_crate("b");
+    b.function_meta(make)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(a)?;
+    context.install(b)?;
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "entry",
+        r#"
+        /// Use a [Foo][::a::Foo].
+        pub fn main() {
+            b::make()
+        }
+        "#,
+    ));
+
+    let mut visitor = Visitor::new(["entry"]);
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_visitor(&mut visitor)
+        .build()?;
+
+    let root = std::env::temp_dir().join(format!("rune-doc-links-{}", process::id()));
+    crate::doc::write_html("root", &root, &context, &[visitor])?;
+
+    let make = fs::read_to_string(root.join("b").join("make.fn.html"));
+    let entry = fs::read_to_string(root.join("entry").join("main.fn.html"));
+    fs::remove_dir_all(&root)?;
+
+    assert!(make?.contains("<a class=\"struct\" href=\"../a/Foo.struct.html\">Foo</a>"));
+    assert!(entry?
+        .contains("Use a <a href=\"../a/Foo.struct.html\" title=\"struct ::a::Foo\">Foo</a>."));
+    Ok(())
+}
@@ -0,0 +1,293 @@
+prelude!();
+
+use crate::compile::{meta, ItemBuf};
+use crate::doc::context::{Assoc, AssocFnKind, Kind};
+use crate::doc::Context as DocContext;
+use crate::doc::Visitor;
+
+#[derive(Any)]
+#[rune(item = ::a)]
+struct Foo;
+
+/// Construct a foo.
+///
+/// More details.
+#[rune::function]
+fn make() -> Foo {
+    Foo
+}
+
+/// Fetch a foo.
+#[rune::function]
+async fn fetch() -> Foo {
+    Foo
+}
+
+impl Foo {
+    /// Instance function which should not be listed.
+    #[rune::function]
+    fn method(&self) {}
+
+    /// Asynchronous instance function.
+    #[rune::function]
+    async fn wait(&self) {}
+}
+
+#[test]
+fn test_module_contents() -> Result<()> {
+    let mut a = Module::with_crate("a");
+    a.ty::<Foo>()?;
+    a.function_meta(make)?;
+    a.function_meta(Foo::method)?;
+    a.constant(["LIMIT"], 10i64)?.docs(["The limit."]);
+
+    let mut sub = Module::with_crate_item("a", ["sub"]);
+    sub.function(["inner"], || ())?;
+
+    let mut context = Context::new();
+    context.install(a)?;
+    context.install(sub)?;
+
+    let doc = DocContext::new(&context, &[]);
+    let item = ItemBuf::with_crate("a");
+    let contents = doc.module_contents(&item);
+
+    macro_rules! names {
+        ($entries:expr) => {
+            $entries
+                .iter()
+                .map(|e| e.name.to_string())
+                .collect::<Vec<_>>()
+        };
+    }
+
+    assert_eq!(names!(contents.structs), ["Foo"]);
+    assert_eq!(names!(contents.functions), ["make"]);
+    assert_eq!(names!(contents.consts), ["LIMIT"]);
+    assert_eq!(names!(contents.modules), ["sub"]);
+    assert!(contents.types.is_empty());
+    assert!(contents.enums.is_empty());
+    assert!(contents.macros.is_empty());
+
+    assert_eq!(contents.functions[0].summary, Some(" Construct a foo."));
+    assert_eq!(contents.consts[0].summary, Some("The limit."));
+    Ok(())
+}
+
+#[test]
+fn test_async_functions() -> Result<()> {
+    let mut a = Module::with_crate("a");
+    a.ty::<Foo>()?;
+    a.function_meta(make)?;
+    a.function_meta(fetch)?;
+    a.function_meta(Foo::method)?;
+    a.function_meta(Foo::wait)?;
+
+    let mut context = Context::new();
+    context.install(a)?;
+
+    let doc = DocContext::new(&context, &[]);
+    let item = ItemBuf::with_crate("a");
+    let contents = doc.module_contents(&item);
+
+    let mut functions = contents
+        .functions
+        .iter()
+        .map(|e| match e.meta.kind {
+            Kind::Function(f) => (e.name.to_string(), f.is_async),
+            _ => panic!("expected function"),
+        })
+        .collect::<Vec<_>>();
+
+    functions.sort();
+
+    assert_eq!(
+        functions,
+        [("fetch".to_string(), true), ("make".to_string(), false)]
+    );
+
+    let mut methods = doc
+        .associated(<Foo as TypeOf>::type_hash())
+        .filter_map(|assoc| match assoc {
+            Assoc::Fn(f) => match f.kind {
+                AssocFnKind::Method(name, ..) => Some((name, f.is_async)),
+                _ => None,
+            },
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    methods.sort();
+
+    assert_eq!(methods, [("method", false), ("wait", true)]);
+    Ok(())
+}
+
+#[test]
+fn test_function_alias_docs() -> Result<()> {
+    let mut a = Module::with_crate("a");
+    a.function_meta(make)?;
+    a.function_alias(["make"], ["create"])?;
+
+    let mut context = Context::new();
+    context.install(a)?;
+
+    let doc = DocContext::new(&context, &[]);
+    let item = ItemBuf::with_crate("a");
+    let contents = doc.module_contents(&item);
+
+    let mut functions = contents
+        .functions
+        .iter()
+        .map(|e| (e.name.to_string(), e.summary))
+        .collect::<Vec<_>>();
+
+    functions.sort();
+
+    assert_eq!(
+        functions,
+        [
+            ("create".to_string(), Some("Alias of `make`.")),
+            ("make".to_string(), Some(" Construct a foo.")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_unstable_items() -> Result<()> {
+    let mut a = Module::with_crate("a");
+    a.ty::<Foo>()?.unstable();
+    a.function_meta(make)?.unstable();
+    a.function_meta(fetch)?;
+    a.constant(["LIMIT"], 10i64)?.unstable();
+
+    let mut context = Context::new();
+    context.install(a)?;
+
+    let doc = DocContext::new(&context, &[]);
+
+    let mut unstable = doc
+        .unstable_items()
+        .flat_map(|meta| Some(meta.item?.to_string()))
+        .collect::<Vec<_>>();
+
+    unstable.sort();
+    assert_eq!(unstable, ["::a::Foo", "::a::LIMIT", "::a::make"]);
+
+    let item = ItemBuf::with_crate("a");
+    let contents = doc.module_contents(&item);
+
+    let mut functions = contents
+        .functions
+        .iter()
+        .map(|e| (e.name.to_string(), e.meta.unstable))
+        .collect::<Vec<_>>();
+
+    functions.sort();
+
+    assert_eq!(
+        functions,
+        [("fetch".to_string(), false), ("make".to_string(), true)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_std_float_constants() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let doc = DocContext::new(&context, &[]);
+    let item = ItemBuf::with_crate_item("std", ["float"]);
+    let contents = doc.module_contents(&item);
+
+    let mut consts = contents
+        .consts
+        .iter()
+        .map(|e| (e.name.to_string(), e.summary))
+        .collect::<Vec<_>>();
+
+    consts.sort();
+
+    assert_eq!(
+        consts,
+        [
+            ("E".to_string(), Some("Euler's number (e).")),
+            ("PI".to_string(), Some("Archimedes' constant (π).")),
+            ("TAU".to_string(), Some("The full circle constant (τ).")),
+        ]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn test_variant_fields_and_enum() -> Result<()> {
+    let context = Context::new();
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new(
+        "entry",
+        r#"
+        /// An enum.
+        enum Enum {
+            /// Named variant.
+            A { b, a },
+            /// Unnamed variant.
+            B(x, y),
+            /// Empty variant.
+            C,
+        }
+        "#,
+    ));
+
+    let mut visitor = Visitor::new(["entry"]);
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_visitor(&mut visitor)
+        .build()?;
+
+    let visitors = [visitor];
+    let doc = DocContext::new(&context, &visitors);
+
+    let item = ItemBuf::with_item(["entry", "Enum"]);
+    let metas = doc.meta(&item);
+    let [meta] = &metas[..] else {
+        panic!("expected exactly one enum meta");
+    };
+
+    assert!(matches!(meta.kind, Kind::Enum));
+    let enum_hash = meta.hash;
+
+    let mut variants = doc
+        .associated(enum_hash)
+        .filter_map(|assoc| match assoc {
+            Assoc::Variant(variant) => Some(variant),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    variants.sort_by_key(|variant| variant.name);
+
+    let names = variants.iter().map(|v| v.name).collect::<Vec<_>>();
+    assert_eq!(names, ["A", "B", "C"]);
+
+    for variant in &variants {
+        assert_eq!(variant.enum_hash, enum_hash);
+    }
+
+    match variants[0].fields {
+        meta::Fields::Named(named) => {
+            // Fields are kept in the order they were declared.
+            let fields = named.fields.iter().map(|f| &**f).collect::<Vec<&str>>();
+            assert_eq!(fields, ["b", "a"]);
+        }
+        fields => panic!("expected named fields, got {fields:?}"),
+    }
+
+    assert!(matches!(variants[1].fields, meta::Fields::Unnamed(2)));
+    assert!(matches!(variants[2].fields, meta::Fields::Empty));
+    Ok(())
+}
@@ -0,0 +1,107 @@
+prelude!();
+
+use std::path::Path;
+
+use crate::ast::Span;
+use crate::compile::{Options, SourceLoader};
+
+/// Loader which serves modules from memory, counting how often it is used.
+#[derive(Default)]
+struct MemoryLoader {
+    loads: usize,
+}
+
+impl SourceLoader for MemoryLoader {
+    fn load(&mut self, _: &Path, _: &Item, _: Span) -> compile::Result<Source> {
+        self.loads += 1;
+        Ok(Source::new("foo", "pub fn value() { 42 }"))
+    }
+}
+
+fn build(options: &Options, loader: &mut MemoryLoader, source: &str) -> Result<(), Diagnostics> {
+    let context = Context::with_default_modules().expect("setting up default modules");
+
+    let mut sources = Sources::new();
+    sources.insert(Source::with_path("main", source, "main.rn"));
+
+    let mut diagnostics = Diagnostics::new();
+
+    let result = prepare(&mut sources)
+        .with_context(&context)
+        .with_options(options)
+        .with_source_loader(loader)
+        .with_diagnostics(&mut diagnostics)
+        .build();
+
+    match result {
+        Ok(_) => Ok(()),
+        Err(_) => Err(diagnostics),
+    }
+}
+
+#[test]
+fn test_file_modules_disabled() {
+    let source = r#"mod foo; pub fn main() { foo::value() }"#;
+
+    let mut loader = MemoryLoader::default();
+    assert!(build(&Options::default(), &mut loader, source).is_ok());
+    assert_eq!(loader.loads, 1);
+
+    let mut options = Options::default();
+    options.file_modules(false);
+
+    let mut loader = MemoryLoader::default();
+    let diagnostics = build(&options, &mut loader, source).unwrap_err();
+    assert_eq!(loader.loads, 0);
+
+    let mut it = diagnostics.into_diagnostics().into_iter();
+
+    let Some(diagnostics::Diagnostic::Fatal(e)) = it.next() else {
+        panic!("expected fatal diagnostic");
+    };
+
+    let diagnostics::FatalDiagnosticKind::CompileError(e) = e.into_kind() else {
+        panic!("expected compile error");
+    };
+
+    assert_eq!(ast::Spanned::span(&e), span!(0, 8));
+    assert!(matches!(
+        e.into_kind(),
+        CompileErrorKind::FileModulesDisabled
+    ));
+
+    // Imports from the context are still allowed.
+    let source = r#"use std::iter::range; pub fn main() { range(0, 3).count() }"#;
+
+    let mut loader = MemoryLoader::default();
+    assert!(build(&options, &mut loader, source).is_ok());
+}
+
+#[test]
+fn test_inline_modules() {
+    let out: i64 = rune! {
+        mod name {
+            pub fn f() { inner::g() + 1 }
+
+            mod inner {
+                pub fn g() { 41 }
+            }
+        }
+
+        pub fn main() {
+            name::f()
+        }
+    };
+
+    assert_eq!(out, 42);
+
+    // Inline modules never consult the source loader, so they're available
+    // even if file modules are disabled.
+    let mut options = Options::default();
+    options.file_modules(false);
+
+    let mut loader = MemoryLoader::default();
+    let source = r#"mod name { pub fn f() { 42 } } pub fn main() { name::f() }"#;
+    assert!(build(&options, &mut loader, source).is_ok());
+    assert_eq!(loader.loads, 0);
+}
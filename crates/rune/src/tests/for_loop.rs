@@ -53,3 +53,21 @@ fn test_ignore_binding() {
 
     assert_eq!(out, 4);
 }
+
+#[test]
+fn test_enumerate_binding() {
+    let out: Vec<(i64, i64)> = rune! {
+        pub fn main() {
+            let items = [10, 20, 30];
+            let out = [];
+
+            for (i, x) in items.enumerate() {
+                out.push((i, x));
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, vec![(0, 10), (1, 20), (2, 30)]);
+}
@@ -120,3 +120,71 @@ fn test_sort() {
     };
     assert_eq!(values, vec![1, 2, 3, 4]);
 }
+
+#[test]
+fn test_generic_bounds_in_meta() -> Result<()> {
+    use std::collections::BTreeMap;
+
+    #[derive(Default)]
+    struct GenericsVisitor {
+        collected: BTreeMap<String, Vec<(String, Vec<String>)>>,
+    }
+
+    impl compile::CompileVisitor for GenericsVisitor {
+        fn register_meta(&mut self, meta: compile::MetaRef<'_>) {
+            let compile::meta::Kind::Function { signature, .. } = meta.kind else {
+                return;
+            };
+
+            let generics = signature
+                .generics()
+                .iter()
+                .map(|p| {
+                    let bounds = p.bounds.iter().map(|b| b.to_string()).collect();
+                    (p.name.to_string(), bounds)
+                })
+                .collect();
+
+            self.collected.insert(meta.item.to_string(), generics);
+        }
+    }
+
+    let mut sources = crate::tests::sources(
+        r#"
+        pub fn plain(x) { x }
+        pub fn show<T: Display, U>(x, y) { (x, y) }
+        pub fn both<T: std::fmt::Display + Clone>(x) { x }
+        "#,
+    );
+
+    let context = Context::default();
+    let mut diagnostics = Diagnostics::new();
+    let mut vis = GenericsVisitor::default();
+
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .with_visitor(&mut vis)
+        .build()?;
+
+    let expected = BTreeMap::from([
+        (
+            "both".to_string(),
+            vec![(
+                "T".to_string(),
+                vec!["std::fmt::Display".to_string(), "Clone".to_string()],
+            )],
+        ),
+        ("plain".to_string(), vec![]),
+        (
+            "show".to_string(),
+            vec![
+                ("T".to_string(), vec!["Display".to_string()]),
+                ("U".to_string(), vec![]),
+            ],
+        ),
+    ]);
+
+    assert_eq!(vis.collected, expected);
+    Ok(())
+}
@@ -44,3 +44,48 @@ fn test_getter_setter() -> Result<()> {
     assert!(matches!(output, Value::Unit));
     Ok(())
 }
+
+#[derive(Any, Debug)]
+struct Rect {
+    width: i64,
+    height: i64,
+}
+
+#[test]
+fn test_computed_field_functions() -> Result<()> {
+    let mut module = Module::new();
+    module.ty::<Rect>()?;
+    module.field_function(Protocol::GET, "area", |r: &Rect| r.width * r.height)?;
+    module.field_function(Protocol::SET, "side", |r: &mut Rect, side: i64| {
+        r.width = side;
+        r.height = side;
+    })?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main(rect) {
+                let before = rect.area;
+                rect.side = 3;
+                (before, rect.area)
+            }
+        }
+    };
+
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let mut rect = Rect {
+        width: 2,
+        height: 5,
+    };
+
+    let output: (i64, i64) = from_value(vm.call(["main"], (&mut rect,))?)?;
+
+    assert_eq!(output, (10, 9));
+    assert_eq!((rect.width, rect.height), (3, 3));
+    Ok(())
+}
@@ -1,5 +1,7 @@
 prelude!();
 
+use std::sync::Arc;
+
 #[test]
 fn test_basic_self() {
     let _: () = rune! {
@@ -42,3 +44,267 @@ fn test_chaining() {
         }
     };
 }
+
+#[test]
+fn test_instance_methods_of() -> Result<()> {
+    #[rune::function(instance)]
+    fn shout(string: &str) -> String {
+        string.to_uppercase()
+    }
+
+    #[rune::function(instance)]
+    fn whisper(string: &str) -> String {
+        string.to_lowercase()
+    }
+
+    let mut module = Module::new();
+    module.function_meta(shout)?;
+    module.function_meta(whisper)?;
+
+    let mut context = Context::with_default_modules()?;
+    let before = context.instance_methods_of(String::type_hash()).count();
+    context.install(module)?;
+
+    let methods = context
+        .instance_methods_of(String::type_hash())
+        .skip(before)
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        methods,
+        [
+            (
+                "shout",
+                Hash::associated_function(String::type_hash(), "shout")
+            ),
+            (
+                "whisper",
+                Hash::associated_function(String::type_hash(), "whisper")
+            ),
+        ]
+    );
+
+    assert!(context
+        .instance_methods_of(String::type_hash())
+        .any(|(name, _)| name == "len"));
+
+    assert_eq!(context.instance_methods_of(Hash::EMPTY).count(), 0);
+    Ok(())
+}
+
+#[test]
+fn test_extend_type_from_other_module() -> Result<()> {
+    #[derive(Any)]
+    struct Counter {
+        value: i64,
+    }
+
+    fn counter_new(value: i64) -> Counter {
+        Counter { value }
+    }
+
+    fn counter_doubled(counter: &Counter) -> i64 {
+        counter.value * 2
+    }
+
+    let mut base = Module::new();
+    base.ty::<Counter>()?;
+    base.function(["Counter", "new"], counter_new)?;
+
+    let mut extension = Module::new();
+    extension.associated_function("doubled", counter_doubled)?;
+
+    // The type has to be installed before it can be extended.
+    let mut context = Context::with_default_modules()?;
+    assert!(matches!(
+        context.install(&extension),
+        Err(ContextError::MissingContainer { .. })
+    ));
+
+    context.install(&base)?;
+    context.install(&extension)?;
+
+    let mut conflicting = Module::new();
+    conflicting.associated_function("doubled", counter_doubled)?;
+
+    let error = context.install(&conflicting).unwrap_err();
+
+    assert!(matches!(
+        &error,
+        ContextError::ConflictingInstanceFunction { name, .. } if name.as_ref() == "doubled"
+    ));
+
+    let out: i64 = run(
+        &context,
+        "pub fn main() { Counter::new(21).doubled() }",
+        ["main"],
+        (),
+    )?;
+
+    assert_eq!(out, 42);
+    Ok(())
+}
+
+#[test]
+fn test_validate_module() -> Result<()> {
+    #[derive(Any)]
+    struct Counter;
+
+    fn counter_get(_: &Counter) -> i64 {
+        42
+    }
+
+    let mut extension = Module::new();
+    extension.associated_function("get", counter_get)?;
+
+    let context = Context::with_default_modules()?;
+    let errors = context.validate(&extension);
+
+    assert!(matches!(
+        &errors[..],
+        [ContextError::MissingContainer { .. }]
+    ));
+
+    // Registering the type in the same module resolves the problem.
+    let mut module = Module::new();
+    module.ty::<Counter>()?;
+    module.associated_function("get", counter_get)?;
+    assert!(context.validate(&module).is_empty());
+
+    let mut context = context;
+    context.install(&module)?;
+
+    // Every conflict is reported, not just the first one.
+    let errors = context.validate(&module);
+
+    assert!(matches!(
+        &errors[..],
+        [
+            ContextError::ConflictingType { .. },
+            ContextError::ConflictingInstanceFunction { .. }
+        ]
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_implemented_protocols() -> Result<()> {
+    use core::fmt::{self, Write};
+
+    #[derive(Any)]
+    struct Point {
+        x: i64,
+    }
+
+    impl Point {
+        fn display(&self, f: &mut String) -> fmt::Result {
+            write!(f, "Point({})", self.x)
+        }
+
+        fn add(&self, other: &Point) -> Point {
+            Point {
+                x: self.x + other.x,
+            }
+        }
+    }
+
+    let mut module = Module::new();
+    module.ty::<Point>()?;
+    module.associated_function(Protocol::STRING_DISPLAY, Point::display)?;
+    module.associated_function("add", Point::add)?;
+    module.associated_function(Protocol::ADD, Point::add)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let protocols = context.implemented_protocols(<Point as Any>::type_hash());
+    assert_eq!(protocols, [Protocol::STRING_DISPLAY, Protocol::ADD]);
+
+    // A conflicting protocol function isn't installed, so it isn't recorded.
+    let mut conflicting = Module::new();
+    conflicting.associated_function(Protocol::ADD, Point::add)?;
+    assert!(context.install(conflicting).is_err());
+
+    let protocols = context.implemented_protocols(<Point as Any>::type_hash());
+    assert_eq!(protocols, [Protocol::STRING_DISPLAY, Protocol::ADD]);
+
+    assert!(context.implemented_protocols(Hash::EMPTY).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_validate_module_internal_enum() -> Result<()> {
+    use crate::runtime::GeneratorState;
+
+    let mut module = Module::with_crate_item("std", ["generator"]);
+    module.generator_state(["GeneratorState"])?;
+    module.associated_function("is_complete", GeneratorState::is_complete)?;
+
+    // The internal enum is installed by the module itself, so it's a valid
+    // container.
+    let mut context = Context::new();
+    assert!(context.validate(&module).is_empty());
+
+    context.install(&module)?;
+
+    let errors = context.validate(&module);
+
+    assert!(matches!(
+        &errors[..],
+        [
+            ContextError::InternalAlreadyPresent { .. },
+            ContextError::ConflictingInstanceFunction { .. }
+        ]
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_stateful_instance_method() -> Result<()> {
+    use std::sync::Mutex;
+
+    #[derive(Any)]
+    struct Counter;
+
+    let total = Arc::new(Mutex::new(0i64));
+
+    let mut module = Module::new();
+    module.ty::<Counter>()?;
+    module.function(["Counter", "new"], || Counter)?;
+    module.associated_function("add", {
+        let total = total.clone();
+
+        move |_: &Counter, n: i64| {
+            let mut total = total.lock().unwrap();
+            *total += n;
+            *total
+        }
+    })?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main() {
+                let counter = Counter::new();
+                counter.add(1);
+                counter.add(2);
+                counter.add(3)
+            }
+        }
+    };
+
+    let mut vm = crate::tests::vm(&context, &mut sources, &mut Diagnostics::new())?;
+    let out: i64 = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(out, 6);
+
+    // State is shared by every virtual machine using the same context.
+    let mut vm = vm.clone();
+    let out: i64 = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(out, 12);
+    assert_eq!(*total.lock().unwrap(), 12);
+    Ok(())
+}
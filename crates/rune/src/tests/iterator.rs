@@ -164,3 +164,139 @@ fn test_flat_map() {
 
     assert_eq!(actual, expected);
 }
+
+#[test]
+fn test_native_iterator() -> Result<()> {
+    #[derive(Any)]
+    struct Countdown {
+        count: i64,
+    }
+
+    impl Countdown {
+        fn new(count: i64) -> Self {
+            Self { count }
+        }
+
+        fn next(&mut self) -> Option<i64> {
+            if self.count == 0 {
+                return None;
+            }
+
+            self.count -= 1;
+            Some(self.count)
+        }
+    }
+
+    let mut module = Module::new();
+    module.ty::<Countdown>()?;
+    module.function(["Countdown", "new"], Countdown::new)?;
+    module.iterator(Countdown::next)?;
+
+    let values: Vec<i64> = rune_n! {
+        &module,
+        (),
+        Vec<i64> =>
+        pub fn main() {
+            let out = [];
+
+            for n in Countdown::new(4) {
+                out.push(n);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(values, [3, 2, 1, 0]);
+    Ok(())
+}
+
+#[test]
+fn test_function_iter() -> Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    let produced = Arc::new(AtomicUsize::new(0));
+
+    let mut module = Module::new();
+
+    module.function_iter(["numbers"], {
+        let produced = produced.clone();
+
+        move |args: Vec<Value>| {
+            let produced = produced.clone();
+
+            let count = match args.first() {
+                Some(Value::Integer(count)) => *count,
+                _ => 0,
+            };
+
+            (0..count).map(move |n| {
+                produced.fetch_add(1, Ordering::SeqCst);
+                n
+            })
+        }
+    })?;
+
+    let out: (i64, i64) = rune_n! {
+        module,
+        (),
+        (i64, i64) =>
+        pub fn main() {
+            let sum = 0;
+            let last = 0;
+
+            for n in numbers(1000) {
+                sum += n;
+                last = n;
+            }
+
+            (sum, last)
+        }
+    };
+
+    assert_eq!(out, ((0..1000).sum(), 999));
+    assert_eq!(produced.load(Ordering::SeqCst), 1000);
+
+    produced.store(0, Ordering::SeqCst);
+
+    let mut module = Module::new();
+
+    module.function_iter(["numbers"], {
+        let produced = produced.clone();
+
+        move |_: Vec<Value>| {
+            let produced = produced.clone();
+
+            (0..).map(move |n: i64| {
+                produced.fetch_add(1, Ordering::SeqCst);
+                n
+            })
+        }
+    })?;
+
+    let out: Vec<i64> = rune_n! {
+        &module,
+        (),
+        Vec<i64> =>
+        pub fn main() {
+            numbers().take(3).collect::<Vec>()
+        }
+    };
+
+    assert_eq!(out, [0, 1, 2]);
+    assert_eq!(produced.load(Ordering::SeqCst), 3);
+
+    let out: Value = rune_n! {
+        &module,
+        (),
+        Value => pub fn main() { numbers() }
+    };
+
+    let Value::Iterator(iter) = out else {
+        panic!("expected iterator");
+    };
+
+    assert_eq!(format!("{:?}", *iter.borrow_ref()?), "numbers");
+    Ok(())
+}
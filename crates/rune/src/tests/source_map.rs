@@ -0,0 +1,50 @@
+prelude!();
+
+#[test]
+fn test_function_source_map() -> Result<()> {
+    const SOURCE: &str = r#"
+        fn helper() {
+            1
+        }
+
+        pub fn main() {
+            let a = helper();
+            a + 2
+        }
+    "#;
+
+    let mut sources = Sources::new();
+    sources.insert(Source::new("entry", SOURCE));
+
+    let unit = prepare(&mut sources).build()?;
+    let debug = unit.debug_info().expect("missing debug info");
+
+    let hash = Hash::type_hash(["main"]);
+    let range = debug.function_range(hash).expect("missing function range");
+    assert!(range.start < range.end);
+
+    let spans = debug
+        .function_source_map(hash)
+        .map(|(_, inst)| &SOURCE[inst.span.range()])
+        .collect::<Vec<_>>();
+
+    assert!(spans.contains(&"a + 2"), "{spans:?}");
+
+    for (ip, _) in debug.function_source_map(hash) {
+        assert!(range.contains(&ip));
+        let (found, signature) = debug.function_containing(ip).expect("missing function");
+        assert_eq!(found, hash);
+        assert_eq!(signature.path.to_string(), "main");
+    }
+
+    let helper = Hash::type_hash(["helper"]);
+    assert!(debug.function_range(helper).is_some());
+
+    for (ip, _) in debug.function_source_map(helper) {
+        let (found, signature) = debug.function_containing(ip).expect("missing function");
+        assert_eq!(found, helper);
+        assert_eq!(signature.path.to_string(), "helper");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,146 @@
+prelude!();
+
+use crate::SourceId;
+
+#[test]
+fn test_insert_unique() {
+    let mut sources = Sources::new();
+
+    let a = sources.insert_unique(Source::new("a.rn", "pub fn main() { 42 }"));
+    let b = sources.insert_unique(Source::new("./a.rn", "pub fn main() { 42 }"));
+    let c = sources.insert_unique(Source::new("c.rn", "pub fn main() { 43 }"));
+
+    assert_eq!(a, b);
+    assert_ne!(a, c);
+    assert_eq!(sources.get(a).map(Source::name), Some("a.rn"));
+    assert!(sources.get(SourceId::new(2)).is_none());
+
+    assert_ne!(sources.content_hash(a), sources.content_hash(c));
+    assert!(sources.content_hash(SourceId::new(2)).is_none());
+
+    // Regular inserts always allocate a new source, but hash the same.
+    let d = sources.insert(Source::new("d.rn", "pub fn main() { 42 }"));
+    assert_ne!(a, d);
+    assert_eq!(sources.content_hash(a), sources.content_hash(d));
+}
+
+#[test]
+#[cfg(feature = "emit")]
+fn test_line_starts() {
+    fn naive(source: &str) -> Vec<usize> {
+        let mut out = vec![0];
+
+        for (n, b) in source.bytes().enumerate() {
+            if b == b'\n' {
+                out.push(n + 1);
+            }
+        }
+
+        out
+    }
+
+    for input in [
+        "",
+        "\n",
+        "a",
+        "a\nbc\r\n\nlast",
+        "ends with newline\n",
+        "ö\nä\n\nå",
+    ] {
+        let source = Source::new("test", input);
+        assert_eq!(source.line_starts(), naive(input), "{input:?}");
+        assert_eq!(source.line_count(), source.line_starts().len());
+
+        for (line, &start) in source.line_starts().iter().enumerate() {
+            assert_eq!(source.line_index(start), line, "{input:?}");
+        }
+    }
+
+    let source = Source::new("test", "a\nbc\r\n\nlast");
+    assert_eq!(source.line_index(3), 1);
+    assert_eq!(source.line_range(1), Some(2..6));
+    assert_eq!(source.line_range(3), Some(7..11));
+    assert_eq!(source.line_range(4), None);
+}
+
+#[test]
+fn test_from_reader() -> Result<()> {
+    let reader = std::io::Cursor::new(b"pub fn main() { 20 + 22 }".to_vec());
+    let source = Source::from_reader("stdin", reader)?;
+    assert_eq!(source.name(), "stdin");
+
+    let mut sources = Sources::new();
+    sources.insert(source);
+
+    let context = Context::with_default_modules()?;
+    let mut vm = crate::tests::vm(&context, &mut sources, &mut Diagnostics::new())?;
+    let out: i64 = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(out, 42);
+
+    let error = Source::from_reader("stdin", &b"pub fn main() { \xff }"[..]).unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    Ok(())
+}
+
+#[test]
+fn test_visual_columns() {
+    let source = Source::new("test", "pub fn main() {\n\tlet x = y;\n  \t\tz\n}");
+
+    let y = 25;
+    assert_eq!(source.pos_to_utf8_linecol(y), (1, 9));
+    assert_eq!(source.pos_to_visual_linecol(y, 1), (1, 9));
+    assert_eq!(source.pos_to_visual_linecol(y, 0), (1, 9));
+    assert_eq!(source.pos_to_visual_linecol(y, 4), (1, 12));
+
+    // Tabs following other characters only advance to the next tab stop.
+    let z = 32;
+    assert_eq!(source.pos_to_visual_linecol(z, 1), (2, 4));
+    assert_eq!(source.pos_to_visual_linecol(z, 4), (2, 8));
+    assert_eq!(source.pos_to_visual_linecol(z, 8), (2, 16));
+}
+
+#[test]
+fn test_unused_entries_from() -> Result<()> {
+    use crate::diagnostics::{Diagnostic, WarningDiagnosticKind};
+
+    fn not_used(diagnostics: &Diagnostics) -> Vec<SourceId> {
+        diagnostics
+            .diagnostics()
+            .iter()
+            .filter_map(|d| match d {
+                Diagnostic::Warning(w)
+                    if matches!(w.kind(), WarningDiagnosticKind::NotUsed { .. }) =>
+                {
+                    Some(w.source_id())
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    let mut sources = Sources::new();
+    let a = sources.insert(Source::new("a", "pub fn main() { 42 } fn unused_a() { 1 }"));
+    let b = sources.insert(Source::new("b", "fn unused_b() { 2 }"));
+
+    let context = Context::with_default_modules()?;
+
+    let mut diagnostics = Diagnostics::new();
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .build()?;
+
+    let mut unused = not_used(&diagnostics);
+    unused.sort();
+    assert_eq!(unused, [a, b]);
+
+    let mut diagnostics = Diagnostics::new();
+    prepare(&mut sources)
+        .with_context(&context)
+        .with_diagnostics(&mut diagnostics)
+        .with_unused_entries_from(&[a])
+        .build()?;
+
+    assert_eq!(not_used(&diagnostics), [a]);
+    Ok(())
+}
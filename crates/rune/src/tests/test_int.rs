@@ -58,3 +58,33 @@ fn test_int_fns() {
     };
     assert_eq!(n, 1728);
 }
+
+#[test]
+fn test_checked_conversions() {
+    let n: (i64, i64, u8) = rune! {
+        pub fn main() {
+            (int::from_float(42.9), int::from_float(-1.5), 255.to_byte())
+        }
+    };
+    assert_eq!(n, (42, -1, 255));
+
+    assert_vm_error!(
+        r#"pub fn main() { int::from_float(1e20) }"#,
+        VmErrorKind::Overflow => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { int::from_float(-1e20) }"#,
+        VmErrorKind::Underflow => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { int::from_float(0.0 / 0.0) }"#,
+        VmErrorKind::NanConversion => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { 256.to_byte() }"#,
+        VmErrorKind::Overflow => {}
+    );
+}
@@ -42,6 +42,35 @@ fn test_range_iter() {
     };
 }
 
+#[test]
+fn test_range_prelude() {
+    let _: () = rune! {
+        pub fn main() {
+            let exclusive = [];
+
+            for n in 0..3 {
+                exclusive.push(n);
+            }
+
+            let inclusive = [];
+
+            for n in 0..=3 {
+                inclusive.push(n);
+            }
+
+            assert_eq!(exclusive, [0, 1, 2]);
+            assert_eq!(inclusive, [0, 1, 2, 3]);
+
+            assert!((0..10) is Range);
+            assert!((0..=10) is Range);
+            assert!((0..10).contains(9));
+            assert!(!(0..10).contains(10));
+            assert!((0..=10).contains(10));
+            assert!(!(0..=10).contains(11));
+        }
+    };
+}
+
 #[test]
 fn test_non_numeric_ranges() {
     let _: () = rune! {
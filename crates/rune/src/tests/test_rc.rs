@@ -0,0 +1,86 @@
+prelude!();
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use VmErrorKind::*;
+
+#[derive(Any)]
+struct Tracker(Arc<AtomicUsize>);
+
+impl Drop for Tracker {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_weak_cycle_is_collected() -> Result<()> {
+    let mut m = Module::new();
+    m.ty::<Tracker>()?;
+
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let alive: bool = rune_n! {
+        &m,
+        (Tracker(drops.clone()),),
+        bool => pub fn main(tracker) {
+            let parent = #{ tracker, children: [] };
+            let child = #{ parent: std::rc::downgrade(parent) };
+            parent.children.push(child);
+            child.parent.upgrade().is_some()
+        }
+    };
+
+    assert!(alive);
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+
+    // A strong back-reference keeps the cycle alive.
+    let drops = Arc::new(AtomicUsize::new(0));
+
+    let _: () = rune_n! {
+        &m,
+        (Tracker(drops.clone()),),
+        () => pub fn main(tracker) {
+            let parent = #{ tracker, children: [] };
+            let child = #{ parent };
+            parent.children.push(child);
+        }
+    };
+
+    assert_eq!(drops.load(Ordering::SeqCst), 0);
+    Ok(())
+}
+
+#[test]
+fn test_weak_upgrade() {
+    let out: bool = rune! {
+        pub fn main() {
+            let value = [1, 2, 3];
+            let weak = std::rc::downgrade(value);
+            weak.upgrade() == Some([1, 2, 3])
+        }
+    };
+
+    assert!(out);
+
+    let out: bool = rune! {
+        pub fn main() {
+            let weak = {
+                let value = #{ a: 1 };
+                std::rc::downgrade(value)
+            };
+
+            weak.upgrade().is_none()
+        }
+    };
+
+    assert!(out);
+
+    assert_vm_error!(
+        "pub fn main() { std::rc::downgrade(42) }",
+        WeakNotSupported { actual } => {
+            assert_eq!(actual.to_string(), "int");
+        }
+    );
+}
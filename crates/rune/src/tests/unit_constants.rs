@@ -77,3 +77,22 @@ fn test_get_const_nested() -> Result<()> {
     );
     Ok(())
 }
+
+#[test]
+fn test_context_constants() -> Result<()> {
+    let mut m = Module::with_item(["math"]);
+    m.constant(["PI"], 3.0f64)?.docs(["An engineer's pi."]);
+    m.constant(["Circle", "SIDES"], 0i64)?;
+
+    let out: (f64, i64, f64) = rune_n! {
+        &m,
+        (),
+        (f64, i64, f64) => pub fn main() {
+            use math::PI;
+            (PI, math::Circle::SIDES, std::float::PI)
+        }
+    };
+
+    assert_eq!(out, (3.0, 0, std::f64::consts::PI));
+    Ok(())
+}
@@ -117,6 +117,44 @@ fn test_mul() {
     error_test!(9223372036854775807i64 * 2 = Overflow);
 }
 
+#[test]
+fn test_string_mul() {
+    let out: String = rune!(
+        pub fn main() {
+            let sep = "-";
+            sep * 3
+        }
+    );
+    assert_eq!(out, "---");
+
+    let out: String = rune!(
+        pub fn main() {
+            let n = 2;
+            String::from_str("ab") * n
+        }
+    );
+    assert_eq!(out, "abab");
+
+    let out: String = rune!(
+        pub fn main() {
+            "ab" * 0
+        }
+    );
+    assert_eq!(out, "");
+
+    assert_vm_error!(
+        r#"pub fn main() { let n = -1; "ab" * n }"#,
+        ValueToIntegerCoercionError { .. } => {}
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { "ab" * 4611686018427387904 }"#,
+        AllocationFailed { size } => {
+            assert_eq!(size, 1 << 63);
+        }
+    );
+}
+
 #[test]
 fn test_div() {
     op_tests!(10 / 2 = 5);
@@ -75,3 +75,46 @@ fn test_assign_assign_exprs() {
     };
     assert_eq!(out, (4, (), ()));
 }
+
+#[test]
+fn test_assign_mixed_field_chain() {
+    let out: (i64, i64, i64) = rune! {
+        struct Foo { inner, value }
+
+        pub fn main() {
+            let foo = Foo { inner: #{ entry: Foo { inner: (), value: 0 } }, value: 0 };
+            foo.value = 1;
+            foo.inner.entry.value = 2;
+            foo.inner.other = 3;
+            foo.inner.entry.value += 40;
+            (foo.value, foo.inner.entry.value, foo.inner.other)
+        }
+    };
+    assert_eq!(out, (1, 42, 3));
+}
+
+#[test]
+fn test_assign_missing_struct_field() {
+    assert_vm_error!(
+        r#"
+        struct Foo { value }
+
+        pub fn main() {
+            let foo = Foo { value: 0 };
+            foo.missing = 1;
+        }
+        "#,
+        VmErrorKind::MissingField { field, .. } => {
+            assert_eq!(field, "missing");
+        }
+    );
+}
+
+#[test]
+fn test_compound_assign() {
+    let out: i64 = rune! {
+        pub fn main() { let a = 10; a += 5; a -= 3; a *= 4; a /= 6; a }
+    };
+
+    assert_eq!(out, 8);
+}
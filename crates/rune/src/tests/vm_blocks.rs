@@ -11,3 +11,44 @@ fn test_anonymous_type_precedence() {
     };
     assert_eq!(out, 3);
 }
+
+#[test]
+fn test_block_expr_value() {
+    let out: i64 = rune! {
+        fn f() { 41 }
+
+        pub fn main() {
+            let x = { let t = f(); t + 1 };
+            x
+        }
+    };
+    assert_eq!(out, 42);
+
+    let out: i64 = rune! {
+        pub fn main() {
+            let x = { let a = 1; { let b = a + 1; b * 10 } };
+            x
+        }
+    };
+    assert_eq!(out, 20);
+}
+
+#[test]
+fn test_block_expr_scoping() {
+    let out: i64 = rune! {
+        pub fn main() {
+            let t = 1;
+            let x = { let t = 10; t + 1 };
+            t + x
+        }
+    };
+    assert_eq!(out, 12);
+
+    assert_compile_error! {
+        r#"pub fn main() { let x = { let t = 1; t }; t }"#,
+        span, CompileErrorKind::MissingLocal { name } => {
+            assert_eq!(name, "t");
+            assert_eq!(span, span!(42, 43));
+        }
+    };
+}
@@ -0,0 +1,49 @@
+prelude!();
+
+use std::sync::Arc;
+
+#[test]
+fn test_call_builder() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let runtime = Arc::new(context.runtime());
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main(a, b, c) {
+                a * 100 + b * 10 + c
+            }
+        }
+    };
+
+    let unit = Arc::new(prepare(&mut sources).with_context(&context).build()?);
+    let mut vm = Vm::new(runtime, unit);
+
+    let expected: i64 = from_value(vm.call(["main"], (1i64, 2i64, 3i64))?)?;
+    let actual: i64 = from_value(
+        vm.call_builder(["main"])
+            .arg(1i64)
+            .arg(2i64)
+            .arg(3i64)
+            .invoke()?,
+    )?;
+
+    assert_eq!(actual, 123);
+    assert_eq!(actual, expected);
+
+    let error = vm
+        .call_builder(["main"])
+        .arg(1i64)
+        .arg(2i64)
+        .invoke()
+        .unwrap_err();
+
+    assert!(matches!(
+        error.into_kind(),
+        VmErrorKind::BadArgumentCount {
+            actual: 2,
+            expected: 3
+        }
+    ));
+
+    Ok(())
+}
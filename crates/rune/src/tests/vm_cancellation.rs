@@ -0,0 +1,55 @@
+prelude!();
+
+use std::thread;
+use std::time::Duration;
+
+use crate::runtime::CancellationToken;
+use crate::tests::vm;
+
+#[test]
+fn test_cancel_infinite_loop() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let token = CancellationToken::new();
+
+    let mut sources = crate::tests::sources(r#"pub fn main() { let n = 0; loop { n += 1; } }"#);
+    let mut vm =
+        vm(&context, &mut sources, &mut Diagnostics::new())?.with_cancellation(token.clone());
+
+    let handle = thread::spawn({
+        let token = token.clone();
+
+        move || {
+            thread::sleep(Duration::from_millis(50));
+            token.cancel();
+        }
+    });
+
+    let error = vm.call(["main"], ()).unwrap_err();
+    handle.join().unwrap();
+
+    assert!(error.is_cancelled());
+    Ok(())
+}
+
+#[test]
+fn test_cancel_inherited() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let token = CancellationToken::new();
+
+    let mut sources = sources! {
+        entry => {
+            fn counter() { let n = 0; loop { yield n; n += 1; } }
+            pub fn main() { let g = counter(); g.next(); g.next() }
+        }
+    };
+
+    let mut vm =
+        vm(&context, &mut sources, &mut Diagnostics::new())?.with_cancellation(token.clone());
+
+    assert!(vm.call(["main"], ()).is_ok());
+
+    token.cancel();
+    let error = vm.call(["main"], ()).unwrap_err();
+    assert!(error.is_cancelled());
+    Ok(())
+}
@@ -0,0 +1,61 @@
+prelude!();
+
+#[test]
+fn test_catch_panic() {
+    let out: Result<i64, String> = rune! {
+        pub fn main() {
+            std::catch(|| panic("boom"))
+        }
+    };
+    assert_eq!(out, Err(String::from("boom")));
+
+    let out: Result<i64, String> = rune! {
+        pub fn main() {
+            let n = 3;
+            std::catch(|| panic!("bad value {}", n))
+        }
+    };
+    assert_eq!(out, Err(String::from("bad value 3")));
+
+    let out: Result<i64, String> = rune! {
+        pub fn main() {
+            std::catch(|| None.expect("missing"))
+        }
+    };
+    assert_eq!(out, Err(String::from("missing")));
+
+    let out: Result<i64, String> = rune! {
+        pub fn main() {
+            std::catch(|| 40 + 2)
+        }
+    };
+    assert_eq!(out, Ok(42));
+
+    // Execution continues normally after a caught panic.
+    let out: i64 = rune! {
+        pub fn main() {
+            let n = 0;
+
+            for v in [1, 2, 3] {
+                if std::catch(|| if v == 2 { panic("skip") } else { v }).is_ok() {
+                    n += v;
+                }
+            }
+
+            n
+        }
+    };
+    assert_eq!(out, 4);
+}
+
+#[test]
+fn test_catch_propagates_other_errors() {
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            std::catch(|| 1 + "a")
+        }
+        "#,
+        VmErrorKind::UnsupportedBinaryOperation { .. } => {}
+    );
+}
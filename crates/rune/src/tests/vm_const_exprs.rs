@@ -156,6 +156,114 @@ fn test_if_else() {
     assert_eq!(result, 3);
 }
 
+#[test]
+fn test_if_expr() {
+    let result: i64 = rune! {
+        const FLAG = true;
+        const VALUE = if FLAG { 1 } else { 2 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 1);
+
+    let result: i64 = rune! {
+        const FLAG = false;
+        const VALUE = if FLAG { 1 } else { 2 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 2);
+
+    // Only the taken branch is evaluated.
+    let result: i64 = rune! {
+        const VALUE = if true { 1 } else { 1 / 0 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 1);
+}
+
+#[test]
+fn test_match() {
+    let result: String = rune! {
+        const VALUE = match 2 { 1 => "one", 2 => "two", _ => "many" };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, "two");
+
+    let result: String = rune! {
+        const VALUE = match "b" { "a" => "first", "b" => "second", _ => "other" };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, "second");
+
+    let result: i64 = rune! {
+        const VALUE = match 10 { n if n < 5 => 1, n => n * 2 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 20);
+
+    // Only the matched branch is evaluated.
+    let result: i64 = rune! {
+        const VALUE = match true { true => 1, false => 1 / 0 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 1);
+
+    let result: i64 = rune! {
+        const VALUE = match 2.5 { 1.5 => 1, 2.5 => 2, _ => 3 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 2);
+
+    // NaN never compares equal, not even to itself.
+    let result: i64 = rune! {
+        const NAN = 0.0 / 0.0;
+        const VALUE = match NAN { 0.0 => 1, _ => 2 };
+        pub fn main() { VALUE }
+    };
+    assert_eq!(result, 2);
+
+    assert_compile_error! {
+        r#"const VALUE = match 3 { 1 => 1, 2 => 2 }; pub fn main() { VALUE }"#,
+        span, CompileErrorKind::Custom { message } => {
+            assert_eq!(message.as_ref(), "no branch matched the value of the match expression");
+            assert_eq!(span, span!(14, 40));
+        }
+    };
+}
+
+#[test]
+fn test_string_mul() {
+    let out: String = rune! {
+        const SEP = "=" * 4;
+        const COUNT = 2;
+        const VALUE = SEP + "ab" * COUNT;
+        pub fn main() { VALUE }
+    };
+    assert_eq!(out, "====abab");
+
+    assert_compile_error! {
+        r#"const N = 0 - 1; const VALUE = "ab" * N; pub fn main() { VALUE }"#,
+        span, CompileErrorKind::Custom { message } => {
+            assert_eq!(message.as_ref(), "cannot be converted to a repeat count");
+            assert_eq!(span, span!(38, 39));
+        }
+    };
+
+    assert_compile_error! {
+        r#"const VALUE = "abc" * 9223372036854775807; pub fn main() { VALUE }"#,
+        span, CompileErrorKind::Custom { message } => {
+            assert_eq!(message.as_ref(), "repeated string is too large");
+            assert_eq!(span, span!(14, 41));
+        }
+    };
+
+    assert_compile_error! {
+        r#"const VALUE = "a" * 1000000000000; pub fn main() { VALUE }"#,
+        span, CompileErrorKind::IrError(compile::IrErrorKind::BudgetExceeded { .. }) => {
+            assert_eq!(span, span!(14, 33));
+        }
+    };
+}
+
 #[test]
 fn test_const_fn() {
     let result: i64 = rune! {
@@ -264,3 +372,64 @@ fn test_const_block() {
 
     assert_eq!(result, "Hello World");
 }
+
+#[test]
+fn test_impl_const() {
+    let result: (i64, i64, bool) = rune! {
+        struct Foo;
+
+        impl Foo {
+            const MAX = 100;
+            const MIN = 0;
+
+            fn clamp(self, n) {
+                if n > Foo::MAX { Foo::MAX } else if n < Foo::MIN { Foo::MIN } else { n }
+            }
+        }
+
+        pub fn main() {
+            (Foo::MAX, Foo.clamp(200), Foo.clamp(-10) == Foo::MIN)
+        }
+    };
+
+    assert_eq!(result, (100, 100, true));
+}
+
+#[test]
+fn test_const_eval_budget() -> Result<()> {
+    const SOURCE: &str = r#"
+    const fn count(n) {
+        let i = 0;
+
+        while i < n {
+            i += 1;
+        }
+
+        i
+    }
+
+    const VALUE = count(1000);
+
+    pub fn main() {
+        VALUE
+    }
+    "#;
+
+    let context = Context::with_default_modules()?;
+
+    let out: i64 = run(&context, SOURCE, ["main"], ())?;
+    assert_eq!(out, 1000);
+
+    let mut options = crate::Options::default();
+    options.const_eval_budget(100);
+
+    assert_compile_error! {
+        &options => SOURCE,
+        _span, kind @ CompileErrorKind::IrError(compile::IrErrorKind::BudgetExceeded { limit }) => {
+            assert_eq!(kind.to_string(), "Evaluation budget of 100 exceeded");
+            assert_eq!(limit, 100);
+        }
+    };
+
+    Ok(())
+}
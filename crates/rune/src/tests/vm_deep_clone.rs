@@ -0,0 +1,134 @@
+prelude!();
+
+use std::sync::Arc;
+
+#[derive(Any, Debug, Clone)]
+struct Counter {
+    #[rune(get, set, copy)]
+    value: i64,
+}
+
+#[derive(Any, Debug)]
+struct Handle;
+
+#[test]
+fn test_deep_clone_object() {
+    let out: (i64, i64, Vec<i64>, Vec<i64>) = rune! {
+        pub fn main() {
+            let a = #{ n: 1, values: [1, 2], nested: #{ n: 10 } };
+            let b = std::deep_clone(a);
+
+            b.n = 2;
+            b.values.push(3);
+            b.nested.n = 20;
+
+            (a.n + a.nested.n, b.n + b.nested.n, a.values, b.values)
+        }
+    };
+
+    assert_eq!(out, (11, 22, vec![1, 2], vec![1, 2, 3]));
+}
+
+#[test]
+fn test_deep_clone_structs_and_variants() {
+    let out: (i64, i64, i64, i64) = rune! {
+        struct Point { x, y }
+        enum Shape { Line(a, b) }
+
+        pub fn main() {
+            let a = Shape::Line(Point { x: 1, y: 2 }, (3, [4]));
+            let b = std::deep_clone(a);
+
+            if let Shape::Line(point, (_, values)) = b {
+                point.x = 10;
+                values.push(5);
+            }
+
+            match (a, b) {
+                (Shape::Line(a, (_, av)), Shape::Line(b, (_, bv))) => {
+                    (a.x, b.x, av.len(), bv.len())
+                }
+            }
+        }
+    };
+
+    assert_eq!(out, (1, 10, 1, 2));
+}
+
+#[test]
+fn test_deep_clone_external() -> Result<()> {
+    let mut module = Module::new();
+    module.ty::<Counter>()?;
+    module.associated_function(Protocol::CLONE, Counter::clone)?;
+    module.ty::<Handle>()?;
+
+    let out: (i64, i64) = rune_n! {
+        module,
+        (Counter { value: 1 },),
+        (i64, i64) =>
+        pub fn main(counter) {
+            let copy = std::deep_clone(counter);
+            copy.value = 2;
+            (counter.value, copy.value)
+        }
+    };
+
+    assert_eq!(out, (1, 2));
+
+    let mut module = Module::new();
+    module.ty::<Handle>()?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let mut sources = crate::tests::sources("pub fn main(handle) { std::deep_clone(handle) }");
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+    let mut vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let error = vm.call(["main"], (Handle,)).unwrap_err();
+
+    match error.into_kind() {
+        VmErrorKind::UnsupportedDeepClone { actual } => {
+            assert_eq!(actual.to_string(), "Handle");
+        }
+        kind => panic!("unexpected error {kind:?}"),
+    }
+
+    Ok(())
+}
+
+#[test]
+fn test_deep_clone_cycle() {
+    let out: (i64, i64) = rune! {
+        pub fn main() {
+            let a = [1];
+            let b = std::deep_clone([a, #{ a }]);
+            b[0].push(2);
+            (b[0].len(), b[1].a.len())
+        }
+    };
+
+    assert_eq!(out, (2, 1));
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            let a = [];
+            a.push(a);
+            std::deep_clone(a)
+        }
+        "#,
+        VmErrorKind::DeepCloneCycle => {}
+    );
+
+    assert_vm_error!(
+        r#"
+        pub fn main() {
+            let a = #{};
+            a.values = [Some(a)];
+            std::deep_clone(#{ a })
+        }
+        "#,
+        VmErrorKind::DeepCloneCycle => {}
+    );
+}
@@ -51,3 +51,29 @@ fn test_typed_object_early_term() {
 fn test_typed_tuple_early_term() {
     test_case!(( Foo { test: 0 } ), test, "test", struct Foo { test };);
 }
+
+#[test]
+fn test_bare_return_break_continue() {
+    let out: (i64, ()) = rune! {
+        fn unit() {
+            return;
+        }
+
+        pub fn main() {
+            let n = 0;
+
+            loop {
+                n += 1;
+
+                if n < 5 {
+                    continue
+                }
+
+                break
+            };
+
+            (n, unit())
+        }
+    };
+    assert_eq!(out, (5, ()));
+}
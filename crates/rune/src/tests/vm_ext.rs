@@ -0,0 +1,55 @@
+prelude!();
+
+use std::sync::Arc;
+
+struct RequestId(i64);
+
+fn request_id() -> Option<i64> {
+    Some(runtime::ext::<RequestId>()?.0)
+}
+
+#[test]
+fn test_vm_ext() -> Result<()> {
+    let mut m = Module::new();
+    m.function(["request_id"], request_id)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+    let runtime = Arc::new(context.runtime());
+
+    let mut sources = sources! {
+        entry => {
+            pub fn main() {
+                request_id()
+            }
+
+            pub fn nested(f) {
+                f()
+            }
+        }
+    };
+
+    let unit = Arc::new(prepare(&mut sources).with_context(&context).build()?);
+    let mut vm = Vm::new(runtime, unit);
+
+    let id: Option<i64> = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(id, None);
+
+    vm.insert_ext(RequestId(1));
+    let id: Option<i64> = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(id, Some(1));
+
+    // Inserting a value of the same type replaces the old one.
+    vm.insert_ext(RequestId(2));
+    assert_eq!(vm.ext::<RequestId>().map(|r| r.0), Some(2));
+
+    // Extensions stay visible when the native function is reached by calling
+    // a function value.
+    let main = vm.lookup_function(["main"])?;
+    let id: Option<i64> = from_value(vm.call(["nested"], (main,))?)?;
+    assert_eq!(id, Some(2));
+
+    // Extensions are not visible outside of the virtual machine.
+    assert_eq!(request_id(), None);
+    Ok(())
+}
@@ -0,0 +1,51 @@
+prelude!();
+
+use VmErrorKind::*;
+
+#[test]
+fn test_format_named() {
+    let out: String = rune! {
+        pub fn main() { format("{name}", #{ name: "x" }) }
+    };
+    assert_eq!(out, "x");
+
+    let out: String = rune! {
+        pub fn main() {
+            let args = #{ a: 1, b: "two", c: [3] };
+            format("{a} {b:?} {c:?} {{a}} {a}", args)
+        }
+    };
+    assert_eq!(out, "1 \"two\" [3] {a} 1");
+
+    let out: String = rune! {
+        pub fn main() {
+            format("[{x:>5}] [{x:*<4}] [{y:.2}] [{x:x}]", #{ x: 42, y: 1.5 })
+        }
+    };
+    assert_eq!(out, "[   42] [42**] [1.50] [2a]");
+}
+
+#[test]
+fn test_format_missing_field() {
+    assert_vm_error!(
+        r#"pub fn main() { format("{name} {age}", #{ name: "x" }) }"#,
+        MissingField { target, field } => {
+            assert_eq!(target.to_string(), "Object");
+            assert_eq!(field, "age");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { format("{name", #{ name: "x" }) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "unterminated `{` in format string");
+        }
+    );
+
+    assert_vm_error!(
+        r#"pub fn main() { format("{name:!}", #{ name: "x" }) }"#,
+        Panic { reason } => {
+            assert_eq!(reason.to_string(), "invalid format specification `!`");
+        }
+    );
+}
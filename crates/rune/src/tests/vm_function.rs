@@ -68,3 +68,195 @@ fn test_function() {
     let value: Value = function.call(()).unwrap();
     assert!(matches!(value, Value::Integer(3)));
 }
+
+#[test]
+fn test_bad_argument_names_function() -> Result<()> {
+    #[derive(Any)]
+    struct Socket;
+
+    impl Socket {
+        fn send(&self, _: i64) {}
+    }
+
+    let mut m = Module::new();
+    m.ty::<Socket>()?;
+    m.function(["connect"], |_: String, _: i64| Socket)?;
+    m.associated_function("send", Socket::send)?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(m)?;
+    let runtime = Arc::new(context.runtime());
+
+    let mut sources = sources! {
+        entry => {
+            pub fn connect_bad() {
+                connect("localhost", "8080")
+            }
+
+            pub fn send_bad() {
+                let socket = connect("localhost", 8080);
+                socket.send("hello")
+            }
+        }
+    };
+
+    let unit = Arc::new(prepare(&mut sources).with_context(&context).build()?);
+    let mut vm = Vm::new(runtime, unit);
+
+    let error = vm.call(["connect_bad"], ()).unwrap_err();
+    let chain = error
+        .chain()
+        .iter()
+        .map(|at| at.to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(chain, ["Converting argument #1 of `connect`"]);
+
+    let error = vm.call(["send_bad"], ()).unwrap_err();
+    let chain = error
+        .chain()
+        .iter()
+        .map(|at| at.to_string())
+        .collect::<Vec<_>>();
+    let expected = format!(
+        "Converting argument #1 of `{}::send`",
+        std::any::type_name::<Socket>()
+    );
+    assert_eq!(chain, [expected]);
+    Ok(())
+}
+
+#[test]
+fn test_raw_value_fn() -> Result<()> {
+    let mut m = Module::new();
+
+    m.raw_value_fn(["weighted"], |args| {
+        let mut sum = 0;
+
+        for (index, value) in args.iter().enumerate() {
+            sum += (index as i64 + 1) * vm_try!(value.clone().into_integer());
+        }
+
+        VmResult::Ok(Value::from(sum))
+    })?;
+
+    let out: (i64, i64, i64) = rune_n! {
+        &m,
+        (),
+        (i64, i64, i64) => pub fn main() {
+            let a = 10;
+            (weighted(1, 2, 3), weighted(), a + weighted(a))
+        }
+    };
+
+    assert_eq!(out, (14, 0, 20));
+    Ok(())
+}
+
+#[test]
+fn test_function_alias() -> Result<()> {
+    fn len(value: &str) -> usize {
+        value.len()
+    }
+
+    let mut m = Module::new();
+    m.function(["len"], len)?;
+    m.function_alias(["len"], ["length"])?;
+    m.function_alias(Hash::type_hash(["len"]), ["size"])?;
+
+    let out: (usize, usize, usize) = rune_n! {
+        &m,
+        (),
+        (usize, usize, usize) => pub fn main() {
+            (len("foo"), length("hello"), size(""))
+        }
+    };
+
+    assert_eq!(out, (3, 5, 0));
+
+    assert!(matches!(
+        m.function_alias(["missing"], ["other"]),
+        Err(ContextError::MissingFunction { .. })
+    ));
+
+    assert!(matches!(
+        m.function_alias(["len"], ["length"]),
+        Err(ContextError::ConflictingFunctionName { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn test_associated_function_for() -> Result<()> {
+    fn describe(value: Value) -> VmResult<String> {
+        VmResult::Ok(format!("a {}", vm_try!(value.type_info())))
+    }
+
+    let mut m = Module::new();
+    m.associated_function_for::<(i64, f64), _, _, _, _>("describe", describe)?
+        .docs(["Describe the value."]);
+
+    let out: (String, String) = rune_n! {
+        &m,
+        (),
+        (String, String) => pub fn main() {
+            (1.describe(), 1.5.describe())
+        }
+    };
+
+    assert_eq!(out, (String::from("a int"), String::from("a float")));
+
+    assert!(matches!(
+        m.associated_function_for::<(i64,), _, _, _, _>("describe", describe),
+        Err(ContextError::ConflictingInstanceFunction { .. })
+    ));
+
+    // A conflict for one of the types means none of them are registered.
+    let mut m = Module::new();
+    m.associated_function_for::<(bool,), _, _, _, _>("describe", describe)?;
+
+    assert!(matches!(
+        m.associated_function_for::<(i64, bool), _, _, _, _>("describe", describe),
+        Err(ContextError::ConflictingInstanceFunction { .. })
+    ));
+
+    m.associated_function_for::<(i64,), _, _, _, _>("describe", describe)?;
+
+    Ok(())
+}
+
+#[test]
+fn test_function_returning_array() -> Result<()> {
+    let mut module = Module::new();
+    module.function(["array"], || [1i64, 2, 3])?;
+    module.function(["split"], |value: i64| [value / 2, value - value / 2])?;
+
+    let out: Vec<i64> = rune_n! {
+        &module,
+        (),
+        Vec<i64> =>
+        pub fn main() {
+            let out = array();
+
+            for value in split(5) {
+                out.push(value);
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(out, [1, 2, 3, 2, 3]);
+
+    let values = [4i64, 5, 6];
+
+    let out: i64 = run(
+        &Context::with_default_modules()?,
+        "pub fn main(values) { values.iter().fold(0, |a, b| a + b) }",
+        ["main"],
+        (&values[1..],),
+    )?;
+
+    assert_eq!(out, 11);
+    Ok(())
+}
@@ -115,6 +115,79 @@ fn test_if() {
     assert_eq!(out, 10);
 }
 
+#[test]
+fn test_if_expr_value() {
+    let out: (i64, i64) = rune! {
+        pub fn main() {
+            let c = true;
+            let a = if c { 1 } else { 2 };
+            let b = if !c { 1 } else if c { 3 } else { 2 };
+            (a, b)
+        }
+    };
+    assert_eq!(out, (1, 3));
+
+    let out: i64 = rune! {
+        pub fn main() {
+            let c = false;
+            let x = { let y = 10; if c { y } else { y * 2 } };
+            x
+        }
+    };
+    assert_eq!(out, 20);
+}
+
+#[test]
+fn test_if_expr_in_loop_body() {
+    let out: i64 = rune! {
+        pub fn main() {
+            let sum = 0;
+
+            for n in 0..10 {
+                let v = if n % 2 == 0 { n } else { 0 };
+                sum += v;
+                if n > 5 { sum += 100 } else { sum += 1 }
+            }
+
+            sum
+        }
+    };
+    assert_eq!(out, 20 + 400 + 6);
+
+    let out: i64 = rune! {
+        pub fn main() {
+            let n = 0;
+            let sum = 0;
+
+            while n < 4 {
+                n += 1;
+                sum += { if n == 2 { 10 } else { 1 } };
+                if n == 3 { continue } else { () }
+            }
+
+            sum
+        }
+    };
+    assert_eq!(out, 13);
+
+    let out: i64 = rune! {
+        pub fn main() {
+            let n = 0;
+
+            let v = loop {
+                n += 1;
+
+                if n == 3 {
+                    break if n > 2 { n * 10 } else { 0 };
+                }
+            };
+
+            v
+        }
+    };
+    assert_eq!(out, 30);
+}
+
 #[test]
 fn test_block() {
     let out: i64 = rune! {
@@ -51,6 +51,52 @@ fn test_literals() {
     assert_eq!(out, '💯');
 }
 
+#[test]
+fn test_empty_collection_literals() {
+    let out: (Object, runtime::Vec) = rune!(
+        pub fn main() {
+            (#{}, [])
+        }
+    );
+    assert!(out.0.is_empty());
+    assert!(out.1.is_empty());
+
+    // An empty object literal in tail position is not an empty block.
+    let out: Object = rune!(
+        pub fn main() {
+            if true { #{} } else { #{"a": 1} }
+        }
+    );
+    assert!(out.is_empty());
+
+    let out: (i64, i64) = rune!(
+        pub fn main() {
+            let object = #{};
+            object.insert("a", 1);
+            let vec = [];
+            vec.push(1);
+            vec.push(2);
+            (object.len(), vec.len())
+        }
+    );
+    assert_eq!(out, (1, 2));
+
+    // Empty literals are constant, and each use is a fresh collection.
+    let out: (i64, i64) = rune!(
+        const OBJECT = #{};
+        const VEC = [];
+
+        pub fn main() {
+            let a = OBJECT;
+            a.insert("a", 1);
+            let b = VEC;
+            b.push(1);
+            (OBJECT.len(), VEC.len())
+        }
+    );
+    assert_eq!(out, (0, 0));
+}
+
 #[test]
 fn test_string_literals() {
     let out: String = rune!(
@@ -78,6 +124,44 @@ fn test_string_literals() {
         }
     );
     assert_eq!(out, "a b");
+
+    let out: String = rune!(
+        pub fn main() {
+            "\t\0\\\"\u{1F600}"
+        }
+    );
+    assert_eq!(out, "\t\0\\\"\u{1F600}");
+}
+
+#[test]
+fn test_raw_string_literals() {
+    let out: String = rune!(
+        pub fn main() {
+            r"C:\foo\n"
+        }
+    );
+    assert_eq!(out, "C:\\foo\\n");
+
+    let out: String = rune!(
+        pub fn main() {
+            r#"has "quotes""#
+        }
+    );
+    assert_eq!(out, "has \"quotes\"");
+
+    let out: String = rune!(
+        pub fn main() {
+            r##"a"#b"##
+        }
+    );
+    assert_eq!(out, "a\"#b");
+
+    let out: String = rune!(
+        pub fn main() {
+            r""
+        }
+    );
+    assert_eq!(out, "");
 }
 
 #[test]
@@ -131,12 +215,14 @@ fn test_number_literals() {
 
     test_case!(0xf_f);
     test_case!(-0xf_f);
+    test_case!(0xFF_FF);
 
     test_case!(42);
     test_case!(-42);
 
     test_case!(4_2);
     test_case!(-4_2);
+    test_case!(1_000_000);
 
     test_case!(0b1001_0001);
     test_case!(-0b1001_0001);
@@ -153,18 +239,14 @@ fn test_number_literals() {
     test_case!(42.42, f32);
     test_case!(-42.42, f32);
 
-    // TODO: we need a different float parsing routine to support _ in floats.
-    // test_case!(42_.42, f32);
-    // test_case!(4_2.42, f32);
-    // test_case!(42.4_2, f32);
-    // test_case!(4_2.4_2, f32);
+    test_case!(4_2.42, f32);
+    test_case!(42.4_2, f32);
+    test_case!(4_2.4_2, f32);
 
     test_case!(1.9e10, f64);
     test_case!(-1.9e10, f64);
 
-    // TODO: we need a different float parsing routine to support _ in floats.
-    // test_case!(1_.9e10, f64);
-    // test_case!(1.9e1_0, f64);
+    test_case!(1.9e1_0, f64);
 
     test_case!(1e10, f64);
 }
@@ -38,6 +38,67 @@ fn test_match_primitives() {
     assert!(out);
 }
 
+#[test]
+fn test_match_negative_literals() {
+    let out: Vec<String> = rune! {
+        fn classify(n) {
+            match n {
+                -1 => "minus one",
+                0 => "zero",
+                _ => "other",
+            }
+        }
+
+        pub fn main() {
+            [classify(-1), classify(0), classify(1)]
+        }
+    };
+
+    assert_eq!(out, ["minus one", "zero", "other"]);
+}
+
+#[test]
+fn test_match_ranges() {
+    let out: Vec<String> = rune! {
+        fn classify(c) {
+            match c {
+                'a'..='z' => "lower",
+                'A'..='Z' => "upper",
+                b'0'..b':' => "byte digit",
+                -9..=-1 => "negative",
+                0..10 => "digit",
+                _ => "other",
+            }
+        }
+
+        pub fn main() {
+            let out = [];
+
+            for value in ['a', 'z', 'M', '_', b'7', -3, 0, 9, 10, "a"] {
+                out.push(classify(value));
+            }
+
+            out
+        }
+    };
+
+    assert_eq!(
+        out,
+        [
+            "lower",
+            "lower",
+            "upper",
+            "other",
+            "byte digit",
+            "negative",
+            "digit",
+            "digit",
+            "other",
+            "other"
+        ]
+    );
+}
+
 #[test]
 fn test_path_type_match() {
     let out: bool = rune! {
@@ -121,3 +182,25 @@ fn test_struct_matching() {
     };
     assert_eq!(out, 3);
 }
+
+#[test]
+fn test_match_grouped_object() {
+    let out: i64 = rune! {
+        pub fn main() {
+            match (#{"a": 1, "b": 2}) {
+                #{a, b} => a + b,
+                _ => 0,
+            }
+        }
+    };
+
+    assert_eq!(out, 3);
+
+    let out: bool = rune! {
+        pub fn main() {
+            if (#{"a": true}).a { true } else { false }
+        }
+    };
+
+    assert!(out);
+}
@@ -0,0 +1,70 @@
+prelude!();
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::runtime::{Function, NativeCall, NativeCallHook};
+
+#[derive(Default)]
+struct Recorder {
+    entered: Mutex<Vec<Hash>>,
+    exited: Mutex<Vec<Hash>>,
+}
+
+impl NativeCallHook for Recorder {
+    fn enter(&self, call: NativeCall) {
+        self.entered.lock().unwrap().push(call.hash);
+    }
+
+    fn exit(&self, call: NativeCall, _: Duration) {
+        self.exited.lock().unwrap().push(call.hash);
+    }
+}
+
+#[test]
+fn test_native_hook() -> Result<()> {
+    let mut module = Module::new();
+    module.function(["first"], |a: i64| a + 1)?;
+    module.function(["second"], |a: i64, b: i64| a * b)?;
+    module.function(["apply"], |f: Function, a: i64| f.call::<_, i64>((a,)))?;
+
+    let mut context = Context::with_default_modules()?;
+    context.install(module)?;
+
+    let mut sources = sources! {
+        entry => {
+            fn script(n) {
+                n
+            }
+
+            pub fn main() {
+                let f = first;
+                second(f(script(1)), apply(first, 2))
+            }
+        }
+    };
+
+    let recorder = Arc::new(Recorder::default());
+    let mut vm = crate::tests::vm(&context, &mut sources, &mut Diagnostics::new())?
+        .with_native_hook(recorder.clone());
+
+    let out: i64 = from_value(vm.call(["main"], ())?)?;
+    assert_eq!(out, 6);
+
+    let first = Hash::type_hash(["first"]);
+    let second = Hash::type_hash(["second"]);
+    let apply = Hash::type_hash(["apply"]);
+
+    // Native functions called through function pointers are hooked, both from
+    // the virtual machine and from other native functions.
+    assert_eq!(
+        *recorder.entered.lock().unwrap(),
+        [first, apply, first, second]
+    );
+
+    assert_eq!(
+        *recorder.exited.lock().unwrap(),
+        [first, first, apply, second]
+    );
+    Ok(())
+}
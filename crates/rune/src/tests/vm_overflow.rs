@@ -0,0 +1,85 @@
+prelude!();
+
+use runtime::Overflow;
+
+use crate::tests::{sources, vm};
+
+const SOURCE: &str = r#"
+pub fn add(a, b) { a + b }
+pub fn sub(a, b) { a - b }
+pub fn mul(a, b) { a * b }
+pub fn add_assign(a, b) { a += b; a }
+
+pub fn generator(a, b) {
+    let g = (|| { yield a + b; })();
+    g.next()
+}
+
+pub fn native(a, b) {
+    [a].iter().map(|a| a + b).next()
+}
+"#;
+
+fn call(vm: &mut Vm, name: &str, a: i64, b: i64) -> Result<i64> {
+    Ok(from_value(vm.call([name], (a, b))?)?)
+}
+
+#[test]
+fn test_overflow_error() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm(&context, &mut sources(SOURCE), &mut Diagnostics::new())?;
+    vm.set_overflow(Overflow::default());
+
+    let error = vm.call(["add"], (i64::MAX, 1i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::Overflow));
+
+    let error = vm.call(["add_assign"], (i64::MAX, 1i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::Overflow));
+
+    let error = vm.call(["sub"], (i64::MIN, 1i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::Underflow));
+
+    let error = vm.call(["mul"], (i64::MAX, 2i64)).unwrap_err();
+    assert!(matches!(error.kind(), VmErrorKind::Overflow));
+
+    assert_eq!(call(&mut vm, "add", 1, 2)?, 3);
+    Ok(())
+}
+
+#[test]
+fn test_overflow_wrap() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm(&context, &mut sources(SOURCE), &mut Diagnostics::new())?;
+    vm.set_overflow(Overflow::Wrap);
+
+    assert_eq!(call(&mut vm, "add", i64::MAX, 1)?, i64::MIN);
+    assert_eq!(call(&mut vm, "add_assign", i64::MAX, 1)?, i64::MIN);
+    assert_eq!(call(&mut vm, "sub", i64::MIN, 1)?, i64::MAX);
+    assert_eq!(call(&mut vm, "mul", i64::MAX, 2)?, -2);
+
+    let out: Option<i64> = from_value(vm.call(["generator"], (i64::MAX, 1i64))?)?;
+    assert_eq!(out, Some(i64::MIN));
+
+    let out: Option<i64> = from_value(vm.call(["native"], (i64::MAX, 1i64))?)?;
+    assert_eq!(out, Some(i64::MIN));
+    Ok(())
+}
+
+#[test]
+fn test_overflow_saturate() -> Result<()> {
+    let context = Context::with_default_modules()?;
+    let mut vm = vm(&context, &mut sources(SOURCE), &mut Diagnostics::new())?;
+    vm.set_overflow(Overflow::Saturate);
+
+    assert_eq!(call(&mut vm, "add", i64::MAX, 1)?, i64::MAX);
+    assert_eq!(call(&mut vm, "add_assign", i64::MAX, 1)?, i64::MAX);
+    assert_eq!(call(&mut vm, "sub", i64::MIN, 1)?, i64::MIN);
+    assert_eq!(call(&mut vm, "mul", i64::MAX, 2)?, i64::MAX);
+
+    let out: Option<i64> = from_value(vm.call(["generator"], (i64::MAX, 1i64))?)?;
+    assert_eq!(out, Some(i64::MAX));
+
+    let out: Option<i64> = from_value(vm.call(["native"], (i64::MAX, 1i64))?)?;
+    assert_eq!(out, Some(i64::MAX));
+    Ok(())
+}
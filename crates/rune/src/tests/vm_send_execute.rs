@@ -0,0 +1,37 @@
+prelude!();
+
+use std::sync::Arc;
+use std::thread;
+
+#[test]
+fn test_send_execute_on_thread() -> Result<()> {
+    let context = Context::with_default_modules()?;
+
+    let mut sources = sources! {
+        entry => {
+            pub async fn main(n) {
+                let total = 0;
+
+                for value in 0..n {
+                    total += value * 2;
+                }
+
+                total
+            }
+        }
+    };
+
+    let unit = prepare(&mut sources).with_context(&context).build()?;
+    let vm = Vm::new(Arc::new(context.runtime()), Arc::new(unit));
+
+    let execution = vm.send_execute(["main"], (10i64,))?;
+
+    let handle = thread::spawn(move || {
+        let output = block_on(execution.async_complete()).into_result()?;
+        from_value::<i64>(output)
+    });
+
+    let output = handle.join().expect("thread panicked")?;
+    assert_eq!(output, 90);
+    Ok(())
+}
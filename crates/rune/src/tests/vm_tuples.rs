@@ -12,3 +12,18 @@ fn test_mutate_tuples() {
     "# };
     assert_eq!(out, "Now You Don't !");
 }
+
+#[test]
+fn test_tuple_literals_and_indexing() {
+    let out: (i64, i64, i64) = rune! {
+        pub fn main() {
+            let unit = ();
+            let group = (1 + 2);
+            let single = (group,);
+            let nested = ((1, 2), (single.0, 4));
+            let (first, second) = nested;
+            (first.1, second.0, (unit, (nested.1).1).1)
+        }
+    };
+    assert_eq!(out, (2, 3, 4));
+}
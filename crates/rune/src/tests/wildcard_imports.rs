@@ -36,3 +36,82 @@ fn test_wildcard_precedence() {
         pub fn main() { Foo is a::Foo }
     });
 }
+
+#[test]
+fn test_enum_variant_wildcard() {
+    let out: (i64, bool) = rune! {
+        enum Enum { A, B(x) }
+        use Enum::*;
+
+        pub fn main() {
+            let b = if let Enum::B(x) = B(2) { x } else { 0 };
+            (b, A is Enum)
+        }
+    };
+
+    assert_eq!(out, (2, true));
+
+    let out: i64 = rune! {
+        mod a { pub enum Enum { A(x), B } }
+        use a::Enum::*;
+
+        pub fn main() {
+            match A(42) {
+                a::Enum::A(x) => x,
+                _ => 0,
+            }
+        }
+    };
+
+    assert_eq!(out, 42);
+
+    let out: i64 = rune! {
+        use std::option::Option::*;
+
+        pub fn main() {
+            match Some(42) {
+                Some(x) => x,
+                None => 0,
+            }
+        }
+    };
+
+    assert_eq!(out, 42);
+}
+
+#[test]
+fn test_enum_variant_wildcard_errors() {
+    use compile::ItemBuf;
+
+    assert_compile_error! {
+        r#"
+        enum Enum { A }
+        impl Enum { fn new() { Enum::A } }
+        use Enum::*;
+        pub fn main() { new() }
+        "#,
+        _span, CompileErrorKind::MissingItem { item } => {
+            assert_eq!(item, ItemBuf::with_item(["new"]));
+        }
+    };
+
+    assert_compile_error! {
+        r#"
+        use std::option::Option::*;
+        pub fn main() { is_some(Some(1)) }
+        "#,
+        _span, CompileErrorKind::MissingItem { item } => {
+            assert_eq!(item, ItemBuf::with_item(["is_some"]));
+        }
+    };
+
+    assert_errors! {
+        r#"
+        struct A;
+        enum Enum { A }
+        use Enum::*;
+        pub fn main() { A }
+        "#,
+        _span, CompileErrorKind::QueryError(QueryErrorKind::AmbiguousItem { .. }) => {}
+    };
+}
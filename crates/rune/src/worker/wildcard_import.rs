@@ -1,7 +1,9 @@
 use crate::no_std::prelude::*;
 
 use crate::ast::Span;
-use crate::compile::{self, CompileErrorKind, IntoComponent, ItemBuf, ModId, Visibility};
+use crate::compile::{
+    self, meta, CompileErrorKind, IntoComponent, Item, ItemBuf, ModId, Visibility,
+};
 use crate::query::Query;
 use crate::{Context, SourceId};
 
@@ -23,9 +25,23 @@ impl WildcardImport {
         context: &Context,
     ) -> compile::Result<()> {
         if context.contains_prefix(&self.name) {
+            // Wildcard imports of an enum only bring its variants into scope,
+            // and not any associated items.
+            let is_enum = is_context_kind(context, &self.name, |kind| {
+                matches!(kind, meta::Kind::Enum { .. })
+            });
+
             for c in context.iter_components(&self.name) {
                 let name = self.name.extended(c);
 
+                if is_enum
+                    && !is_context_kind(context, &name, |kind| {
+                        matches!(kind, meta::Kind::Variant { .. })
+                    })
+                {
+                    continue;
+                }
+
                 query.insert_import(
                     self.source_id,
                     self.span,
@@ -52,9 +68,23 @@ impl WildcardImport {
                 .map(|c| c.into_component())
                 .collect::<Vec<_>>();
 
+            // Wildcard imports of an enum only bring its variants into scope,
+            // and not any associated items.
+            let is_enum = query.is_enum(&self.name);
+
             for c in components {
                 let name = self.name.extended(c);
 
+                if is_enum && !query.is_variant(&name) {
+                    continue;
+                }
+
+                // Only items which are visible from the importing module are
+                // re-exported by the wildcard.
+                if !query.is_visible_from(self.module, &name) {
+                    continue;
+                }
+
                 query.insert_import(
                     self.source_id,
                     self.span,
@@ -80,3 +110,11 @@ impl WildcardImport {
         Ok(())
     }
 }
+
+/// Test if the context declares the given item as the expected kind of item.
+fn is_context_kind(context: &Context, item: &Item, kind: impl Fn(&meta::Kind) -> bool) -> bool {
+    match context.lookup_meta(item) {
+        Some(mut metas) => metas.any(|meta| kind(&meta.kind)),
+        None => false,
+    }
+}